@@ -0,0 +1,45 @@
+use crate::abi::types::Abi;
+use crate::abi::Error;
+use crate::boc::internal::deserialize_cell_from_boc;
+use crate::client::ClientContext;
+use crate::error::ClientResult;
+use serde_json::Value;
+use std::sync::Arc;
+use ton_abi::token::Detokenizer;
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfDecodeAccountData {
+    /// Contract ABI
+    pub abi: Abi,
+
+    /// Data BOC or BOC handle
+    pub data: String,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfDecodeData {
+    /// Decoded data as a JSON structure.
+    pub data: Value,
+}
+
+/// Decodes account data using provided data BOC and ABI.
+///
+/// Contract fields are described in `fields` section of the ABI (ABI version 2.1 and later).
+/// Use it to read the state of the contract without running getters.
+#[api_function]
+pub async fn decode_account_data(
+    context: Arc<ClientContext>,
+    params: ParamsOfDecodeAccountData,
+) -> ClientResult<ResultOfDecodeData> {
+    let abi = params.abi.resolve(&context)?;
+    let (_, data) = deserialize_cell_from_boc(&context, &params.data, "contract data").await?;
+
+    let tokens = abi
+        .contract
+        .decode_storage_fields(data.into())
+        .map_err(|err| Error::invalid_data(err))?;
+    let data = Detokenizer::detokenize_to_json_value(abi.contract.fields(), &tokens)
+        .map_err(|err| Error::invalid_data(err))?;
+
+    Ok(ResultOfDecodeData { data })
+}
@@ -1,4 +1,5 @@
 use crate::{abi::types::Abi, boc::internal::deserialize_cell_from_boc};
+use crate::abi::registry::ParsedAbi;
 use crate::abi::{Error, FunctionHeader};
 use crate::boc::internal::deserialize_object_from_boc;
 use crate::client::ClientContext;
@@ -79,7 +80,7 @@ pub async fn decode_message(
 ) -> ClientResult<DecodedMessageBody> {
     let (abi, message) = prepare_decode(&context, &params).await?;
     if let Some(body) = message.body() {
-        decode_body(abi, body, message.is_internal())
+        decode_body(&abi.contract, body, message.is_internal())
     } else {
         Err(Error::invalid_message_for_decode(
             "The message body is empty",
@@ -107,26 +108,24 @@ pub async fn decode_message_body(
     context: Arc<ClientContext>,
     params: ParamsOfDecodeMessageBody,
 ) -> ClientResult<DecodedMessageBody> {
-    let abi = params.abi.json_string()?;
-    let abi = AbiContract::load(abi.as_bytes()).map_err(|x| Error::invalid_json(x))?;
+    let abi = params.abi.resolve(&context)?;
     let (_, body) = deserialize_cell_from_boc(&context, &params.body, "message body").await?;
-    decode_body(abi, body.into(), params.is_internal)
+    decode_body(&abi.contract, body.into(), params.is_internal)
 }
 
 async fn prepare_decode(
     context: &ClientContext,
     params: &ParamsOfDecodeMessage,
-) -> ClientResult<(AbiContract, ton_block::Message)> {
-    let abi = params.abi.json_string()?;
-    let abi = AbiContract::load(abi.as_bytes()).map_err(|x| Error::invalid_json(x))?;
+) -> ClientResult<(Arc<ParsedAbi>, ton_block::Message)> {
+    let abi = params.abi.resolve(context)?;
     let message = deserialize_object_from_boc(context, &params.message, "message")
         .await
         .map_err(|x| Error::invalid_message_for_decode(x))?;
     Ok((abi, message.object))
 }
 
-fn decode_body(
-    abi: AbiContract,
+pub(crate) fn decode_body(
+    abi: &AbiContract,
     body: SliceData,
     is_internal: bool,
 ) -> ClientResult<DecodedMessageBody> {
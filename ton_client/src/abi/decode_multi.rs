@@ -0,0 +1,190 @@
+use crate::abi::decode_message::{decode_body, DecodedMessageBody};
+use crate::abi::registry::ParsedAbi;
+use crate::abi::types::Abi;
+use crate::abi::Error;
+use crate::boc::internal::deserialize_object_from_boc;
+use crate::client::ClientContext;
+use crate::error::ClientResult;
+use std::sync::Arc;
+
+#[derive(Serialize, Deserialize, ApiType, Default, Clone)]
+pub struct KnownAbi {
+    /// Contract ABI.
+    pub abi: Abi,
+
+    /// Contract name to report in the decoding result.
+    pub name: Option<String>,
+
+    /// Hash of the contract code, if known.
+    ///
+    /// If the code hash of the decoded contract is specified in the parameters,
+    /// ABIs with the same code hash are tried first.
+    pub code_hash: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, PartialEq, Debug, Clone)]
+pub struct MatchedMessageBody {
+    /// Index of the matched ABI in `abis` parameter.
+    pub abi_index: u32,
+
+    /// Contract name of the matched ABI, if specified.
+    pub contract_name: Option<String>,
+
+    /// Decoded message body.
+    pub decoded: DecodedMessageBody,
+}
+
+pub(crate) struct KnownAbis {
+    abis: Vec<(Arc<ParsedAbi>, Option<String>)>,
+    order: Vec<usize>,
+}
+
+impl KnownAbis {
+    pub(crate) fn resolve(
+        context: &ClientContext,
+        abis: &[KnownAbi],
+        code_hash: &Option<String>,
+    ) -> ClientResult<Self> {
+        let mut resolved = Vec::with_capacity(abis.len());
+        for known in abis {
+            resolved.push((known.abi.resolve(context)?, known.name.clone()));
+        }
+        let has_code_hash = |known: &KnownAbi| match (&known.code_hash, code_hash) {
+            (Some(abi_hash), Some(code_hash)) => abi_hash.eq_ignore_ascii_case(code_hash),
+            _ => false,
+        };
+        let (mut order, others): (Vec<usize>, Vec<usize>) =
+            (0..abis.len()).partition(|i| has_code_hash(&abis[*i]));
+        order.extend(others);
+        Ok(Self {
+            abis: resolved,
+            order,
+        })
+    }
+
+    fn decode(&self, message: &ton_block::Message) -> Option<MatchedMessageBody> {
+        let body = message.body()?;
+        for index in &self.order {
+            let (abi, name) = &self.abis[*index];
+            if let Ok(decoded) = decode_body(&abi.contract, body.clone(), message.is_internal()) {
+                return Some(MatchedMessageBody {
+                    abi_index: *index as u32,
+                    contract_name: name.clone(),
+                    decoded,
+                });
+            }
+        }
+        None
+    }
+
+    /// Decodes inbound and outbound messages of the transaction
+    pub(crate) fn decode_transaction(
+        &self,
+        transaction: &ton_block::Transaction,
+    ) -> ClientResult<(Option<MatchedMessageBody>, Vec<Option<MatchedMessageBody>>)> {
+        let in_msg = match &transaction.in_msg {
+            Some(in_msg) => {
+                let message = in_msg
+                    .read_struct()
+                    .map_err(|x| Error::invalid_message_for_decode(x))?;
+                self.decode(&message)
+            }
+            None => None,
+        };
+        let mut out_msgs = Vec::new();
+        transaction
+            .out_msgs
+            .iterate(|message| {
+                out_msgs.push(self.decode(&message.0));
+                Ok(true)
+            })
+            .map_err(|x| Error::invalid_message_for_decode(x))?;
+        Ok((in_msg, out_msgs))
+    }
+}
+
+//---------------------------------------------------------------------------- decode_message_multi
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfDecodeMessageMulti {
+    /// Contract ABIs to try.
+    pub abis: Vec<KnownAbi>,
+
+    /// Message BOC.
+    pub message: String,
+
+    /// Code hash of the contract the message belongs to, if known.
+    pub code_hash: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, PartialEq, Debug)]
+pub struct ResultOfDecodeMessageMulti {
+    /// Decoded message body or `null` if none of the ABIs matches the message.
+    pub matched: Option<MatchedMessageBody>,
+}
+
+/// Decodes message body trying several ABIs.
+///
+/// ABIs are tried in the order they are specified, except the ABIs with `code_hash`
+/// equal to the one specified in the parameters which are tried first.
+/// The first ABI which decodes the message body is returned.
+/// Use it when the ABI of the contract is not known up front.
+#[api_function]
+pub async fn decode_message_multi(
+    context: Arc<ClientContext>,
+    params: ParamsOfDecodeMessageMulti,
+) -> ClientResult<ResultOfDecodeMessageMulti> {
+    let abis = KnownAbis::resolve(&context, &params.abis, &params.code_hash)?;
+    let message: ton_block::Message =
+        deserialize_object_from_boc(&context, &params.message, "message")
+            .await
+            .map_err(|x| Error::invalid_message_for_decode(x))?
+            .object;
+    Ok(ResultOfDecodeMessageMulti {
+        matched: abis.decode(&message),
+    })
+}
+
+//----------------------------------------------------------------------- decode_transaction_multi
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfDecodeTransactionMulti {
+    /// Contract ABIs to try.
+    pub abis: Vec<KnownAbi>,
+
+    /// Transaction BOC.
+    pub transaction: String,
+
+    /// Code hash of the account the transaction belongs to, if known.
+    pub code_hash: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, PartialEq, Debug)]
+pub struct ResultOfDecodeTransactionMulti {
+    /// Decoded inbound message or `null` if the transaction has no inbound message
+    /// or none of the ABIs matches it.
+    pub in_msg: Option<MatchedMessageBody>,
+
+    /// Decoded outbound messages in the order of the transaction out messages.
+    ///
+    /// Item is `null` if none of the ABIs matches the message.
+    pub out_msgs: Vec<Option<MatchedMessageBody>>,
+}
+
+/// Decodes inbound and outbound messages of the transaction trying several ABIs.
+///
+/// Each message is decoded independently in the same way as `decode_message_multi`
+/// does, so the messages sent to other contracts can be decoded with their own ABIs.
+#[api_function]
+pub async fn decode_transaction_multi(
+    context: Arc<ClientContext>,
+    params: ParamsOfDecodeTransactionMulti,
+) -> ClientResult<ResultOfDecodeTransactionMulti> {
+    let abis = KnownAbis::resolve(&context, &params.abis, &params.code_hash)?;
+    let transaction: ton_block::Transaction =
+        deserialize_object_from_boc(&context, &params.transaction, "transaction")
+            .await?
+            .object;
+    let (in_msg, out_msgs) = abis.decode_transaction(&transaction)?;
+    Ok(ResultOfDecodeTransactionMulti { in_msg, out_msgs })
+}
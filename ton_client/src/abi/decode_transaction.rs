@@ -0,0 +1,190 @@
+use crate::abi::decode_message::MessageBodyType;
+use crate::abi::decode_multi::{KnownAbi, KnownAbis, MatchedMessageBody};
+use crate::boc::internal::deserialize_object_from_boc;
+use crate::boc::parse::serialize_transaction;
+use crate::client::ClientContext;
+use crate::error::ClientResult;
+use crate::net::{ParamsOfQueryCollection, TransactionNode, TRANSACTIONS_COLLECTION};
+use crate::tvm::TransactionFees;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfDecodeTransaction {
+    /// Contract ABIs to try.
+    ///
+    /// Usually it is the ABI of the transaction account, but the ABIs of the
+    /// contracts the account sends messages to can be added to decode them too.
+    pub abis: Vec<KnownAbi>,
+
+    /// Transaction BOC.
+    ///
+    /// E.g. `boc` field of the transaction fetched with `net.query_collection`.
+    /// Use `decode_transaction_tree` to decode the `net.query_transaction_tree` result.
+    pub transaction: String,
+
+    /// Code hash of the account the transaction belongs to, if known.
+    pub code_hash: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, PartialEq, Debug)]
+pub struct ResultOfDecodeTransaction {
+    /// Parsed transaction.
+    ///
+    /// In addition to the regular transaction fields there is a
+    /// `boc` field encoded with `base64` which contains source
+    /// transaction BOC.
+    pub transaction: Value,
+
+    /// Decoded inbound message.
+    ///
+    /// `null` if the transaction has no inbound message or none of the ABIs matches it.
+    pub in_msg: Option<MatchedMessageBody>,
+
+    /// Decoded outbound messages in the order of the transaction out messages.
+    ///
+    /// Item is `null` if none of the ABIs matches the message.
+    pub out_msgs: Vec<Option<MatchedMessageBody>>,
+
+    /// Events emitted by the transaction.
+    pub events: Vec<MatchedMessageBody>,
+
+    /// Decoded body of the function return value.
+    pub output: Option<Value>,
+
+    /// Indicates that the transaction is aborted.
+    pub aborted: bool,
+
+    /// Exit code of the compute phase.
+    ///
+    /// `null` if the compute phase is skipped.
+    pub exit_code: Option<i32>,
+
+    /// Transaction fees.
+    pub fees: TransactionFees,
+}
+
+/// Decodes the transaction with its inbound and outbound messages.
+///
+/// Messages are decoded trying several ABIs in the same way as
+/// `decode_transaction_multi` does.
+///
+/// Unlike `processing.process_message` result, the transaction can be arbitrary
+/// one, including the aborted transactions, so the function can be used to inspect
+/// the failed transactions: `aborted` flag, compute phase `exit_code` and fees
+/// are returned along with the decoded messages.
+#[api_function]
+pub async fn decode_transaction(
+    context: Arc<ClientContext>,
+    params: ParamsOfDecodeTransaction,
+) -> ClientResult<ResultOfDecodeTransaction> {
+    let abis = KnownAbis::resolve(&context, &params.abis, &params.code_hash)?;
+    decode_transaction_boc(&context, &abis, &params.transaction).await
+}
+
+async fn decode_transaction_boc(
+    context: &Arc<ClientContext>,
+    abis: &KnownAbis,
+    boc: &str,
+) -> ClientResult<ResultOfDecodeTransaction> {
+    let object =
+        deserialize_object_from_boc::<ton_block::Transaction>(context, boc, "transaction").await?;
+
+    let (in_msg, out_msgs) = abis.decode_transaction(&object.object)?;
+
+    let mut events = Vec::new();
+    let mut output = None;
+    for matched in out_msgs.iter().flatten() {
+        match matched.decoded.body_type {
+            MessageBodyType::Event => events.push(matched.clone()),
+            MessageBodyType::Output => output = matched.decoded.value.clone(),
+            _ => {}
+        }
+    }
+
+    let transaction = ton_sdk::Transaction::try_from(&object.object)
+        .map_err(|err| crate::tvm::Error::can_not_read_transaction(err))?;
+
+    Ok(ResultOfDecodeTransaction {
+        transaction: serialize_transaction(&object)?,
+        in_msg,
+        out_msgs,
+        events,
+        output,
+        aborted: transaction.is_aborted(),
+        exit_code: transaction.compute.exit_code,
+        fees: transaction.calc_fees(),
+    })
+}
+
+//------------------------------------------------------------------------ decode_transaction_tree
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfDecodeTransactionTree {
+    /// Contract ABIs to try.
+    pub abis: Vec<KnownAbi>,
+
+    /// Transactions returned by `net.query_transaction_tree`.
+    pub transactions: Vec<TransactionNode>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, PartialEq, Debug)]
+pub struct ResultOfDecodeTransactionTree {
+    /// Decoded transactions in the order of `params.transactions`.
+    pub transactions: Vec<ResultOfDecodeTransaction>,
+}
+
+/// Decodes the transactions of the `net.query_transaction_tree` result.
+///
+/// Transaction BOCs are fetched by the transaction ids, then every transaction
+/// is decoded in the same way as `decode_transaction` does.
+#[api_function]
+pub async fn decode_transaction_tree(
+    context: Arc<ClientContext>,
+    params: ParamsOfDecodeTransactionTree,
+) -> ClientResult<ResultOfDecodeTransactionTree> {
+    let abis = KnownAbis::resolve(&context, &params.abis, &None)?;
+    if params.transactions.is_empty() {
+        return Ok(ResultOfDecodeTransactionTree::default());
+    }
+
+    let ids: Vec<&str> = params.transactions.iter().map(|node| node.id.as_str()).collect();
+    let fetched = context
+        .get_server_link()?
+        .query_collection(
+            ParamsOfQueryCollection {
+                collection: TRANSACTIONS_COLLECTION.to_string(),
+                filter: Some(json!({ "id": { "in": ids } })),
+                result: "id boc".to_string(),
+                order: None,
+                limit: Some(ids.len() as u32),
+            },
+            None,
+        )
+        .await?;
+    let bocs: HashMap<&str, &str> = fetched
+        .as_array()
+        .map(|transactions| {
+            transactions
+                .iter()
+                .filter_map(|transaction| {
+                    Some((transaction["id"].as_str()?, transaction["boc"].as_str()?))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut transactions = Vec::with_capacity(params.transactions.len());
+    for node in &params.transactions {
+        let boc = bocs.get(node.id.as_str()).ok_or_else(|| {
+            crate::net::Error::invalid_server_response(format!(
+                "transaction {} is not found",
+                node.id
+            ))
+        })?;
+        transactions.push(decode_transaction_boc(&context, &abis, boc).await?);
+    }
+    Ok(ResultOfDecodeTransactionTree { transactions })
+}
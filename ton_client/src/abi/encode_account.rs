@@ -135,7 +135,7 @@ async fn state_init_from_tvc(
         image
             .update_data(
                 init_params.value.to_string().as_str(),
-                &init_params.abi.json_string()?,
+                &init_params.abi.resolve(context)?.contract,
             )
             .map_err(|err| {
                 Error::invalid_tvc_image(format!("Failed to set initial data: {}", err))
@@ -9,9 +9,8 @@ use crate::error::ClientResult;
 use serde_json::Value;
 use std::str::FromStr;
 use std::sync::Arc;
-use ton_abi::Contract;
 use ton_block::{MsgAddressInt, CurrencyCollection};
-use ton_sdk::{ContractImage, FunctionCallSet};
+use ton_sdk::{AbiContract, ContractImage, FunctionCallSet};
 
 //--------------------------------------------------------------------------- encode_deploy_message
 
@@ -90,7 +89,7 @@ fn resolve_header(
     pubkey: Option<&str>,
     processing_try_index: Option<u8>,
     context: &Arc<ClientContext>,
-    abi: &Contract,
+    abi: &AbiContract,
 ) -> ClientResult<Option<FunctionHeader>> {
     if abi.header().len() == 0 {
         return Ok(None);
@@ -141,15 +140,14 @@ fn header_to_string(header: &FunctionHeader) -> String {
 }
 
 impl CallSet {
-    fn to_function_call_set(
+    fn to_function_call_set<'a>(
         &self,
         pubkey: Option<&str>,
         processing_try_index: Option<u8>,
         context: &Arc<ClientContext>,
-        abi: &str,
+        abi: &'a AbiContract,
         internal: bool,
-    ) -> ClientResult<FunctionCallSet> {
-        let contract = Contract::load(abi.as_bytes()).map_err(|x| Error::invalid_json(x))?;
+    ) -> ClientResult<FunctionCallSet<'a>> {
         let header = if internal {
             None
         } else {
@@ -158,13 +156,13 @@ impl CallSet {
                 pubkey,
                 processing_try_index,
                 context,
-                &contract,
+                abi,
             )?
         };
 
         let func = match decode_abi_number::<u32>(&self.function_name) {
             Ok(id) => {
-                &contract
+                &abi
                     .function_by_id(id, true)
                     .map_err(|e| Error::invalid_function_id(&self.function_name, e))?
                     .name
@@ -174,7 +172,7 @@ impl CallSet {
         .clone();
 
         Ok(FunctionCallSet {
-            abi: abi.to_string(),
+            abi,
             func,
             header: header.as_ref().map(|x| header_to_string(x)),
             input: self
@@ -259,7 +257,7 @@ fn required_public_key(public_key: Option<String>) -> ClientResult<String> {
 
 fn encode_deploy(
     context: std::sync::Arc<ClientContext>,
-    abi: &str,
+    abi: &AbiContract,
     image: ContractImage,
     workchain: i32,
     call_set: &CallSet,
@@ -268,7 +266,7 @@ fn encode_deploy(
 ) -> ClientResult<(Vec<u8>, Option<Vec<u8>>, MsgAddressInt)> {
     let address = image.msg_address(workchain);
     let unsigned = ton_sdk::Contract::get_deploy_message_bytes_for_signing(
-        call_set.to_function_call_set(pubkey, processing_try_index, &context, &abi, false)?,
+        call_set.to_function_call_set(pubkey, processing_try_index, &context, abi, false)?,
         image,
         workchain,
     )
@@ -279,7 +277,7 @@ fn encode_deploy(
 fn encode_int_deploy(
     src: Option<MsgAddressInt>,
     context: std::sync::Arc<ClientContext>,
-    abi: &str,
+    abi: &AbiContract,
     image: ContractImage,
     workchain_id: i32,
     call_set: &CallSet,
@@ -290,7 +288,7 @@ fn encode_int_deploy(
     let address = image.msg_address(workchain_id);
     let message = ton_sdk::Contract::get_int_deploy_message_bytes(
         src,
-        call_set.to_function_call_set(pubkey, None, &context, &abi, true)?,
+        call_set.to_function_call_set(pubkey, None, &context, abi, true)?,
         image,
         workchain_id,
         ihr_disabled,
@@ -345,7 +343,7 @@ fn encode_empty_int_deploy(
 fn encode_run(
     context: std::sync::Arc<ClientContext>,
     params: &ParamsOfEncodeMessage,
-    abi: &str,
+    abi: &AbiContract,
     call_set: &CallSet,
     pubkey: Option<&str>,
     processing_try_index: Option<u8>,
@@ -415,7 +413,8 @@ pub async fn encode_message(
     context: std::sync::Arc<ClientContext>,
     params: ParamsOfEncodeMessage,
 ) -> ClientResult<ResultOfEncodeMessage> {
-    let abi = params.abi.json_string()?;
+    let abi = params.abi.resolve(&context)?;
+    let abi = &abi.contract;
 
     let public = params.signer.resolve_public_key(context.clone()).await?;
     let (message, data_to_sign, address) = if let Some(deploy_set) = params.deploy_set {
@@ -424,7 +423,7 @@ pub async fn encode_message(
             .unwrap_or(context.config.abi.workchain);
         let mut image = create_tvc_image(
             &context,
-            &abi,
+            abi,
             deploy_set.initial_data.as_ref(),
             &deploy_set.tvc,
         ).await?;
@@ -438,7 +437,7 @@ pub async fn encode_message(
         if let Some(call_set) = &params.call_set {
             encode_deploy(
                 context.clone(),
-                &abi,
+                abi,
                 image,
                 workchain,
                 call_set,
@@ -452,7 +451,7 @@ pub async fn encode_message(
         encode_run(
             context.clone(),
             &params,
-            &abi,
+            abi,
             call_set,
             public.as_ref().map(|x| x.as_str()),
             params.processing_try_index,
@@ -462,7 +461,7 @@ pub async fn encode_message(
     };
 
     let (message, data_to_sign) = try_to_sign_message(
-        context, &abi, message, data_to_sign, &params.signer
+        context, abi, message, data_to_sign, &params.signer
     ).await?;
 
     Ok(ResultOfEncodeMessage {
@@ -555,14 +554,15 @@ pub async  fn encode_internal_message(
     let (message, address) = if let Some(deploy_set) = params.deploy_set {
         let abi = params.abi
             .ok_or_else(|| Error::invalid_abi("abi is undefined"))?
-            .json_string()?;
+            .resolve(&context)?;
+        let abi = &abi.contract;
 
         let workchain_id = deploy_set
             .workchain_id
             .unwrap_or(context.config.abi.workchain);
         let mut image = create_tvc_image(
             &context,
-            &abi,
+            abi,
             deploy_set.initial_data.as_ref(),
             &deploy_set.tvc,
         ).await?;
@@ -573,7 +573,7 @@ pub async  fn encode_internal_message(
             encode_int_deploy(
                 src_address,
                 Arc::clone(&context),
-                &abi,
+                abi,
                 image,
                 workchain_id,
                 call_set,
@@ -597,14 +597,14 @@ pub async  fn encode_internal_message(
         if let Some(call_set) = &params.call_set {
             let abi = params.abi
                 .ok_or_else(|| Error::invalid_abi("abi is undefined"))?
-                .json_string()?;
+                .resolve(&context)?;
             let message = ton_sdk::Contract::construct_call_int_message_json(
                 address.clone(),
                 src_address,
                 ihr_disabled,
                 bounce,
                 value,
-                call_set.to_function_call_set(None, None, &context, &abi, true)?,
+                call_set.to_function_call_set(None, None, &context, &abi.contract, true)?,
             )
             .map_err(|err| abi::Error::encode_run_message_failed(err, &call_set.function_name))?;
 
@@ -682,47 +682,31 @@ pub async fn encode_message_body(
     context: std::sync::Arc<ClientContext>,
     params: ParamsOfEncodeMessageBody,
 ) -> ClientResult<ResultOfEncodeMessageBody> {
-    let abi = params.abi.json_string()?;
+    let abi = params.abi.resolve(&context)?;
+    let abi = &abi.contract;
 
     let public = params.signer.resolve_public_key(context.clone()).await?;
     let call = params.call_set.to_function_call_set(
         public.as_ref().map(|x| x.as_str()),
         params.processing_try_index,
         &context,
-        &abi,
+        abi,
         params.is_internal,
     )?;
     let func = call.func.clone();
     let (body, data_to_sign) = match params.signer {
         Signer::None => {
-            let body = ton_abi::encode_function_call(
-                abi.clone(),
-                func.clone(),
-                call.header,
-                call.input.clone(),
-                params.is_internal,
-                None,
-            )
-            .map_err(|err| Error::encode_run_message_failed(err, &func))?;
+            let body = call
+                .encode_body(params.is_internal, None)
+                .map_err(|err| Error::encode_run_message_failed(err, &func))?;
             (body, None)
         }
         _ => {
             if params.is_internal {
-                ton_abi::encode_function_call(
-                    abi.clone(),
-                    func.clone(),
-                    None,
-                    call.input,
-                    true,
-                    None,
-                ).map(|body| (body, None))
+                call.encode_body(true, None).map(|body| (body, None))
             } else {
-                ton_abi::prepare_function_call_for_sign(
-                    abi.clone(),
-                    func.clone(),
-                    call.header,
-                    call.input,
-                ).map(|(body, data_to_sign)| (body, Some(data_to_sign)))
+                call.prepare_body_for_sign()
+                    .map(|(body, data_to_sign)| (body, Some(data_to_sign)))
             }.map_err(|err| Error::encode_run_message_failed(err, &func))?
         }
     };
@@ -739,7 +723,7 @@ pub async fn encode_message_body(
                 .map(|string| hex_decode(&string))
                 .transpose()?;
             let body = add_sign_to_message_body(
-                &abi,
+                abi,
                 &signature,
                 pubkey.as_ref().map(|vec| vec.as_slice()),
                 &body,
@@ -790,7 +774,7 @@ pub async fn attach_signature(
 ) -> ClientResult<ResultOfAttachSignature> {
     let (boc, _) = deserialize_cell_from_boc(&context, &params.message, "message").await?;
     let signed = add_sign_to_message(
-        &params.abi.json_string()?,
+        &params.abi.resolve(&context)?.contract,
         &hex_decode(&params.signature)?,
         Some(&hex_decode(&params.public_key)?),
         &boc.bytes("message")?,
@@ -831,7 +815,7 @@ pub async fn attach_signature_to_message_body(
 ) -> ClientResult<ResultOfAttachSignatureToMessageBody> {
     let (boc, _) = deserialize_cell_from_boc(&context, &params.message, "message body").await?;
     let signed = add_sign_to_message_body(
-        &params.abi.json_string()?,
+        &params.abi.resolve(&context)?.contract,
         &hex_decode(&params.signature)?,
         Some(&hex_decode(&params.public_key)?),
         &boc.bytes("message body")?,
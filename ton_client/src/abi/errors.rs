@@ -15,6 +15,10 @@ pub enum ErrorCode {
     InvalidSigner = 310,
     InvalidAbi = 311,
     InvalidFunctionId = 312,
+    AbiNotRegistered = 313,
+    InvalidData = 314,
+    InvalidSigningEnvelope = 315,
+    InvalidEnvelopeSignature = 316,
 }
 
 pub struct Error;
@@ -102,4 +106,32 @@ impl Error {
             format!("Invalid function {}: {}", func_id, err),
         )
     }
+
+    pub fn abi_not_registered(handle: u32) -> ClientError {
+        error(
+            ErrorCode::AbiNotRegistered,
+            format!("ABI is not registered. Handle {}", handle),
+        )
+    }
+
+    pub fn invalid_data<E: Display>(err: E) -> ClientError {
+        error(
+            ErrorCode::InvalidData,
+            format!("Invalid contract data: {}", err),
+        )
+    }
+
+    pub fn invalid_signing_envelope<E: Display>(err: E) -> ClientError {
+        error(
+            ErrorCode::InvalidSigningEnvelope,
+            format!("Invalid signing envelope: {}", err),
+        )
+    }
+
+    pub fn invalid_envelope_signature<E: Display>(public_key: &str, err: E) -> ClientError {
+        error(
+            ErrorCode::InvalidEnvelopeSignature,
+            format!("Invalid envelope signature of {}: {}", public_key, err),
+        )
+    }
 }
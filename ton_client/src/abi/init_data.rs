@@ -0,0 +1,132 @@
+use crate::abi::types::Abi;
+use crate::abi::Error;
+use crate::boc::internal::{deserialize_cell_from_boc, serialize_cell_to_boc};
+use crate::boc::BocCacheType;
+use crate::client::ClientContext;
+use crate::encoding::hex_decode;
+use crate::error::ClientResult;
+use serde_json::Value;
+use std::sync::Arc;
+use ton_abi::token::Detokenizer;
+use ton_sdk::AbiContract;
+use ton_types::SliceData;
+
+//----------------------------------------------------------------------------- update_initial_data
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfUpdateInitialData {
+    /// Contract ABI. Required if `initial_data` is specified
+    pub abi: Option<Abi>,
+
+    /// Data BOC or BOC handle
+    pub data: String,
+
+    /// List of initial values for contract's static variables.
+    /// `abi` parameter should be provided to set initial data
+    pub initial_data: Option<Value>,
+
+    /// Initial account owner's public key to set into account data. Encoded in `hex`
+    pub initial_pubkey: Option<String>,
+
+    /// Cache type to put the result. The BOC itself returned if no cache type provided.
+    pub boc_cache: Option<BocCacheType>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfUpdateInitialData {
+    /// Updated data BOC or BOC handle
+    pub data: String,
+}
+
+/// Updates initial account data with initial values for the contract's static variables
+/// and owner's public key.
+///
+/// Use it to patch the data of a prebuilt TVC before deploy.
+/// This operation is applicable only for initial account data (before deploy).
+/// If the contract is already deployed, its data doesn't contain this data section any more.
+#[api_function]
+pub async fn update_initial_data(
+    context: Arc<ClientContext>,
+    params: ParamsOfUpdateInitialData,
+) -> ClientResult<ResultOfUpdateInitialData> {
+    let (_, data) = deserialize_cell_from_boc(&context, &params.data, "contract data").await?;
+    let mut data: SliceData = data.into();
+
+    if let Some(initial_data) = params.initial_data {
+        let abi = params
+            .abi
+            .ok_or_else(|| Error::invalid_abi("abi is required to set initial data"))?
+            .resolve(&context)?;
+        data = ton_sdk::Contract::update_contract_data(&abi.contract, &initial_data.to_string(), data)
+            .map_err(|err| Error::invalid_data(err))?;
+    }
+
+    if let Some(pubkey) = params.initial_pubkey {
+        data = AbiContract::insert_pubkey(data, &hex_decode(&pubkey)?)
+            .map_err(|err| Error::invalid_data(err))?;
+    }
+
+    Ok(ResultOfUpdateInitialData {
+        data: serialize_cell_to_boc(&context, data.into_cell(), "contract data", params.boc_cache)
+            .await?,
+    })
+}
+
+//----------------------------------------------------------------------------- decode_initial_data
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfDecodeInitialData {
+    /// Contract ABI. Initial data is decoded if this parameter is provided
+    pub abi: Option<Abi>,
+
+    /// Data BOC or BOC handle
+    pub data: String,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfDecodeInitialData {
+    /// List of initial values of contract's public variables.
+    /// Initial data is decoded if `abi` parameter is provided
+    pub initial_data: Option<Value>,
+
+    /// Initial account owner's public key. Encoded in `hex`.
+    /// `None` if the data doesn't contain the public key
+    pub initial_pubkey: Option<String>,
+}
+
+/// Decodes initial values of the contract's static variables and owner's public key
+/// from the account initial data.
+///
+/// This operation is applicable only for initial account data (before deploy).
+/// If the contract is already deployed, its data doesn't contain this data section any more.
+#[api_function]
+pub async fn decode_initial_data(
+    context: Arc<ClientContext>,
+    params: ParamsOfDecodeInitialData,
+) -> ClientResult<ResultOfDecodeInitialData> {
+    let (_, data) = deserialize_cell_from_boc(&context, &params.data, "contract data").await?;
+    let data: SliceData = data.into();
+
+    let initial_pubkey = AbiContract::get_pubkey(&data)
+        .map_err(|err| Error::invalid_data(err))?
+        .map(|pubkey| hex::encode(&pubkey));
+
+    let initial_data = match params.abi {
+        Some(abi) => {
+            let abi = abi.resolve(&context)?;
+            let tokens = abi
+                .contract
+                .decode_data(data)
+                .map_err(|err| Error::invalid_data(err))?;
+            let initial_data = Detokenizer::detokenize(&tokens)
+                .map_err(|err| Error::invalid_data(err))?;
+            Some(serde_json::from_str(&initial_data).map_err(|err| Error::invalid_data(err))?)
+        }
+        None => None,
+    };
+
+    Ok(ResultOfDecodeInitialData {
+        initial_data,
+        initial_pubkey,
+    })
+}
@@ -6,18 +6,18 @@ use crate::encoding::hex_decode;
 use crate::error::ClientResult;
 use std::sync::Arc;
 use serde_json::Value;
-use ton_sdk::ContractImage;
+use ton_sdk::{AbiContract, ContractImage};
 
 /// Combines `hex` encoded `signature` with `base64` encoded `unsigned_message`.
 /// Returns signed message encoded with `base64`.
 pub(crate) fn add_sign_to_message(
-    abi: &str,
+    abi: &AbiContract,
     signature: &[u8],
     public_key: Option<&[u8]>,
     unsigned_message: &[u8],
 ) -> ClientResult<Vec<u8>> {
-    let signed = ton_sdk::Contract::add_sign_to_message(
-        abi.to_string(),
+    let signed = ton_sdk::Contract::attach_signature(
+        abi,
         signature,
         public_key,
         unsigned_message,
@@ -29,14 +29,15 @@ pub(crate) fn add_sign_to_message(
 /// Combines `hex` encoded `signature` with `base64` encoded `unsigned_message`.
 /// Returns signed message encoded with `base64`.
 pub(crate) fn add_sign_to_message_body(
-    abi: &str,
+    abi: &AbiContract,
     signature: &[u8],
     public_key: Option<&[u8]>,
     unsigned_body: &[u8],
 ) -> ClientResult<Vec<u8>> {
     let unsigned = ton_sdk::Contract::deserialize_tree_to_slice(unsigned_body)
         .map_err(|err| Error::attach_signature_failed(err))?;
-    let body = ton_abi::add_sign_to_function_call(abi.to_string(), signature, public_key, unsigned)
+    let body = abi
+        .add_sign_to_encoded_input(signature, public_key, unsigned)
         .map_err(|err| Error::attach_signature_failed(err))?;
     Ok(ton_types::serialize_toc(
         &body
@@ -48,7 +49,7 @@ pub(crate) fn add_sign_to_message_body(
 
 pub(crate) async fn try_to_sign_message(
     context: Arc<ClientContext>,
-    abi: &str,
+    abi: &AbiContract,
     message: Vec<u8>,
     data_to_sign: Option<Vec<u8>>,
     signer: &Signer,
@@ -70,7 +71,7 @@ pub(crate) async fn try_to_sign_message(
 
 pub(crate) async fn create_tvc_image(
     context: &ClientContext,
-    abi: &str,
+    abi: &AbiContract,
     init_params: Option<&Value>,
     tvc: &String,
 ) -> ClientResult<ContractImage> {
@@ -14,19 +14,37 @@
 #[cfg(test)]
 mod tests;
 
+pub(crate) mod decode_data;
 pub(crate) mod decode_message;
+pub(crate) mod decode_multi;
+pub(crate) mod decode_transaction;
 pub(crate) mod encode_account;
 pub(crate) mod encode_message;
 mod errors;
+pub(crate) mod init_data;
 mod internal;
+pub(crate) mod registry;
+pub(crate) mod schema;
+pub(crate) mod signature;
 mod signing;
+pub(crate) mod signing_envelope;
 mod types;
 use serde::{Deserialize, Deserializer};
 
+pub use decode_data::{decode_account_data, ParamsOfDecodeAccountData, ResultOfDecodeData};
 pub use decode_message::{
     decode_message, decode_message_body, DecodedMessageBody, MessageBodyType,
     ParamsOfDecodeMessage, ParamsOfDecodeMessageBody,
 };
+pub use decode_multi::{
+    decode_message_multi, decode_transaction_multi, KnownAbi, MatchedMessageBody,
+    ParamsOfDecodeMessageMulti, ParamsOfDecodeTransactionMulti, ResultOfDecodeMessageMulti,
+    ResultOfDecodeTransactionMulti,
+};
+pub use decode_transaction::{
+    decode_transaction, decode_transaction_tree, ParamsOfDecodeTransaction,
+    ParamsOfDecodeTransactionTree, ResultOfDecodeTransaction, ResultOfDecodeTransactionTree,
+};
 pub use encode_account::{
     encode_account, ParamsOfEncodeAccount, ResultOfEncodeAccount, StateInitParams, StateInitSource,
 };
@@ -38,7 +56,27 @@ pub use encode_message::{
     ResultOfEncodeMessage, ResultOfEncodeMessageBody,
 };
 pub use errors::{Error, ErrorCode};
+pub use init_data::{
+    decode_initial_data, update_initial_data, ParamsOfDecodeInitialData,
+    ParamsOfUpdateInitialData, ResultOfDecodeInitialData, ResultOfUpdateInitialData,
+};
+pub use registry::{register_abi, unregister_abi, ParamsOfRegisterAbi, RegisteredAbi};
+pub use schema::{
+    get_function_schema, validate_input, InputValidationError, ParamsOfGetFunctionSchema,
+    ParamsOfValidateInput, ResultOfGetFunctionSchema, ResultOfValidateInput,
+};
+pub use signature::{
+    get_function_id, get_signature, identify_message_body, IdentifiedMessageBody,
+    ParamsOfGetFunctionId, ParamsOfGetSignature, ParamsOfIdentifyMessageBody, ResultOfGetFunctionId,
+    ResultOfGetSignature, ResultOfIdentifyMessageBody,
+};
 pub use signing::Signer;
+pub use signing_envelope::{
+    add_envelope_signature, create_signing_envelope, finalize_envelope, sign_envelope,
+    ParamsOfAddEnvelopeSignature, ParamsOfCreateSigningEnvelope, ParamsOfFinalizeEnvelope,
+    ParamsOfSignEnvelope, ResultOfFinalizeEnvelope, ResultOfSigningEnvelope, SignedEnvelopeMessage,
+    SigningEnvelope, SigningEnvelopeEntry,
+};
 pub use types::{
     Abi, AbiContract, AbiData, AbiEvent, AbiFunction, AbiHandle, AbiParam, FunctionHeader,
     MessageSource,
@@ -0,0 +1,70 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+use crate::abi::{Abi, AbiHandle, Error};
+use crate::client::ClientContext;
+use crate::error::ClientResult;
+use std::sync::Arc;
+use ton_sdk::AbiContract;
+
+/// ABI parsed once and shared between the calls
+pub(crate) struct ParsedAbi {
+    pub json: String,
+    pub contract: AbiContract,
+}
+
+impl ParsedAbi {
+    pub fn from_json(json: String) -> ClientResult<Self> {
+        let contract = AbiContract::load(json.as_bytes()).map_err(|x| Error::invalid_json(x))?;
+        Ok(Self { json, contract })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default)]
+pub struct ParamsOfRegisterAbi {
+    /// Contract ABI to register.
+    pub abi: Abi,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default)]
+pub struct RegisteredAbi {
+    /// Handle of the registered ABI.
+    pub handle: AbiHandle,
+}
+
+/// Registers ABI in the client context.
+///
+/// ABI is parsed once and then used by all the functions accepting `Abi::Handle`
+/// with the returned handle, so no JSON parsing is performed on every call.
+/// Use it when the same ABI is used many times, e.g. for decoding messages
+/// of the same contract.
+#[api_function]
+pub async fn register_abi(
+    context: Arc<ClientContext>,
+    params: ParamsOfRegisterAbi,
+) -> ClientResult<RegisteredAbi> {
+    let abi = params.abi.resolve(&context)?;
+    let id = context.get_next_id();
+    context.abis.insert(id, abi);
+
+    Ok(RegisteredAbi {
+        handle: AbiHandle(id),
+    })
+}
+
+/// Removes registered ABI from the client context.
+#[api_function]
+pub fn unregister_abi(context: Arc<ClientContext>, params: RegisteredAbi) -> ClientResult<()> {
+    context.abis.remove(&params.handle.0);
+    Ok(())
+}
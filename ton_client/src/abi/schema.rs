@@ -0,0 +1,462 @@
+use crate::abi::types::{Abi, AbiContract, AbiFunction, AbiParam};
+use crate::abi::Error;
+use crate::boc::internal::deserialize_cell_from_base64;
+use crate::client::ClientContext;
+use crate::encoding::decode_abi_bigint;
+use crate::error::ClientResult;
+use num_bigint::BigInt;
+use serde_json::{Map, Value};
+use std::str::FromStr;
+use std::sync::Arc;
+use ton_block::MsgAddressInt;
+
+const INTEGER_PATTERN: &str = "^-?(0[xX][0-9a-fA-F]+|[0-9]+)$";
+const UNSIGNED_PATTERN: &str = "^(0[xX][0-9a-fA-F]+|[0-9]+)$";
+const ADDRESS_PATTERN: &str = "^-?[0-9]+:[0-9a-fA-F]{64}$";
+const HEX_PATTERN: &str = "^([0-9a-fA-F]{2})*$";
+
+/// ABI parameter type parsed from the type string, e.g. `map(uint32,tuple[])`
+#[derive(Debug, Clone, PartialEq)]
+enum ParamType {
+    Uint(usize),
+    Int(usize),
+    /// Variable length integer with the max length of the value in bytes
+    VarUint(usize),
+    VarInt(usize),
+    Bool,
+    Address,
+    Cell,
+    Bytes,
+    FixedBytes(usize),
+    String,
+    PublicKey,
+    Tuple(Vec<AbiParam>),
+    Array(Box<ParamType>),
+    FixedArray(Box<ParamType>, usize),
+    Map(Box<ParamType>, Box<ParamType>),
+    Optional(Box<ParamType>),
+}
+
+/// Returns max value length in bytes for `varuint<size>` and `varint<size>` types.
+/// ABI supports only 16 and 32 sizes
+fn var_int_length(type_name: &str, size: usize) -> ClientResult<usize> {
+    match size {
+        16 | 32 => Ok(size - 1),
+        _ => Err(Error::invalid_abi(format!(
+            "unsupported parameter type `{}`: size must be 16 or 32",
+            type_name
+        ))),
+    }
+}
+
+fn bounded_size(type_name: &str, size: usize, max: usize) -> ClientResult<usize> {
+    if (1..=max).contains(&size) {
+        Ok(size)
+    } else {
+        Err(Error::invalid_abi(format!(
+            "unsupported parameter type `{}`: size must be from 1 to {}",
+            type_name, max
+        )))
+    }
+}
+
+impl ParamType {
+    fn parse(type_name: &str, components: &[AbiParam]) -> ClientResult<Self> {
+        let invalid = || Error::invalid_abi(format!("unsupported parameter type `{}`", type_name));
+        if type_name.ends_with(']') {
+            let open = type_name.rfind('[').ok_or_else(invalid)?;
+            let item = Box::new(Self::parse(&type_name[..open], components)?);
+            let size = &type_name[open + 1..type_name.len() - 1];
+            return Ok(if size.is_empty() {
+                Self::Array(item)
+            } else {
+                Self::FixedArray(item, size.parse().map_err(|_| invalid())?)
+            });
+        }
+        if let Some(inner) = type_name.strip_prefix("optional(").and_then(|t| t.strip_suffix(')')) {
+            return Ok(Self::Optional(Box::new(Self::parse(inner, components)?)));
+        }
+        if let Some(inner) = type_name.strip_prefix("map(").and_then(|t| t.strip_suffix(')')) {
+            let comma = inner.find(',').ok_or_else(invalid)?;
+            return Ok(Self::Map(
+                Box::new(Self::parse(inner[..comma].trim(), &[])?),
+                Box::new(Self::parse(inner[comma + 1..].trim(), components)?),
+            ));
+        }
+        let sized = |prefix: &str| -> Option<usize> {
+            type_name.strip_prefix(prefix).and_then(|size| size.parse().ok())
+        };
+        Ok(match type_name {
+            "bool" => Self::Bool,
+            "address" => Self::Address,
+            "cell" => Self::Cell,
+            "bytes" => Self::Bytes,
+            "string" => Self::String,
+            "pubkey" => Self::PublicKey,
+            "tuple" => Self::Tuple(components.to_vec()),
+            "gram" | "token" => Self::VarUint(15),
+            "time" => Self::Uint(64),
+            "expire" => Self::Uint(32),
+            _ => {
+                if let Some(size) = sized("varuint") {
+                    Self::VarUint(var_int_length(type_name, size)?)
+                } else if let Some(size) = sized("varint") {
+                    Self::VarInt(var_int_length(type_name, size)?)
+                } else if let Some(size) = sized("uint") {
+                    Self::Uint(bounded_size(type_name, size, 256)?)
+                } else if let Some(size) = sized("int") {
+                    Self::Int(bounded_size(type_name, size, 256)?)
+                } else if let Some(size) = sized("fixedbytes") {
+                    Self::FixedBytes(bounded_size(type_name, size, 32)?)
+                } else {
+                    return Err(invalid());
+                }
+            }
+        })
+    }
+
+    fn of(param: &AbiParam) -> ClientResult<Self> {
+        Self::parse(&param.param_type, &param.components)
+    }
+
+    fn is_optional(&self) -> bool {
+        matches!(self, Self::Optional(_))
+    }
+
+    /// Range of the integer type values: `[min, max)`
+    fn integer_range(&self) -> Option<(BigInt, BigInt)> {
+        let one = BigInt::from(1);
+        match self {
+            Self::Uint(bits) => Some((BigInt::from(0), one << *bits)),
+            Self::Int(bits) if *bits > 0 => Some((-(one.clone() << (bits - 1)), one << (bits - 1))),
+            Self::VarUint(bytes) => Some((BigInt::from(0), one << (bytes * 8))),
+            Self::VarInt(bytes) if *bytes > 0 => {
+                Some((-(one.clone() << (bytes * 8 - 1)), one << (bytes * 8 - 1)))
+            }
+            _ => None,
+        }
+    }
+
+    fn schema(&self) -> ClientResult<Value> {
+        Ok(match self {
+            Self::Uint(_) | Self::Int(_) | Self::VarUint(_) | Self::VarInt(_) => {
+                let pattern = match self {
+                    Self::Uint(_) | Self::VarUint(_) => UNSIGNED_PATTERN,
+                    _ => INTEGER_PATTERN,
+                };
+                json!({
+                    "anyOf": [
+                        { "type": "integer" },
+                        { "type": "string", "pattern": pattern },
+                    ]
+                })
+            }
+            Self::Bool => json!({ "type": "boolean" }),
+            Self::Address => json!({ "type": "string", "pattern": ADDRESS_PATTERN }),
+            Self::Cell => json!({ "type": "string", "contentEncoding": "base64" }),
+            Self::Bytes => json!({ "type": "string", "pattern": HEX_PATTERN }),
+            Self::FixedBytes(size) => json!({
+                "type": "string",
+                "pattern": HEX_PATTERN,
+                "minLength": size * 2,
+                "maxLength": size * 2,
+            }),
+            Self::String => json!({ "type": "string" }),
+            Self::PublicKey => json!({
+                "anyOf": [
+                    { "type": "null" },
+                    { "type": "string", "pattern": "^(0x)?[0-9a-fA-F]{64}$" },
+                ]
+            }),
+            Self::Tuple(components) => params_schema(components)?,
+            Self::Array(item) => json!({ "type": "array", "items": item.schema()? }),
+            Self::FixedArray(item, size) => json!({
+                "type": "array",
+                "items": item.schema()?,
+                "minItems": size,
+                "maxItems": size,
+            }),
+            Self::Map(key, value) => {
+                let key_pattern = match key.as_ref() {
+                    Self::Address => ADDRESS_PATTERN,
+                    Self::Uint(_) => UNSIGNED_PATTERN,
+                    _ => INTEGER_PATTERN,
+                };
+                json!({
+                    "type": "object",
+                    "propertyNames": { "pattern": key_pattern },
+                    "additionalProperties": value.schema()?,
+                })
+            }
+            Self::Optional(inner) => json!({ "anyOf": [{ "type": "null" }, inner.schema()?] }),
+        })
+    }
+
+    fn validate(&self, value: &Value, path: &str, errors: &mut Vec<InputValidationError>) {
+        let mut error = |message: String| {
+            errors.push(InputValidationError {
+                path: path.to_owned(),
+                message,
+            })
+        };
+        match self {
+            Self::Uint(_) | Self::Int(_) | Self::VarUint(_) | Self::VarInt(_) => {
+                let number = match value {
+                    Value::Number(number) if number.is_i64() || number.is_u64() => {
+                        decode_abi_bigint(&number.to_string()).ok()
+                    }
+                    Value::String(string) => decode_abi_bigint(string).ok(),
+                    _ => None,
+                };
+                match (number, self.integer_range()) {
+                    (Some(number), Some((min, max))) => {
+                        if number < min || number >= max {
+                            error(format!("value {} is out of range [{}, {})", number, min, max));
+                        }
+                    }
+                    _ => error(format!("integer expected, found {}", value)),
+                }
+            }
+            Self::Bool => {
+                if !value.is_boolean() && value != "true" && value != "false" {
+                    error(format!("boolean expected, found {}", value));
+                }
+            }
+            Self::Address => match value.as_str() {
+                Some(address) => {
+                    if let Err(err) = MsgAddressInt::from_str(address) {
+                        error(format!("invalid address `{}`: {}", address, err));
+                    }
+                }
+                None => error(format!("address string expected, found {}", value)),
+            },
+            Self::Cell => match value.as_str() {
+                Some(boc) => {
+                    if let Err(err) = deserialize_cell_from_base64(boc, "cell") {
+                        error(err.message);
+                    }
+                }
+                None => error(format!("cell BOC encoded with base64 expected, found {}", value)),
+            },
+            Self::Bytes | Self::FixedBytes(_) => match value.as_str().map(hex::decode) {
+                Some(Ok(bytes)) => {
+                    if let Self::FixedBytes(size) = self {
+                        if bytes.len() != *size {
+                            error(format!("{} bytes expected, found {}", size, bytes.len()));
+                        }
+                    }
+                }
+                _ => error(format!("hex encoded bytes expected, found {}", value)),
+            },
+            Self::String => {
+                if !value.is_string() {
+                    error(format!("string expected, found {}", value));
+                }
+            }
+            Self::PublicKey => {
+                let key = value.as_str().map(|key| key.trim_start_matches("0x"));
+                let valid = value.is_null()
+                    || key.map(|key| key.len() == 64 && hex::decode(key).is_ok()).unwrap_or(false);
+                if !valid {
+                    error(format!("public key encoded with hex expected, found {}", value));
+                }
+            }
+            Self::Tuple(components) => validate_params(components, value, path, errors),
+            Self::Array(item) | Self::FixedArray(item, _) => match value.as_array() {
+                Some(items) => {
+                    if let Self::FixedArray(_, size) = self {
+                        if items.len() != *size {
+                            error(format!("{} items expected, found {}", size, items.len()));
+                        }
+                    }
+                    for (i, value) in items.iter().enumerate() {
+                        item.validate(value, &format!("{}[{}]", path, i), errors);
+                    }
+                }
+                None => error(format!("array expected, found {}", value)),
+            },
+            Self::Map(key, item) => match value.as_object() {
+                Some(map) => {
+                    for (name, value) in map {
+                        let path = format!("{}[\"{}\"]", path, name);
+                        key.validate(&Value::String(name.clone()), &path, errors);
+                        item.validate(value, &path, errors);
+                    }
+                }
+                None => error(format!("map object expected, found {}", value)),
+            },
+            Self::Optional(inner) => {
+                if !value.is_null() {
+                    inner.validate(value, path, errors);
+                }
+            }
+        }
+    }
+}
+
+fn params_schema(params: &[AbiParam]) -> ClientResult<Value> {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for param in params {
+        let param_type = ParamType::of(param)?;
+        if !param_type.is_optional() {
+            required.push(Value::String(param.name.clone()));
+        }
+        properties.insert(param.name.clone(), param_type.schema()?);
+    }
+    Ok(json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    }))
+}
+
+fn validate_params(
+    params: &[AbiParam],
+    value: &Value,
+    path: &str,
+    errors: &mut Vec<InputValidationError>,
+) {
+    let empty = Map::new();
+    let object = match value {
+        Value::Object(object) => object,
+        // functions without parameters can be called with `null` input
+        Value::Null if params.is_empty() => &empty,
+        _ => {
+            errors.push(InputValidationError {
+                path: path.to_owned(),
+                message: format!("object expected, found {}", value),
+            });
+            return;
+        }
+    };
+    for param in params {
+        let field_path = format!("{}.{}", path, param.name);
+        let param_type = match ParamType::of(param) {
+            Ok(param_type) => param_type,
+            Err(err) => {
+                errors.push(InputValidationError {
+                    path: field_path,
+                    message: err.message,
+                });
+                continue;
+            }
+        };
+        match object.get(&param.name) {
+            Some(value) => param_type.validate(value, &field_path, errors),
+            None if param_type.is_optional() => {}
+            None => errors.push(InputValidationError {
+                path: field_path,
+                message: "required field is missing".to_owned(),
+            }),
+        }
+    }
+    for name in object.keys() {
+        if !params.iter().any(|param| &param.name == name) {
+            errors.push(InputValidationError {
+                path: format!("{}.{}", path, name),
+                message: "unknown field".to_owned(),
+            });
+        }
+    }
+}
+
+fn resolve_function(
+    context: &ClientContext,
+    abi: &Abi,
+    function_name: &str,
+) -> ClientResult<AbiFunction> {
+    let contract: AbiContract =
+        serde_json::from_str(&abi.json(context)?).map_err(|err| Error::invalid_json(err))?;
+    contract
+        .functions
+        .into_iter()
+        .find(|function| function.name == function_name)
+        .ok_or_else(|| {
+            Error::invalid_abi(format!("function `{}` is not found in the ABI", function_name))
+        })
+}
+
+//---------------------------------------------------------------------------- get_function_schema
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfGetFunctionSchema {
+    /// Contract ABI
+    pub abi: Abi,
+
+    /// Function name
+    pub function_name: String,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, Debug, PartialEq)]
+pub struct ResultOfGetFunctionSchema {
+    /// JSON schema of the function input (`CallSet::input`)
+    pub input: Value,
+
+    /// JSON schema of the function output
+    pub output: Value,
+}
+
+/// Returns JSON schemas of the function input and output.
+///
+/// Schemas follow JSON Schema draft 7 and describe the values accepted by
+/// `encode_message` and returned by `decode_message`: integers are accepted as
+/// JSON numbers or decimal/hex strings, bytes are hex strings, cells are base64 encoded BOCs,
+/// maps are objects with the keys encoded as strings, optional parameters may be `null`.
+#[api_function]
+pub async fn get_function_schema(
+    context: Arc<ClientContext>,
+    params: ParamsOfGetFunctionSchema,
+) -> ClientResult<ResultOfGetFunctionSchema> {
+    let function = resolve_function(&context, &params.abi, &params.function_name)?;
+    Ok(ResultOfGetFunctionSchema {
+        input: params_schema(&function.inputs)?,
+        output: params_schema(&function.outputs)?,
+    })
+}
+
+//--------------------------------------------------------------------------------- validate_input
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfValidateInput {
+    /// Contract ABI
+    pub abi: Abi,
+
+    /// Function name
+    pub function_name: String,
+
+    /// Function input to validate
+    pub input: Value,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, Debug, PartialEq, Clone)]
+pub struct InputValidationError {
+    /// Path to the invalid value, e.g. `input.transfers[1].dest`
+    pub path: String,
+
+    /// Mismatch description
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, Debug, PartialEq)]
+pub struct ResultOfValidateInput {
+    /// All the mismatches of the input. Empty if the input is valid
+    pub errors: Vec<InputValidationError>,
+}
+
+/// Validates the function input against the ABI.
+///
+/// Reports every mismatch of the input with the function parameters:
+/// missing and unknown fields, wrong value types, integers out of range,
+/// invalid addresses, BOCs and hex strings.
+#[api_function]
+pub async fn validate_input(
+    context: Arc<ClientContext>,
+    params: ParamsOfValidateInput,
+) -> ClientResult<ResultOfValidateInput> {
+    let function = resolve_function(&context, &params.abi, &params.function_name)?;
+    let mut errors = Vec::new();
+    validate_params(&function.inputs, &params.input, "input", &mut errors);
+    Ok(ResultOfValidateInput { errors })
+}
@@ -0,0 +1,205 @@
+use crate::abi::registry::ParsedAbi;
+use crate::abi::types::Abi;
+use crate::abi::{Error, MessageBodyType};
+use crate::boc::internal::deserialize_cell_from_boc;
+use crate::client::ClientContext;
+use crate::error::ClientResult;
+use std::sync::Arc;
+use ton_types::SliceData;
+
+fn not_found(name: &str) -> crate::error::ClientError {
+    Error::invalid_abi(format!("function or event `{}` is not found in the ABI", name))
+}
+
+// TODO: add pub access to `abi_version` field of `Contract` struct.
+fn abi_version(abi: &ParsedAbi) -> u8 {
+    abi.contract
+        .functions()
+        .values()
+        .next()
+        .map(|x| x.abi_version)
+        .unwrap_or(1)
+}
+
+//--------------------------------------------------------------------------------- get_function_id
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfGetFunctionId {
+    /// Contract ABI
+    pub abi: Abi,
+
+    /// Function or event name
+    pub name: String,
+
+    /// Return the ID of the function output (answer) message instead of the input one.
+    /// Ignored for events. Default is `false`
+    pub output: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, Debug, PartialEq)]
+pub struct ResultOfGetFunctionId {
+    /// Function or event ID
+    pub function_id: u32,
+}
+
+/// Returns the ID of the ABI function or event.
+///
+/// Explicit `id` specified in the ABI is returned if any, otherwise the ID
+/// is calculated from the function signature.
+#[api_function]
+pub async fn get_function_id(
+    context: Arc<ClientContext>,
+    params: ParamsOfGetFunctionId,
+) -> ClientResult<ResultOfGetFunctionId> {
+    let abi = params.abi.resolve(&context)?;
+    let function_id = if let Some(function) = abi.contract.functions().get(&params.name) {
+        if params.output.unwrap_or_default() {
+            function.get_output_id()
+        } else {
+            function.get_input_id()
+        }
+    } else if let Some(event) = abi.contract.events().get(&params.name) {
+        event.get_function_id()
+    } else {
+        return Err(not_found(&params.name));
+    };
+    Ok(ResultOfGetFunctionId { function_id })
+}
+
+//----------------------------------------------------------------------------------- get_signature
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfGetSignature {
+    /// Contract ABI
+    pub abi: Abi,
+
+    /// Function or event name
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, Debug, PartialEq)]
+pub struct ResultOfGetSignature {
+    /// Canonical signature, e.g. `returnValue(uint256)(uint256)v2`
+    pub signature: String,
+}
+
+/// Returns the canonical signature of the ABI function or event
+/// which is used to calculate its ID.
+#[api_function]
+pub async fn get_signature(
+    context: Arc<ClientContext>,
+    params: ParamsOfGetSignature,
+) -> ClientResult<ResultOfGetSignature> {
+    let abi = params.abi.resolve(&context)?;
+    let signature = if let Some(function) = abi.contract.functions().get(&params.name) {
+        function.get_function_signature()
+    } else if let Some(event) = abi.contract.events().get(&params.name) {
+        event.get_function_signature()
+    } else {
+        return Err(not_found(&params.name));
+    };
+    Ok(ResultOfGetSignature { signature })
+}
+
+//--------------------------------------------------------------------------- identify_message_body
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfIdentifyMessageBody {
+    /// Contract ABIs to look up the body ID in
+    pub abis: Vec<Abi>,
+
+    /// Message body BOC encoded in `base64`.
+    pub body: String,
+
+    /// True if the body belongs to the internal message.
+    pub is_internal: bool,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Debug, PartialEq, Clone)]
+pub struct IdentifiedMessageBody {
+    /// Index of the matching ABI in `abis` parameter
+    pub abi_index: u32,
+
+    /// Type of the message body content.
+    pub body_type: MessageBodyType,
+
+    /// Function or event name.
+    pub name: String,
+
+    /// Function or event ID.
+    pub function_id: u32,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, Debug, PartialEq)]
+pub struct ResultOfIdentifyMessageBody {
+    /// Functions and events matching the body ID, in the order of `abis`.
+    /// Empty if the body doesn't match any of the ABIs
+    pub matches: Vec<IdentifiedMessageBody>,
+}
+
+fn identify(
+    abi: &ParsedAbi,
+    abi_index: u32,
+    body: &SliceData,
+    is_internal: bool,
+    matches: &mut Vec<IdentifiedMessageBody>,
+) {
+    let mut add = |body_type: MessageBodyType, name: &str, function_id: u32| {
+        matches.push(IdentifiedMessageBody {
+            abi_index,
+            body_type,
+            name: name.to_owned(),
+            function_id,
+        })
+    };
+
+    // function outputs and events start with the ID
+    if let Ok(id) = body.clone().get_next_u32() {
+        for (name, function) in abi.contract.functions() {
+            if function.get_output_id() == id {
+                add(MessageBodyType::Output, name, id);
+            }
+        }
+        for (name, event) in abi.contract.events() {
+            if event.get_function_id() == id {
+                add(MessageBodyType::Event, name, id);
+            }
+        }
+    }
+
+    // function inputs of the external messages are prefixed with the signature and header
+    let header = ton_abi::Function::decode_header(
+        abi_version(abi),
+        body.clone(),
+        abi.contract.header(),
+        is_internal,
+    );
+    if let Ok((_, id, _)) = header {
+        for (name, function) in abi.contract.functions() {
+            if function.get_input_id() == id {
+                add(MessageBodyType::Input, name, id);
+            }
+        }
+    }
+}
+
+/// Identifies the function or event of the message body without decoding its parameters.
+///
+/// Looks up the body ID in all the provided ABIs at once and returns every
+/// matching function input, function output or event. Use it to route
+/// or classify messages of different contracts. Pass `Abi::Handle`s of
+/// registered ABIs to avoid ABI parsing on every call.
+#[api_function]
+pub async fn identify_message_body(
+    context: Arc<ClientContext>,
+    params: ParamsOfIdentifyMessageBody,
+) -> ClientResult<ResultOfIdentifyMessageBody> {
+    let (_, body) = deserialize_cell_from_boc(&context, &params.body, "message body").await?;
+    let body: SliceData = body.into();
+    let mut matches = Vec::new();
+    for (index, abi) in params.abis.iter().enumerate() {
+        let abi = abi.resolve(&context)?;
+        identify(&abi, index as u32, &body, params.is_internal, &mut matches);
+    }
+    Ok(ResultOfIdentifyMessageBody { matches })
+}
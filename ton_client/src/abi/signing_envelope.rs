@@ -0,0 +1,458 @@
+use crate::abi::decode_message::{decode_message, DecodedMessageBody, ParamsOfDecodeMessage};
+use crate::abi::encode_message::{attach_signature, encode_message, ParamsOfAttachSignature};
+use crate::abi::{
+    Abi, CallSet, Error, FunctionHeader, MessageBodyType, ParamsOfEncodeMessage, Signer,
+};
+use crate::boc::internal::get_boc_hash;
+use crate::client::ClientContext;
+use crate::crypto::{nacl_sign_detached_verify, ParamsOfNaclSignDetachedVerify};
+use crate::encoding::base64_decode;
+use crate::error::ClientResult;
+use serde_json::Value;
+use std::sync::Arc;
+
+const SIGNING_ENVELOPE_VERSION: u8 = 1;
+
+#[derive(Serialize, Deserialize, ApiType, Default, Clone, Debug, PartialEq)]
+pub struct SigningEnvelopeEntry {
+    /// Public key of the party allowed to sign the message. Encoded in `hex`.
+    pub public_key: String,
+
+    /// Unsigned message BOC encoded in `base64`.
+    pub message: String,
+
+    /// Data to be signed by the party, encoded in `base64`.
+    ///
+    /// If the contract ABI includes `pubkey` header, the data is different
+    /// for every public key.
+    pub data_to_sign: String,
+
+    /// Signature of the party encoded in `hex`. `null` until the party signs the message.
+    pub signature: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, Clone, Debug, PartialEq)]
+pub struct SigningEnvelope {
+    /// Envelope format version.
+    pub version: u8,
+
+    /// Destination address of the message.
+    pub address: String,
+
+    /// Name of the called function.
+    pub function_name: String,
+
+    /// Function input parameters, so the parties can check what they sign.
+    pub input: Option<Value>,
+
+    /// Message expiration time in seconds.
+    /// `null` if the contract ABI doesn't include `expire` header.
+    ///
+    /// For information only: `finalize_envelope` checks the `expire` header of the messages.
+    pub expire: Option<u32>,
+
+    /// Unsigned messages for every allowed party.
+    pub entries: Vec<SigningEnvelopeEntry>,
+}
+
+impl SigningEnvelope {
+    fn check_version(self) -> ClientResult<Self> {
+        if self.version != SIGNING_ENVELOPE_VERSION {
+            return Err(Error::invalid_signing_envelope(format!(
+                "unsupported version {}",
+                self.version
+            )));
+        }
+        Ok(self)
+    }
+
+    fn entry_mut(&mut self, public_key: &str) -> ClientResult<&mut SigningEnvelopeEntry> {
+        self.entries
+            .iter_mut()
+            .find(|entry| entry.public_key.eq_ignore_ascii_case(public_key))
+            .ok_or_else(|| {
+                Error::invalid_signing_envelope(format!(
+                    "public key {} is not allowed to sign the envelope",
+                    public_key
+                ))
+            })
+    }
+}
+
+fn check_allowed(allowed_public_keys: &[String], public_key: &str) -> ClientResult<()> {
+    if !allowed_public_keys
+        .iter()
+        .any(|allowed| allowed.eq_ignore_ascii_case(public_key))
+    {
+        return Err(Error::invalid_signing_envelope(format!(
+            "public key {} is not allowed to sign the message",
+            public_key
+        )));
+    }
+    Ok(())
+}
+
+/// Decodes the entry message with the trusted ABI and encodes the call again
+/// for the entry public key. The call is taken from the decoded message or, if
+/// specified, from the `declared_call`. Fails if the encoded message or its data
+/// to sign differ from the entry ones, so the entry can't be tampered with.
+async fn check_entry(
+    context: &Arc<ClientContext>,
+    abi: &Abi,
+    address: &str,
+    entry: &SigningEnvelopeEntry,
+    declared_call: Option<(String, Option<Value>)>,
+) -> ClientResult<DecodedMessageBody> {
+    let decoded = decode_message(
+        context.clone(),
+        ParamsOfDecodeMessage {
+            abi: abi.clone(),
+            message: entry.message.clone(),
+        },
+    )
+    .await
+    .map_err(|err| {
+        Error::invalid_signing_envelope(format!(
+            "message of {} can't be decoded: {}",
+            entry.public_key, err
+        ))
+    })?;
+    if decoded.body_type != MessageBodyType::Input {
+        return Err(Error::invalid_signing_envelope(format!(
+            "message of {} is not a function call",
+            entry.public_key
+        )));
+    }
+    let (function_name, input) =
+        declared_call.unwrap_or_else(|| (decoded.name.clone(), decoded.value.clone()));
+    // the public key header must be the one of the entry
+    let header = decoded.header.clone().map(|header| FunctionHeader {
+        pubkey: None,
+        ..header
+    });
+    let encoded = encode_message(
+        context.clone(),
+        ParamsOfEncodeMessage {
+            abi: abi.clone(),
+            address: Some(address.to_string()),
+            deploy_set: None,
+            call_set: Some(CallSet {
+                function_name,
+                header,
+                input,
+            }),
+            signer: Signer::External {
+                public_key: entry.public_key.clone(),
+            },
+            processing_try_index: None,
+        },
+    )
+    .await
+    .map_err(|err| {
+        Error::invalid_signing_envelope(format!(
+            "message of {} can't be encoded: {}",
+            entry.public_key, err
+        ))
+    })?;
+    if get_boc_hash(&base64_decode(&encoded.message)?)?
+        != get_boc_hash(&base64_decode(&entry.message)?)?
+        || encoded.data_to_sign.as_ref() != Some(&entry.data_to_sign)
+    {
+        return Err(Error::invalid_signing_envelope(format!(
+            "message of {} doesn't match the envelope",
+            entry.public_key
+        )));
+    }
+    Ok(decoded)
+}
+
+fn verify_signature(
+    context: &Arc<ClientContext>,
+    entry: &SigningEnvelopeEntry,
+    signature: &str,
+) -> ClientResult<()> {
+    let verified = nacl_sign_detached_verify(
+        context.clone(),
+        ParamsOfNaclSignDetachedVerify {
+            unsigned: entry.data_to_sign.clone(),
+            signature: signature.to_string(),
+            public: entry.public_key.clone(),
+        },
+    )
+    .map_err(|err| Error::invalid_envelope_signature(&entry.public_key, err))?;
+    if !verified.succeeded {
+        return Err(Error::invalid_envelope_signature(
+            &entry.public_key,
+            "signature verification failed",
+        ));
+    }
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfSigningEnvelope {
+    /// Signing envelope.
+    pub envelope: SigningEnvelope,
+}
+
+//------------------------------------------------------------------------ create_signing_envelope
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfCreateSigningEnvelope {
+    /// Parameters of the run message to be signed.
+    ///
+    /// `signer` is ignored: every message in the envelope is encoded with
+    /// `Signer::External` with the corresponding public key.
+    pub message_encode_params: ParamsOfEncodeMessage,
+
+    /// Public keys of the parties allowed to sign the message. Encoded in `hex`.
+    pub public_keys: Vec<String>,
+}
+
+#[doc(summary = "Creates an unsigned message envelope for the multi-party signing")]
+/// Encodes the unsigned run message for every allowed public key and returns
+/// the portable envelope with the messages and the data to sign.
+///
+/// All the messages share the same `time` and `expire` headers. Default message
+/// expiration timeout is usually too short to collect signatures from several parties,
+/// so specify `expire` in `call_set.header` explicitly.
+///
+/// The envelope is a plain JSON object, so it can be transferred to the air-gapped
+/// machines. Parties sign their `data_to_sign` with `sign_envelope` or any other
+/// ed25519 signer and the signatures are collected with `add_envelope_signature`.
+/// Signed messages are produced with `finalize_envelope`.
+#[api_function]
+pub async fn create_signing_envelope(
+    context: Arc<ClientContext>,
+    params: ParamsOfCreateSigningEnvelope,
+) -> ClientResult<ResultOfSigningEnvelope> {
+    let mut encode_params = params.message_encode_params;
+    if encode_params.deploy_set.is_some() {
+        return Err(Error::invalid_signing_envelope(
+            "deploy messages are not supported",
+        ));
+    }
+    if encode_params.address.is_none() {
+        return Err(Error::required_address_missing_for_encode_message());
+    }
+    if params.public_keys.is_empty() {
+        return Err(Error::invalid_signing_envelope("no public keys specified"));
+    }
+    let has_expire = encode_params
+        .abi
+        .resolve(&context)?
+        .contract
+        .header()
+        .iter()
+        .any(|param| param.name == "expire");
+    let call_set = encode_params
+        .call_set
+        .as_mut()
+        .ok_or_else(Error::missing_required_call_set_for_encode_message)?;
+    let now = context.env.now_ms();
+    let header = call_set.header.take().unwrap_or_default();
+    let header = FunctionHeader {
+        time: Some(header.time.unwrap_or(now)),
+        expire: Some(header.expire.unwrap_or_else(|| {
+            ((now + context.config.abi.message_expiration_timeout as u64) / 1000) as u32
+        })),
+        pubkey: None,
+    };
+    let mut envelope = SigningEnvelope {
+        version: SIGNING_ENVELOPE_VERSION,
+        address: String::new(),
+        function_name: call_set.function_name.clone(),
+        input: call_set.input.clone(),
+        expire: if has_expire { header.expire } else { None },
+        entries: Vec::new(),
+    };
+    call_set.header = Some(header);
+
+    for public_key in params.public_keys {
+        let mut encode_params = encode_params.clone();
+        encode_params.signer = Signer::External {
+            public_key: public_key.clone(),
+        };
+        encode_params.processing_try_index = None;
+        let encoded = encode_message(context.clone(), encode_params).await?;
+        envelope.address = encoded.address;
+        envelope.entries.push(SigningEnvelopeEntry {
+            public_key,
+            message: encoded.message,
+            data_to_sign: encoded.data_to_sign.unwrap_or_default(),
+            signature: None,
+        });
+    }
+    Ok(ResultOfSigningEnvelope { envelope })
+}
+
+//---------------------------------------------------------------------------------- sign_envelope
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfSignEnvelope {
+    /// Contract ABI.
+    pub abi: Abi,
+
+    /// Signing envelope.
+    pub envelope: SigningEnvelope,
+
+    /// Signer of the party. Must be `Keys` or `SigningBox`.
+    pub signer: Signer,
+}
+
+/// Signs the envelope entry of the signer public key and returns the updated envelope.
+///
+/// Fails if the entry message doesn't call the envelope function with the envelope
+/// input or its data to sign doesn't match the message.
+#[api_function]
+pub async fn sign_envelope(
+    context: Arc<ClientContext>,
+    params: ParamsOfSignEnvelope,
+) -> ClientResult<ResultOfSigningEnvelope> {
+    let mut envelope = params.envelope.check_version()?;
+    let public_key = match &params.signer {
+        Signer::Keys { .. } | Signer::SigningBox { .. } => params
+            .signer
+            .resolve_public_key(context.clone())
+            .await?
+            .unwrap_or_default(),
+        _ => {
+            return Err(Error::invalid_signer(
+                "Envelope can be signed with `Keys` or `SigningBox` signer only".into(),
+            ))
+        }
+    };
+    let declared_call = (envelope.function_name.clone(), envelope.input.clone());
+    let address = envelope.address.clone();
+    let entry = envelope.entry_mut(&public_key)?;
+    check_entry(&context, &params.abi, &address, entry, Some(declared_call)).await?;
+    let signature = params
+        .signer
+        .sign(context.clone(), &base64_decode(&entry.data_to_sign)?)
+        .await?
+        .unwrap_or_default();
+    entry.signature = Some(hex::encode(&signature));
+    Ok(ResultOfSigningEnvelope { envelope })
+}
+
+//------------------------------------------------------------------------- add_envelope_signature
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfAddEnvelopeSignature {
+    /// Contract ABI.
+    pub abi: Abi,
+
+    /// Public keys of the parties allowed to sign the message. Encoded in `hex`.
+    pub allowed_public_keys: Vec<String>,
+
+    /// Signing envelope.
+    pub envelope: SigningEnvelope,
+
+    /// Public key of the signing party encoded in `hex`.
+    pub public_key: String,
+
+    /// Signature of the entry `data_to_sign` encoded in `hex`.
+    pub signature: String,
+}
+
+/// Verifies the signature made offline and adds it to the envelope.
+///
+/// Fails if the public key is not allowed or not in the envelope, the entry message
+/// doesn't match its data to sign or the signature doesn't match the data to sign.
+#[api_function]
+pub async fn add_envelope_signature(
+    context: Arc<ClientContext>,
+    params: ParamsOfAddEnvelopeSignature,
+) -> ClientResult<ResultOfSigningEnvelope> {
+    check_allowed(&params.allowed_public_keys, &params.public_key)?;
+    let mut envelope = params.envelope.check_version()?;
+    let address = envelope.address.clone();
+    let entry = envelope.entry_mut(&params.public_key)?;
+    check_entry(&context, &params.abi, &address, entry, None).await?;
+    verify_signature(&context, entry, &params.signature)?;
+    entry.signature = Some(params.signature);
+    Ok(ResultOfSigningEnvelope { envelope })
+}
+
+//------------------------------------------------------------------------------ finalize_envelope
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfFinalizeEnvelope {
+    /// Contract ABI.
+    pub abi: Abi,
+
+    /// Public keys of the parties allowed to sign the message. Encoded in `hex`.
+    pub allowed_public_keys: Vec<String>,
+
+    /// Signing envelope.
+    pub envelope: SigningEnvelope,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, Clone, Debug, PartialEq)]
+pub struct SignedEnvelopeMessage {
+    /// Public key of the signing party encoded in `hex`.
+    pub public_key: String,
+
+    /// Signed message BOC encoded in `base64`.
+    pub message: String,
+
+    /// Message ID.
+    pub message_id: String,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfFinalizeEnvelope {
+    /// Signed messages in the order of the signed envelope entries.
+    pub messages: Vec<SignedEnvelopeMessage>,
+}
+
+#[doc(summary = "Produces signed messages from the envelope")]
+/// Verifies all the collected signatures and attaches them to the messages.
+///
+/// Entries without signatures are skipped. Fails if there are no signatures,
+/// any signed entry has a public key that is not allowed, a message that doesn't match
+/// its data to sign, an invalid signature or an expired message.
+#[api_function]
+pub async fn finalize_envelope(
+    context: Arc<ClientContext>,
+    params: ParamsOfFinalizeEnvelope,
+) -> ClientResult<ResultOfFinalizeEnvelope> {
+    let envelope = params.envelope.check_version()?;
+    let mut messages = Vec::new();
+    for entry in &envelope.entries {
+        let signature = match &entry.signature {
+            Some(signature) => signature,
+            None => continue,
+        };
+        check_allowed(&params.allowed_public_keys, &entry.public_key)?;
+        let decoded = check_entry(&context, &params.abi, &envelope.address, entry, None).await?;
+        if let Some(expire) = decoded.header.and_then(|header| header.expire) {
+            if expire as u64 * 1000 <= context.env.now_ms() {
+                return Err(Error::invalid_signing_envelope(format!(
+                    "message of {} expired at {}",
+                    entry.public_key, expire
+                )));
+            }
+        }
+        verify_signature(&context, entry, signature)?;
+        let signed = attach_signature(
+            context.clone(),
+            ParamsOfAttachSignature {
+                abi: params.abi.clone(),
+                public_key: entry.public_key.clone(),
+                message: entry.message.clone(),
+                signature: signature.clone(),
+            },
+        )
+        .await?;
+        messages.push(SignedEnvelopeMessage {
+            public_key: entry.public_key.clone(),
+            message: signed.message,
+            message_id: signed.message_id,
+        });
+    }
+    if messages.is_empty() {
+        return Err(Error::invalid_signing_envelope("envelope is not signed"));
+    }
+    Ok(ResultOfFinalizeEnvelope { messages })
+}
@@ -5,6 +5,11 @@ use crate::abi::encode_message::{
 };
 use crate::abi::internal::{is_empty_pubkey, resolve_pubkey, create_tvc_image};
 use crate::abi::{FunctionHeader, ParamsOfDecodeMessageBody, Signer};
+use crate::abi::{KnownAbi, ParamsOfDecodeMessageMulti, ResultOfDecodeMessageMulti};
+use crate::abi::{
+    IdentifiedMessageBody, ParamsOfGetFunctionId, ParamsOfGetSignature, ParamsOfIdentifyMessageBody,
+    ResultOfGetFunctionId, ResultOfGetSignature, ResultOfIdentifyMessageBody,
+};
 use crate::boc::internal::{get_boc_hash, serialize_object_to_base64};
 use crate::boc::{ParamsOfParse, ResultOfGetCodeFromTvc, ParamsOfGetCodeFromTvc};
 use crate::crypto::KeyPair;
@@ -356,6 +361,731 @@ fn decode_v2() {
     assert_eq!(expected, decode_events("te6ccgEBAQEAVQAApeACvg5/pmQpY4m61HmJ0ne+zjHJu3MNG8rJxUDLbHKBu/AAAAAAAAAMKr6z6rxK3xYJAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABA"));
 }
 
+#[test]
+fn abi_handles() {
+    let client = TestClient::new();
+    let (events_abi, _events_tvc) = TestClient::package(EVENTS, Some(2));
+
+    let registered: RegisteredAbi = client
+        .request("abi.register_abi", ParamsOfRegisterAbi { abi: events_abi })
+        .unwrap();
+    let params = || ParamsOfDecodeMessage {
+        abi: Abi::Handle(registered.handle.clone()),
+        message: "te6ccgEBAQEAVQAApeACvg5/pmQpY4m61HmJ0ne+zjHJu3MNG8rJxUDLbHKBu/AAAAAAAAAMJL6z6ro48sYvAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABA".into(),
+    };
+    let result: DecodedMessageBody = client.request("abi.decode_message", params()).unwrap();
+    assert_eq!(result.body_type, MessageBodyType::Event);
+    assert_eq!(result.name, "EventThrown");
+
+    // registered handle can be used to encode messages as well
+    let _: ResultOfEncodeMessage = client
+        .request(
+            "abi.encode_message",
+            ParamsOfEncodeMessage {
+                abi: Abi::Handle(registered.handle.clone()),
+                address: Some(
+                    "0:1111111111111111111111111111111111111111111111111111111111111111".into(),
+                ),
+                call_set: CallSet::some_with_function_and_input("returnValue", json!({ "id": "0" })),
+                signer: Signer::None,
+                deploy_set: None,
+                processing_try_index: None,
+            },
+        )
+        .unwrap();
+
+    let _: () = client.request("abi.unregister_abi", registered.clone()).unwrap();
+    let error = client
+        .request::<_, DecodedMessageBody>("abi.decode_message", params())
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::AbiNotRegistered as u32);
+}
+
+#[test]
+fn initial_data() {
+    let client = TestClient::new();
+    let abi = Abi::Json(
+        json!({
+            "ABI version": 2,
+            "functions": [],
+            "events": [],
+            "data": [
+                { "key": 1, "name": "a", "type": "uint256" },
+                { "key": 2, "name": "b", "type": "uint32" },
+            ],
+        })
+        .to_string(),
+    );
+    let pubkey = "4c7c408ff1ddebb8d6405ee979c716a14fdd6cc08124107a61d3c25597099499";
+
+    let updated: ResultOfUpdateInitialData = client
+        .request(
+            "abi.update_initial_data",
+            ParamsOfUpdateInitialData {
+                abi: Some(abi.clone()),
+                data: "te6ccgEBAQEAAgAAAA==".into(),
+                initial_data: Some(json!({ "a": "123", "b": "7" })),
+                initial_pubkey: Some(pubkey.into()),
+                boc_cache: None,
+            },
+        )
+        .unwrap();
+
+    let decoded: ResultOfDecodeInitialData = client
+        .request(
+            "abi.decode_initial_data",
+            ParamsOfDecodeInitialData {
+                abi: Some(abi),
+                data: updated.data.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(decoded.initial_pubkey.as_deref(), Some(pubkey));
+    assert_eq!(
+        decoded.initial_data,
+        Some(json!({ "a": abi_uint(123, 256), "b": abi_uint(7, 32) }))
+    );
+
+    let decoded: ResultOfDecodeInitialData = client
+        .request(
+            "abi.decode_initial_data",
+            ParamsOfDecodeInitialData {
+                abi: None,
+                data: updated.data,
+            },
+        )
+        .unwrap();
+    assert_eq!(decoded.initial_pubkey.as_deref(), Some(pubkey));
+    assert_eq!(decoded.initial_data, None);
+}
+
+#[test]
+fn account_data() {
+    let client = TestClient::new();
+    let abi = Abi::Json(
+        json!({
+            "ABI version": 2,
+            "version": "2.1",
+            "header": ["time"],
+            "functions": [],
+            "events": [],
+            "data": [],
+            "fields": [
+                { "name": "_pubkey", "type": "uint256" },
+                { "name": "_timestamp", "type": "uint64" },
+                { "name": "_constructorFlag", "type": "bool" },
+                { "name": "counter", "type": "uint32" },
+                { "name": "limit", "type": "uint128" },
+            ],
+        })
+        .to_string(),
+    );
+
+    // data of the deployed contract: constructor flag is set
+    let pubkey = [0x4cu8; 32];
+    let mut builder = ton_types::BuilderData::new();
+    builder
+        .append_raw(&pubkey, 256)
+        .unwrap()
+        .append_u64(1620000000000)
+        .unwrap()
+        .append_bit_one()
+        .unwrap()
+        .append_u32(42)
+        .unwrap()
+        .append_u128(1000000000)
+        .unwrap();
+    let data = crate::boc::internal::serialize_cell_to_base64(&builder.into_cell().unwrap(), "data")
+        .unwrap();
+
+    let decoded: ResultOfDecodeData = client
+        .request(
+            "abi.decode_account_data",
+            ParamsOfDecodeAccountData {
+                abi: abi.clone(),
+                data,
+            },
+        )
+        .unwrap();
+    let pubkey = ton_abi::TokenValue::Uint(ton_abi::Uint {
+        number: num_bigint::BigUint::from_bytes_be(&pubkey),
+        size: 256,
+    });
+    assert_eq!(
+        decoded.data,
+        json!({
+            "_pubkey": serde_json::to_value(pubkey).unwrap(),
+            "_timestamp": abi_uint(1620000000000, 64),
+            "_constructorFlag": true,
+            "counter": abi_uint(42, 32),
+            "limit": abi_uint(1000000000, 128),
+        })
+    );
+
+    let error = client
+        .request::<_, ResultOfDecodeData>(
+            "abi.decode_account_data",
+            ParamsOfDecodeAccountData {
+                abi,
+                data: "te6ccgEBAQEAAgAAAA==".into(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidData as u32);
+}
+
+#[test]
+fn function_schema_and_input_validation() {
+    let client = TestClient::new();
+    let abi = Abi::Json(
+        json!({
+            "ABI version": 2,
+            "functions": [{
+                "name": "transfer",
+                "inputs": [
+                    { "name": "dest", "type": "address" },
+                    { "name": "value", "type": "uint8" },
+                    { "name": "payload", "type": "optional(cell)" },
+                    {
+                        "name": "parts",
+                        "type": "tuple[]",
+                        "components": [
+                            { "name": "share", "type": "int8" },
+                            { "name": "memo", "type": "bytes" },
+                        ],
+                    },
+                    { "name": "limits", "type": "map(uint32,bool)" },
+                ],
+                "outputs": [{ "name": "ok", "type": "bool" }],
+            }],
+            "events": [],
+            "data": [],
+        })
+        .to_string(),
+    );
+
+    let schema: ResultOfGetFunctionSchema = client
+        .request(
+            "abi.get_function_schema",
+            ParamsOfGetFunctionSchema {
+                abi: abi.clone(),
+                function_name: "transfer".into(),
+            },
+        )
+        .unwrap();
+    assert_eq!(schema.input["required"], json!(["dest", "value", "parts", "limits"]));
+    assert_eq!(schema.input["properties"]["parts"]["items"]["properties"]["memo"]["type"], "string");
+    assert_eq!(schema.input["properties"]["limits"]["additionalProperties"], json!({ "type": "boolean" }));
+    assert_eq!(schema.output["properties"]["ok"], json!({ "type": "boolean" }));
+
+    let validate = |input: Value| -> Vec<String> {
+        let result: ResultOfValidateInput = client
+            .request(
+                "abi.validate_input",
+                ParamsOfValidateInput {
+                    abi: abi.clone(),
+                    function_name: "transfer".into(),
+                    input,
+                },
+            )
+            .unwrap();
+        result.errors.into_iter().map(|error| error.path).collect()
+    };
+
+    assert!(validate(json!({
+        "dest": "0:1111111111111111111111111111111111111111111111111111111111111111",
+        "value": "0xff",
+        "parts": [{ "share": -128, "memo": "00ff" }],
+        "limits": { "1": true },
+    }))
+    .is_empty());
+
+    assert_eq!(
+        validate(json!({
+            "dest": "not an address",
+            "value": 256,
+            "payload": "not a boc",
+            "parts": [{ "share": 1, "memo": "00" }, { "share": 128 }],
+            "limits": { "key": "yes" },
+            "extra": 1,
+        })),
+        vec![
+            "input.dest",
+            "input.value",
+            "input.payload",
+            "input.parts[1].share",
+            "input.parts[1].memo",
+            "input.limits[\"key\"]",
+            "input.limits[\"key\"]",
+            "input.extra",
+        ]
+    );
+
+    for param_type in &[
+        "varuint0",
+        "varint0",
+        "varuint1",
+        "varint64",
+        "varuint4294967296",
+        "uint0",
+        "uint257",
+        "uint99999999999",
+        "int0",
+        "int99999999999",
+        "fixedbytes0",
+        "fixedbytes33",
+        "fixedbytes18446744073709551615",
+    ] {
+        let abi = Abi::Json(
+            json!({
+                "ABI version": 2,
+                "functions": [{
+                    "name": "transfer",
+                    "inputs": [{ "name": "value", "type": param_type }],
+                    "outputs": [],
+                }],
+                "events": [],
+                "data": [],
+            })
+            .to_string(),
+        );
+        let error = client
+            .request::<_, ResultOfGetFunctionSchema>(
+                "abi.get_function_schema",
+                ParamsOfGetFunctionSchema {
+                    abi,
+                    function_name: "transfer".into(),
+                },
+            )
+            .unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidAbi as u32, "{}", param_type);
+    }
+}
+
+#[test]
+fn function_ids_and_signatures() {
+    let client = TestClient::new();
+    let (events_abi, _events_tvc) = TestClient::package(EVENTS, Some(2));
+    let (hello_abi, _hello_tvc) = TestClient::package(HELLO, Some(2));
+
+    let signature: ResultOfGetSignature = client
+        .request(
+            "abi.get_signature",
+            ParamsOfGetSignature {
+                abi: events_abi.clone(),
+                name: "returnValue".into(),
+            },
+        )
+        .unwrap();
+    assert_eq!(signature.signature, "returnValue(uint256)(uint256)v2");
+
+    let function_id = |name: &str, output: bool| {
+        let result: ResultOfGetFunctionId = client
+            .request(
+                "abi.get_function_id",
+                ParamsOfGetFunctionId {
+                    abi: events_abi.clone(),
+                    name: name.into(),
+                    output: Some(output),
+                },
+            )
+            .unwrap();
+        result.function_id
+    };
+    let input_id = function_id("returnValue", false);
+    let output_id = function_id("returnValue", true);
+    let event_id = function_id("EventThrown", false);
+    assert_eq!(input_id & 0x7FFFFFFF, input_id);
+    assert_eq!(output_id, input_id | 0x80000000);
+
+    let unknown = client.request::<_, ResultOfGetFunctionId>(
+        "abi.get_function_id",
+        ParamsOfGetFunctionId {
+            abi: events_abi.clone(),
+            name: "unknown".into(),
+            output: None,
+        },
+    );
+    assert!(unknown.is_err());
+
+    let identify = |body: &str, is_internal: bool| {
+        let result: ResultOfIdentifyMessageBody = client
+            .request(
+                "abi.identify_message_body",
+                ParamsOfIdentifyMessageBody {
+                    abis: vec![hello_abi.clone(), events_abi.clone()],
+                    body: body.into(),
+                    is_internal,
+                },
+            )
+            .unwrap();
+        result.matches
+    };
+    let message_body = |message: &str| {
+        let parsed: ResultOfParse = client
+            .request("boc.parse_message", ParamsOfParse { boc: message.into() })
+            .unwrap();
+        parsed.parsed["body"].as_str().unwrap().to_owned()
+    };
+
+    assert_eq!(
+        identify("te6ccgEBAgEAlgAB4a3f2/jCeWWvgMoAXOakv3VSD56sQrDPT76n1cbrSvpZ0BCs0KEUy2Duvo3zPExePONW3TYy0MCA1i+FFRXcSIXTHxAj/Hd67jWQF7peccWoU/dbMCBJBB6YdPCVZcJlJkAAAF0ZyXLg19VzGQVviwSgAQBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=", false),
+        vec![IdentifiedMessageBody {
+            abi_index: 1,
+            body_type: MessageBodyType::Input,
+            name: "returnValue".into(),
+            function_id: input_id,
+        }]
+    );
+    assert_eq!(
+        identify(&message_body("te6ccgEBAQEAVQAApeACvg5/pmQpY4m61HmJ0ne+zjHJu3MNG8rJxUDLbHKBu/AAAAAAAAAMJL6z6ro48sYvAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABA"), false),
+        vec![IdentifiedMessageBody {
+            abi_index: 1,
+            body_type: MessageBodyType::Event,
+            name: "EventThrown".into(),
+            function_id: event_id,
+        }]
+    );
+    assert_eq!(
+        identify(&message_body("te6ccgEBAQEAVQAApeACvg5/pmQpY4m61HmJ0ne+zjHJu3MNG8rJxUDLbHKBu/AAAAAAAAAMKr6z6rxK3xYJAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABA"), false),
+        vec![IdentifiedMessageBody {
+            abi_index: 1,
+            body_type: MessageBodyType::Output,
+            name: "returnValue".into(),
+            function_id: output_id,
+        }]
+    );
+}
+
+#[test]
+fn decode_with_multiple_abis() {
+    let client = TestClient::new();
+    let (events_abi, _events_tvc) = TestClient::package(EVENTS, Some(2));
+    let (hello_abi, _hello_tvc) = TestClient::package(HELLO, Some(2));
+
+    let decode = |abis: Vec<KnownAbi>, code_hash: Option<&str>| {
+        let result: ResultOfDecodeMessageMulti = client
+            .request(
+                "abi.decode_message_multi",
+                ParamsOfDecodeMessageMulti {
+                    abis,
+                    message: "te6ccgEBAQEAVQAApeACvg5/pmQpY4m61HmJ0ne+zjHJu3MNG8rJxUDLbHKBu/AAAAAAAAAMJL6z6ro48sYvAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABA".into(),
+                    code_hash: code_hash.map(|x| x.to_owned()),
+                },
+            )
+            .unwrap();
+        result.matched
+    };
+    let known = |abi: &Abi, name: &str, code_hash: Option<&str>| KnownAbi {
+        abi: abi.clone(),
+        name: Some(name.into()),
+        code_hash: code_hash.map(|x| x.to_owned()),
+    };
+
+    assert_eq!(decode(vec![known(&hello_abi, "Hello", None)], None), None);
+
+    let matched = decode(
+        vec![
+            known(&hello_abi, "Hello", None),
+            known(&events_abi, "Events", None),
+        ],
+        None,
+    )
+    .unwrap();
+    assert_eq!(matched.abi_index, 1);
+    assert_eq!(matched.contract_name, Some("Events".into()));
+    assert_eq!(matched.decoded.body_type, MessageBodyType::Event);
+    assert_eq!(matched.decoded.name, "EventThrown");
+
+    // ABI with the matching code hash is tried first
+    let matched = decode(
+        vec![
+            known(&events_abi, "Events", None),
+            known(&events_abi, "EventsCopy", Some("AB01")),
+        ],
+        Some("ab01"),
+    )
+    .unwrap();
+    assert_eq!(matched.abi_index, 1);
+    assert_eq!(matched.contract_name, Some("EventsCopy".into()));
+}
+
+#[tokio::test(core_threads = 2)]
+async fn decode_transaction_with_abi() {
+    let emulator = crate::net::BlockchainEmulator::new("abi_decode_transaction", None)
+        .await
+        .unwrap();
+    let client = TestClient::new_with_config(json!({
+        "network": {
+            "endpoints": [emulator.endpoint()],
+        }
+    }));
+    let keys = client.generate_sign_keys();
+    let (abi, tvc) = TestClient::package(EVENTS, Some(2));
+    let deploy_params = ParamsOfEncodeMessage {
+        abi: abi.clone(),
+        address: None,
+        deploy_set: DeploySet::some_with_tvc(tvc),
+        call_set: Some(CallSet {
+            function_name: "constructor".into(),
+            header: Some(FunctionHeader {
+                pubkey: Some(keys.public.clone()),
+                ..Default::default()
+            }),
+            input: None,
+        }),
+        signer: Signer::Keys { keys: keys.clone() },
+        processing_try_index: None,
+    };
+    let address = client.encode_message(deploy_params.clone()).await.unwrap().address;
+    emulator.topup(&address, 1_000_000_000).await.unwrap();
+    client
+        .net_process_message(
+            crate::processing::ParamsOfProcessMessage {
+                message_encode_params: deploy_params,
+                send_events: false,
+                send_state_events: None,
+            },
+            TestClient::default_callback,
+        )
+        .await
+        .unwrap();
+
+    let processed = client
+        .net_process_function(
+            address.clone(),
+            abi.clone(),
+            "returnValue",
+            json!({ "id": "0x1" }),
+            Signer::Keys { keys },
+        )
+        .await
+        .unwrap();
+
+    let result: ResultOfDecodeTransaction = client
+        .request_async(
+            "abi.decode_transaction",
+            ParamsOfDecodeTransaction {
+                abis: vec![KnownAbi {
+                    abi: abi.clone(),
+                    name: Some("Events".into()),
+                    code_hash: None,
+                }],
+                transaction: processed.transaction["boc"].as_str().unwrap().to_owned(),
+                code_hash: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.transaction["id"], processed.transaction["id"]);
+    assert_eq!(result.in_msg.unwrap().decoded.name, "returnValue");
+    assert_eq!(result.out_msgs.len(), 2);
+    assert_eq!(result.events.len(), 1);
+    assert_eq!(result.events[0].decoded.name, "EventThrown");
+    assert_eq!(result.events[0].contract_name, Some("Events".into()));
+    assert_eq!(result.output, Some(json!({ "value0": abi_uint(1, 256) })));
+    assert!(!result.aborted);
+    assert_eq!(result.exit_code, Some(0));
+    assert_eq!(result.fees, processed.fees);
+
+    let tree: crate::net::ResultOfQueryTransactionTree = client
+        .request_async(
+            "net.query_transaction_tree",
+            crate::net::ParamsOfQueryTransactionTree {
+                in_msg: processed.transaction["in_msg"].as_str().unwrap().to_owned(),
+                abi_registry: None,
+            },
+        )
+        .await
+        .unwrap();
+    let result: ResultOfDecodeTransactionTree = client
+        .request_async(
+            "abi.decode_transaction_tree",
+            ParamsOfDecodeTransactionTree {
+                abis: vec![KnownAbi {
+                    abi: abi.clone(),
+                    name: Some("Events".into()),
+                    code_hash: None,
+                }],
+                transactions: tree.transactions,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.transactions.len(), 1);
+    let decoded = &result.transactions[0];
+    assert_eq!(decoded.transaction["id"], processed.transaction["id"]);
+    assert_eq!(decoded.events[0].decoded.name, "EventThrown");
+    assert_eq!(decoded.output, Some(json!({ "value0": abi_uint(1, 256) })));
+}
+
+#[tokio::test(core_threads = 2)]
+async fn signing_envelope() {
+    let client = TestClient::new();
+    let (abi, _tvc) = TestClient::package(EVENTS, Some(2));
+    let keys1 = client.generate_sign_keys();
+    let keys2 = client.generate_sign_keys();
+    let keys3 = client.generate_sign_keys();
+    let address = "0:1111111111111111111111111111111111111111111111111111111111111111";
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+    let header = FunctionHeader {
+        time: Some(now),
+        expire: Some((now / 1000) as u32 + 3600),
+        pubkey: None,
+    };
+    let encode_params = |signer: Signer| ParamsOfEncodeMessage {
+        abi: abi.clone(),
+        address: Some(address.into()),
+        deploy_set: None,
+        call_set: Some(CallSet {
+            function_name: "returnValue".into(),
+            header: Some(header.clone()),
+            input: Some(json!({ "id": abi_uint(0, 256) })),
+        }),
+        signer,
+        processing_try_index: None,
+    };
+
+    let envelope = client
+        .request_async::<_, ResultOfSigningEnvelope>(
+            "abi.create_signing_envelope",
+            ParamsOfCreateSigningEnvelope {
+                message_encode_params: encode_params(Signer::None),
+                public_keys: vec![keys1.public.clone(), keys2.public.clone()],
+            },
+        )
+        .await
+        .unwrap()
+        .envelope;
+    assert_eq!(envelope.entries.len(), 2);
+    assert_eq!(envelope.expire, header.expire);
+    assert_eq!(envelope.function_name, "returnValue");
+    assert_ne!(envelope.entries[0].data_to_sign, envelope.entries[1].data_to_sign);
+    let allowed_public_keys = vec![keys1.public.clone(), keys2.public.clone()];
+
+    // the 1st party signs with the keys, the 2nd one signs offline
+    let sign_envelope = |envelope: SigningEnvelope| {
+        client.request_async::<_, ResultOfSigningEnvelope>(
+            "abi.sign_envelope",
+            ParamsOfSignEnvelope {
+                abi: abi.clone(),
+                envelope,
+                signer: Signer::Keys { keys: keys1.clone() },
+            },
+        )
+    };
+    let mut tampered = envelope.clone();
+    tampered.input = Some(json!({ "id": abi_uint(1, 256) }));
+    let error = sign_envelope(tampered).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidSigningEnvelope as u32);
+    let mut tampered = envelope.clone();
+    tampered.entries[0].data_to_sign = envelope.entries[1].data_to_sign.clone();
+    let error = sign_envelope(tampered).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidSigningEnvelope as u32);
+    let envelope = sign_envelope(envelope).await.unwrap().envelope;
+    let sign = |keys: &KeyPair, data_to_sign: &str| {
+        client
+            .request::<_, crate::crypto::ResultOfSign>(
+                "crypto.sign",
+                crate::crypto::ParamsOfSign {
+                    unsigned: data_to_sign.into(),
+                    keys: keys.clone(),
+                },
+            )
+            .unwrap()
+            .signature
+    };
+    let add_signature = |envelope: &SigningEnvelope, public_key: &str, signature: String| {
+        client.request_async::<_, ResultOfSigningEnvelope>(
+            "abi.add_envelope_signature",
+            ParamsOfAddEnvelopeSignature {
+                abi: abi.clone(),
+                allowed_public_keys: allowed_public_keys.clone(),
+                envelope: envelope.clone(),
+                public_key: public_key.into(),
+                signature,
+            },
+        )
+    };
+    let error = add_signature(
+        &envelope,
+        &keys3.public,
+        sign(&keys3, &envelope.entries[1].data_to_sign),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidSigningEnvelope as u32);
+    let error = add_signature(
+        &envelope,
+        &keys2.public,
+        sign(&keys1, &envelope.entries[1].data_to_sign),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidEnvelopeSignature as u32);
+
+    // the envelope can't substitute the message or the data to sign of the entry
+    let mut tampered = envelope.clone();
+    tampered.entries[1].message = envelope.entries[0].message.clone();
+    let error = add_signature(
+        &tampered,
+        &keys2.public,
+        sign(&keys2, &envelope.entries[1].data_to_sign),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidSigningEnvelope as u32);
+    let mut tampered = envelope.clone();
+    tampered.entries[1].data_to_sign = envelope.entries[0].data_to_sign.clone();
+    let error = add_signature(
+        &tampered,
+        &keys2.public,
+        sign(&keys2, &envelope.entries[0].data_to_sign),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidSigningEnvelope as u32);
+
+    let envelope = add_signature(
+        &envelope,
+        &keys2.public,
+        sign(&keys2, &envelope.entries[1].data_to_sign),
+    )
+    .await
+    .unwrap()
+    .envelope;
+
+    let finalize = |allowed_public_keys: Vec<String>| {
+        client.request_async::<_, ResultOfFinalizeEnvelope>(
+            "abi.finalize_envelope",
+            ParamsOfFinalizeEnvelope {
+                abi: abi.clone(),
+                allowed_public_keys,
+                envelope: envelope.clone(),
+            },
+        )
+    };
+    let error = finalize(vec![keys1.public.clone()]).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidSigningEnvelope as u32);
+    let finalized = finalize(allowed_public_keys.clone()).await.unwrap();
+    assert_eq!(finalized.messages.len(), 2);
+    for (message, keys) in finalized.messages.iter().zip(&[keys1, keys2]) {
+        let expected: ResultOfEncodeMessage = client
+            .request_async(
+                "abi.encode_message",
+                encode_params(Signer::Keys { keys: keys.clone() }),
+            )
+            .await
+            .unwrap();
+        assert_eq!(message.public_key, keys.public);
+        assert_eq!(message.message, expected.message);
+        assert_eq!(message.message_id, expected.message_id);
+    }
+}
+
 #[test]
 fn test_is_empty_pubkey() -> Result<()> {
     let pubkey = ed25519_dalek::PublicKey::from_bytes(&[0; 32])?;
@@ -381,7 +1111,8 @@ async fn test_resolve_pubkey() -> Result<()> {
         tvc: tvc.clone(),
         ..Default::default()
     };
-    let mut image = create_tvc_image(&context, "", None, &tvc).await?;
+    let abi = TestClient::abi(HELLO, Some(2)).resolve(&context)?;
+    let mut image = create_tvc_image(&context, &abi.contract, None, &tvc).await?;
     assert!(resolve_pubkey(&deploy_set, &image, &None )?.is_none());
 
     let external_pub_key = Some("1234567890ABCDEF1234567890ABCDEF1234567890ABCDEF1234567890ABCDEF".to_owned());
@@ -494,7 +1225,7 @@ async fn test_encode_message_pubkey_internal(
     expected_pubkey: &Option<ed25519_dalek::PublicKey>,
 ) -> Result<()> {
     let context = crate::ClientContext::new(crate::ClientConfig::default()).unwrap();
-    let mut image = create_tvc_image(&context, &abi.json_string()?, None, &tvc).await?;
+    let mut image = create_tvc_image(&context, &abi.resolve(&context)?.contract, None, &tvc).await?;
     if let Some(tvc_pubkey) = tvc_pubkey {
         image.set_public_key(tvc_pubkey)?;
     }
@@ -546,7 +1277,7 @@ async fn test_encode_internal_message() -> Result<()> {
     let contract = Contract::load(abi.json_string().unwrap().as_bytes()).unwrap();
     let func_id = contract.function("sayHello").unwrap().get_input_id();
     let context = crate::ClientContext::new(crate::ClientConfig::default()).unwrap();
-    let image = create_tvc_image(&context, &abi.json_string()?, None, &tvc).await?;
+    let image = create_tvc_image(&context, &abi.resolve(&context)?.contract, None, &tvc).await?;
     let address = String::from("0:1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef");
 
     test_encode_internal_message_deploy(
@@ -1,3 +1,4 @@
+use crate::abi::registry::ParsedAbi;
 use crate::abi::{Error, ParamsOfEncodeMessage};
 use crate::error::ClientResult;
 use crate::{processing, ClientContext};
@@ -5,7 +6,7 @@ use std::sync::Arc;
 use ton_abi::{Token, TokenValue};
 
 #[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default)]
-pub struct AbiHandle(u32);
+pub struct AbiHandle(pub u32);
 
 #[derive(Serialize, Deserialize, Clone, Debug, ApiType)]
 #[serde(tag = "type", content = "value")]
@@ -24,17 +25,39 @@ impl Default for Abi {
 }
 
 impl Abi {
+    /// Returns JSON of the ABI passed by value. Handles must be resolved with `json` or `resolve`.
     pub(crate) fn json_string(&self) -> ClientResult<String> {
         match self {
             Self::Contract(abi) | Self::Serialized(abi) => {
                 Ok(serde_json::to_string(abi).map_err(|err| Error::invalid_abi(err))?)
             }
             Self::Json(abi) => Ok(abi.clone()),
-            _ => Err(crate::client::Error::not_implemented(
-                "ABI handles are not supported yet",
+            Self::Handle(_) => Err(Error::invalid_abi(
+                "ABI handle can not be used without the client context",
             )),
         }
     }
+
+    /// Returns ABI JSON. Registered ABI is taken from the context.
+    pub(crate) fn json(&self, context: &ClientContext) -> ClientResult<String> {
+        match self {
+            Self::Handle(_) => Ok(self.resolve(context)?.json.clone()),
+            _ => self.json_string(),
+        }
+    }
+
+    /// Returns parsed ABI. Registered ABI is taken from the context without parsing.
+    pub(crate) fn resolve(&self, context: &ClientContext) -> ClientResult<Arc<ParsedAbi>> {
+        match self {
+            Self::Handle(handle) => Ok(context
+                .abis
+                .get(&handle.0)
+                .ok_or(Error::abi_not_registered(handle.0))?
+                .1
+                .clone()),
+            _ => Ok(Arc::new(ParsedAbi::from_json(self.json_string()?)?)),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default)]
@@ -51,6 +74,8 @@ pub struct AbiContract {
     pub events: Vec<AbiEvent>,
     #[serde(default)]
     pub data: Vec<AbiData>,
+    #[serde(default)]
+    pub fields: Vec<AbiParam>,
 }
 
 fn default_abi_version() -> u32 {
@@ -11,16 +11,18 @@
 * limitations under the License.
 */
 
-use crate::boc::internal::{deserialize_cell_from_boc, serialize_cell_to_base64, DeserializedBoc};
+use crate::boc::internal::{deserialize_cell_from_boc, serialize_cell_to_base64};
 use crate::client::ClientContext;
 use crate::error::ClientResult;
-use super::Error;
+#[cfg(not(feature = "wasm"))]
+use super::disk_cache::DiskBocs;
+use super::{BocConfig, Error};
 
 use lru::LruCache;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use tokio::sync::{Mutex, RwLock};
-use ton_types::{Cell, UInt256};
+use tokio::sync::Mutex;
+use ton_types::{BuilderData, Cell, UInt256};
 
 
 pub const SHA256_SIZE: usize = 32;
@@ -38,11 +40,15 @@ fn number_of_bytes_to_fit(l: usize) -> usize {
     n
 }
 
-fn calc_tree_cells(cell: &ton_types::Cell, hashes: &mut HashSet<ton_types::UInt256>) -> (usize, usize, usize) {
+fn calc_cell_size(cell: &ton_types::Cell) -> usize {
     let bits = cell.bit_length();
-    let mut size = 2 +
+    2 +
         if cell.store_hashes() { (cell.level() as usize + 1) * (SHA256_SIZE + DEPTH_SIZE) } else { 0 } +
-        (bits / 8) + if bits % 8 != 0 { 1 } else { 0 };
+        (bits / 8) + if bits % 8 != 0 { 1 } else { 0 }
+}
+
+fn calc_tree_cells(cell: &ton_types::Cell, hashes: &mut HashSet<ton_types::UInt256>) -> (usize, usize, usize) {
+    let mut size = calc_cell_size(cell);
     let mut cell_count = 1;
     let mut refs_count = cell.references_count();
     hashes.insert(cell.repr_hash());
@@ -68,6 +74,12 @@ fn calc_tree_size(cell: &ton_types::Cell) -> usize {
     size + refs_count * ref_size
 }
 
+fn kilobytes_to_bytes(size: u32) -> usize {
+    (size as usize)
+        .checked_mul(1024) // kilobytes in config
+        .unwrap_or(std::usize::MAX)
+}
+
 #[derive(Serialize, Deserialize, Clone, ApiType, Debug)]
 #[serde(tag="type")]
 pub enum BocCacheType {
@@ -83,145 +95,378 @@ impl Default for BocCacheType {
     }
 }
 
-pub struct PinnedBoc {
-    pins: HashSet<String>,
+struct StoredCell {
     cell: Cell,
+    refs: usize,
+    /// References from unpinned BOCs and from parent cells reachable from them
+    unpinned_refs: usize,
+    size: usize,
 }
 
-pub struct CachedBoc {
-    size: usize,
-    cell: Cell,
+/// Cells of cached BOCs stored by representation hash. Each cell is stored once
+/// and counts references from parent cells and cached BOCs, so subtrees shared between
+/// BOCs (code, libraries, common dictionaries) occupy memory and cache size only once.
+///
+/// Cells reachable from unpinned BOCs are counted separately, since only they
+/// are limited by the cache size.
+#[derive(Default)]
+struct CellStore {
+    cells: HashMap<UInt256, StoredCell>,
+    unpinned_cells_count: usize,
+    unpinned_cells_size: usize,
+    unpinned_refs_count: usize,
+}
+
+impl CellStore {
+    /// Size of the cells reachable from unpinned BOCs serialized as a single bag of cells
+    fn unpinned_size(&self) -> usize {
+        self.unpinned_cells_size
+            + self.unpinned_refs_count * number_of_bytes_to_fit(self.unpinned_cells_count)
+    }
+
+    fn get(&self, hash: &UInt256) -> Option<Cell> {
+        self.cells.get(hash).map(|entry| entry.cell.clone())
+    }
+
+    /// Adds reference to the cell tree. Returns the cell sharing subtrees with already stored cells
+    fn insert(&mut self, cell: &Cell) -> ClientResult<Cell> {
+        self.insert_tree(cell).map(|(cell, _)| cell)
+    }
+
+    fn insert_tree(&mut self, cell: &Cell) -> ClientResult<(Cell, bool)> {
+        let hash = cell.repr_hash();
+        if let Some(entry) = self.cells.get_mut(&hash) {
+            entry.refs += 1;
+            return Ok((entry.cell.clone(), true));
+        }
+
+        let mut references = Vec::with_capacity(cell.references_count());
+        let mut shared = false;
+        for i in 0..cell.references_count() {
+            let inserted = cell.reference(i)
+                .map_err(|err| Error::invalid_boc(err))
+                .and_then(|child| self.insert_tree(&child));
+            match inserted {
+                Ok((child, child_shared)) => {
+                    shared |= child_shared;
+                    references.push(child);
+                }
+                Err(err) => {
+                    self.release_references(&references);
+                    return Err(err);
+                }
+            }
+        }
+
+        // rebuild the cell only if it has subtrees which are already stored
+        let stored = if shared {
+            match Self::rebuild_cell(cell, &references) {
+                Ok(stored) => stored,
+                Err(err) => {
+                    self.release_references(&references);
+                    return Err(err);
+                }
+            }
+        } else {
+            cell.clone()
+        };
+
+        let size = calc_cell_size(cell);
+        self.cells.insert(
+            hash,
+            StoredCell { cell: stored.clone(), refs: 1, unpinned_refs: 0, size },
+        );
+
+        Ok((stored, shared))
+    }
+
+    fn rebuild_cell(cell: &Cell, references: &[Cell]) -> ClientResult<Cell> {
+        let mut builder = BuilderData::new();
+        builder.set_type(cell.cell_type());
+        builder.set_level_mask(cell.level_mask());
+        builder.append_raw(cell.data(), cell.bit_length())
+            .map_err(|err| Error::invalid_boc(err))?;
+        for reference in references {
+            builder.append_reference_cell(reference.clone());
+        }
+        builder.into_cell().map_err(|err| Error::invalid_boc(err))
+    }
+
+    fn release_references(&mut self, references: &[Cell]) {
+        for reference in references {
+            self.release(&reference.repr_hash());
+        }
+    }
+
+    /// Removes reference to the cell tree. Cells without references are removed from the store
+    fn release(&mut self, hash: &UInt256) {
+        let mut to_release = vec![hash.clone()];
+        while let Some(hash) = to_release.pop() {
+            let entry = match self.cells.get_mut(&hash) {
+                Some(entry) => entry,
+                None => continue,
+            };
+            entry.refs -= 1;
+            if entry.refs > 0 {
+                continue;
+            }
+            if let Some(entry) = self.cells.remove(&hash) {
+                for i in 0..entry.cell.references_count() {
+                    if let Ok(child) = entry.cell.reference(i) {
+                        to_release.push(child.repr_hash());
+                    }
+                }
+            }
+        }
+    }
+
+    /// Counts the stored cell tree as referenced by unpinned BOC
+    fn mark_unpinned(&mut self, hash: &UInt256) {
+        let mut to_mark = vec![hash.clone()];
+        while let Some(hash) = to_mark.pop() {
+            let entry = match self.cells.get_mut(&hash) {
+                Some(entry) => entry,
+                None => continue,
+            };
+            entry.unpinned_refs += 1;
+            if entry.unpinned_refs > 1 {
+                continue;
+            }
+            self.unpinned_cells_count += 1;
+            self.unpinned_cells_size += entry.size;
+            self.unpinned_refs_count += entry.cell.references_count();
+            for i in 0..entry.cell.references_count() {
+                if let Ok(child) = entry.cell.reference(i) {
+                    to_mark.push(child.repr_hash());
+                }
+            }
+        }
+    }
+
+    /// Removes unpinned BOC reference counted by `mark_unpinned`.
+    /// Must be called before the tree is released
+    fn unmark_unpinned(&mut self, hash: &UInt256) {
+        let mut to_unmark = vec![hash.clone()];
+        while let Some(hash) = to_unmark.pop() {
+            let entry = match self.cells.get_mut(&hash) {
+                Some(entry) => entry,
+                None => continue,
+            };
+            entry.unpinned_refs -= 1;
+            if entry.unpinned_refs > 0 {
+                continue;
+            }
+            self.unpinned_cells_count -= 1;
+            self.unpinned_cells_size -= entry.size;
+            self.unpinned_refs_count -= entry.cell.references_count();
+            for i in 0..entry.cell.references_count() {
+                if let Ok(child) = entry.cell.reference(i) {
+                    to_unmark.push(child.repr_hash());
+                }
+            }
+        }
+    }
 }
 
+/// Pinned and unpinned BOCs share the cell store, only unpinned BOCs
+/// are counted in the cache size limit.
 pub struct CachedBocs {
-    bocs: LruCache<UInt256, CachedBoc>,
-    cache_size: usize,
+    cells: CellStore,
+    pinned: HashMap<UInt256, HashSet<String>>,
+    unpinned: LruCache<UInt256, ()>,
+}
+
+impl CachedBocs {
+    fn get(&mut self, hash: &UInt256) -> Option<Cell> {
+        if self.pinned.contains_key(hash) || self.unpinned.get(hash).is_some() {
+            self.cells.get(hash)
+        } else {
+            None
+        }
+    }
+
+    fn release_unpinned(&mut self, hash: &UInt256) {
+        self.cells.unmark_unpinned(hash);
+        self.cells.release(hash);
+    }
+
+    /// Removes least recently used unpinned BOCs until they fit the size limit.
+    /// Returns `false` if it is not possible
+    fn shrink(&mut self, max_size: usize) -> bool {
+        while self.cells.unpinned_size() > max_size {
+            match self.unpinned.pop_lru() {
+                Some((hash, _)) => self.release_unpinned(&hash),
+                None => return false,
+            }
+        }
+        true
+    }
 }
 
 pub struct Bocs {
-    pinned: RwLock<HashMap<UInt256, PinnedBoc>>,
     cached: Mutex<CachedBocs>,
     max_cache_size: usize,
+    #[cfg(not(feature = "wasm"))]
+    stored: Option<DiskBocs>,
 }
 
 impl Bocs {
-    pub(crate) fn new(max_cache_size: u32) -> Self {
-        let max_cache_size = (max_cache_size as usize)
-            .checked_mul(1024) // kilobytes in config
-            .unwrap_or(std::usize::MAX);
-        Bocs {
-            pinned: RwLock::default(),
-            cached:Mutex::new(CachedBocs {
-                bocs: LruCache::unbounded(),
-                cache_size: 0,
-            }),
-            max_cache_size,
+    pub(crate) fn new(config: &BocConfig) -> ClientResult<Self> {
+        #[cfg(not(feature = "wasm"))]
+        let stored = config.cache_dir
+            .as_ref()
+            .map(|dir| DiskBocs::new(dir, kilobytes_to_bytes(config.cache_dir_max_size)))
+            .transpose()?;
+        #[cfg(feature = "wasm")]
+        if let Some(dir) = &config.cache_dir {
+            return Err(Error::cache_storage_error(
+                "persistent BOC cache is not supported in wasm",
+                std::path::Path::new(dir),
+            ));
         }
+        Ok(Bocs {
+            cached: Mutex::new(CachedBocs {
+                cells: CellStore::default(),
+                pinned: HashMap::new(),
+                unpinned: LruCache::unbounded(),
+            }),
+            max_cache_size: kilobytes_to_bytes(config.cache_max_size),
+            #[cfg(not(feature = "wasm"))]
+            stored,
+        })
     }
 
-    async fn add_pinned(&self, hash: UInt256, pin: String, cell: Cell) {
-        if let Some(entry) = self.pinned.read().await.get(&hash) {
-            if entry.pins.contains(&pin) {
-                return;
-            }
-        }
+    async fn add_pinned(&self, hash: UInt256, pin: String, cell: Cell) -> ClientResult<()> {
+        let mut lock = self.cached.lock().await;
 
-        let mut lock = self.pinned.write().await;
-        if let Some(entry) = lock.get_mut(&hash) {
-            entry.pins.insert(pin);
-            return;
+        if let Some(pins) = lock.pinned.get_mut(&hash) {
+            pins.insert(pin);
+            return Ok(());
         }
+        lock.cells.insert(&cell)?;
         let mut pins = HashSet::new();
         pins.insert(pin);
-        lock.insert(hash.clone(), PinnedBoc { pins, cell });
+        lock.pinned.insert(hash, pins);
+
+        Ok(())
     }
 
     pub(crate) async fn unpin(&self, pin: &str, hash: Option<UInt256>) {
         let mut to_remove = vec![];
-        let mut lock = self.pinned.write().await;
+        let mut lock = self.cached.lock().await;
 
         if let Some(hash) = hash {
-            if let Some(entry) = lock.get_mut(&hash) {
-                entry.pins.remove(pin);
-                if entry.pins.is_empty() {
+            if let Some(pins) = lock.pinned.get_mut(&hash) {
+                pins.remove(pin);
+                if pins.is_empty() {
                     to_remove.push(hash);
                 }
             }
         } else {
-            for (key, entry) in lock.iter_mut() {
-                entry.pins.remove(pin);
-                if entry.pins.is_empty() {
+            for (key, pins) in lock.pinned.iter_mut() {
+                pins.remove(pin);
+                if pins.is_empty() {
                     to_remove.push(key.clone());
                 }
             }
         }
         
         for key in to_remove {
-            lock.remove(&key);
+            lock.pinned.remove(&key);
+            lock.cells.release(&key);
         }
     }
 
-    async fn add_cached(&self, hash: UInt256, cell: Cell, size: usize) -> ClientResult<()> {
-        if size > self.max_cache_size as usize {
-            return Err(Error::insufficient_cache_size(self.max_cache_size, size));
-        }
+    async fn add_unpinned(&self, hash: UInt256, cell: Cell) -> ClientResult<()> {
         let mut lock = self.cached.lock().await;
 
-        if let Some(_) = lock.bocs.get(&hash) {
+        if let Some(_) = lock.unpinned.get(&hash) {
             return Ok(());
         }
 
-        while lock.cache_size + size > self.max_cache_size as usize {
-            let (_, entry) = lock.bocs
-                .pop_lru()
-                .ok_or(Error::insufficient_cache_size(self.max_cache_size, size))?;
-            lock.cache_size -= entry.size;
+        // BOC doesn't fit even into the empty cache
+        let size = calc_tree_size(&cell);
+        if size > self.max_cache_size {
+            return Err(Error::insufficient_cache_size(self.max_cache_size, size));
+        }
+
+        lock.cells.insert(&cell)?;
+        lock.cells.mark_unpinned(&hash);
+        lock.unpinned.put(hash.clone(), ());
+
+        if !lock.shrink(self.max_cache_size) || lock.unpinned.peek(&hash).is_none() {
+            // the BOC itself was removed, so it doesn't fit the cache
+            if let Some(_) = lock.unpinned.pop(&hash) {
+                lock.release_unpinned(&hash);
+            }
+            return Err(Error::insufficient_cache_size(self.max_cache_size, size));
         }
-        lock.bocs.put(hash.clone(), CachedBoc { cell, size });
-        lock.cache_size += size;
 
         Ok(())
     }
 
-    async fn get_pinned(&self, hash: &UInt256) -> Option<Cell> {
-        self.pinned.read().await.get(hash).map(|entry| entry.cell.clone())
+    #[cfg(not(feature = "wasm"))]
+    async fn add_stored(&self, hash: &UInt256, cell: &Cell) {
+        if let Some(stored) = &self.stored {
+            if let Err(err) = stored.put(hash, cell).await {
+                log::warn!("Cannot store BOC {} in the persistent cache: {}", hash.to_hex_string(), err);
+            }
+        }
+    }
+
+    #[cfg(feature = "wasm")]
+    async fn add_stored(&self, _hash: &UInt256, _cell: &Cell) {}
+
+    #[cfg(not(feature = "wasm"))]
+    async fn get_stored(&self, hash: &UInt256) -> Option<Cell> {
+        let cell = self.stored.as_ref()?.get(hash).await?;
+
+        // keep recently loaded BOC in memory, persistent cache still has it if it doesn't fit
+        let _ = self.add_unpinned(hash.clone(), cell.clone()).await;
+
+        Some(cell)
     }
 
-    async fn get_cached(&self, hash: &UInt256) -> Option<Cell> {
-        self.cached.lock().await.bocs.get(hash).map(|entry| entry.cell.clone())
+    #[cfg(feature = "wasm")]
+    async fn get_stored(&self, _hash: &UInt256) -> Option<Cell> {
+        None
     }
 
     pub(crate) async fn get(&self, hash: &UInt256) -> Option<Cell> {
-        if let Some(cell) = self.get_pinned(&hash).await {
+        if let Some(cell) = self.cached.lock().await.get(&hash) {
             return Some(cell);
         }
 
-        if let Some(cell) = self.get_cached(&hash).await {
+        if let Some(cell) = self.get_stored(&hash).await {
             return Some(cell);
         }
 
         None
     }
 
+    /// Adds BOC to the memory cache only. Used for BOCs produced by other functions
+    /// with `boc_cache` parameter, which are usually short-lived
     pub(crate) async fn add(
-        &self, cache_type: BocCacheType, cell: Cell, size: Option<usize>
+        &self, cache_type: BocCacheType, cell: Cell,
     ) -> ClientResult<UInt256> {
         let hash = cell.repr_hash();
         log::debug!("Bocs::add {}", hash.to_hex_string());
         match cache_type {
-            BocCacheType::Pinned { pin } => self.add_pinned(hash.clone(), pin, cell).await,
-            BocCacheType::Unpinned => {
-                if let Some(_) = self.get_cached(&hash).await {
-                    return Ok(hash);
-                }
-                let size = size.unwrap_or_else(|| calc_tree_size(&cell));
-                self.add_cached(hash.clone(), cell, size).await?;
-            }
+            BocCacheType::Pinned { pin } => self.add_pinned(hash.clone(), pin, cell).await?,
+            BocCacheType::Unpinned => self.add_unpinned(hash.clone(), cell).await?,
         }
         Ok(hash)
     }
-}
 
+    /// Adds BOC to the memory cache and to the persistent cache if it is configured
+    pub(crate) async fn add_persistent(
+        &self, cache_type: BocCacheType, cell: Cell,
+    ) -> ClientResult<UInt256> {
+        let hash = self.add(cache_type, cell.clone()).await?;
+        self.add_stored(&hash, &cell).await;
+        Ok(hash)
+    }
+}
 fn parse_boc_ref(boc_ref: &str) -> ClientResult<UInt256> {
     if !boc_ref.starts_with("*") {
         return Err(Error::invalid_boc_ref(
@@ -257,12 +502,8 @@ pub async fn cache_set(
     context: Arc<ClientContext>, 
     params: ParamsOfBocCacheSet,
 ) -> ClientResult<ResultOfBocCacheSet> {
-    let (bytes, cell) = deserialize_cell_from_boc(&context, &params.boc, "BOC").await?;
-    let size = match bytes {
-        DeserializedBoc::Bytes(bytes) => Some(bytes.len()),
-        _ => None,
-    };
-    context.bocs.add(params.cache_type, cell, size)
+    let (_, cell) = deserialize_cell_from_boc(&context, &params.boc, "BOC").await?;
+    context.bocs.add_persistent(params.cache_type, cell)
         .await
         .map(|hash| ResultOfBocCacheSet { boc_ref: format!("*{}", hash.to_hex_string()) })
 }
@@ -0,0 +1,215 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+use super::Error;
+use crate::boc::internal::serialize_cell_to_bytes;
+use crate::error::ClientResult;
+
+use lru::LruCache;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tokio::sync::Mutex;
+use ton_types::{deserialize_tree_of_cells, Cell, UInt256};
+
+const BOC_FILE_EXTENSION: &str = "boc";
+const TEMP_FILE_EXTENSION: &str = "tmp";
+
+fn boc_file_path(dir: &Path, hash: &UInt256) -> PathBuf {
+    dir.join(format!("{}.{}", hash.to_hex_string(), BOC_FILE_EXTENSION))
+}
+
+fn remove_files(paths: Vec<PathBuf>) {
+    for path in paths {
+        if let Err(err) = std::fs::remove_file(&path) {
+            log::warn!("Cannot remove BOC cache file {}: {}", path.display(), err);
+        }
+    }
+}
+
+/// Runs blocking file system operation on the blocking thread pool
+/// so it doesn't stall the async executor
+async fn blocking<R, F>(dir: &Path, f: F) -> ClientResult<R>
+where
+    R: Send + 'static,
+    F: FnOnce() -> R + Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|err| Error::cache_storage_error(err, dir))
+}
+
+struct StoredBocs {
+    files: LruCache<UInt256, usize>,
+    /// BOCs being written. Their size is already counted in `size`
+    pending: HashSet<UInt256>,
+    size: usize,
+}
+
+impl StoredBocs {
+    /// Removes least recently used BOCs from the index until `required_size` bytes
+    /// fit the limit. Returns paths of the files to be removed
+    fn evict(&mut self, dir: &Path, max_size: usize, required_size: usize) -> Vec<PathBuf> {
+        let mut evicted = Vec::new();
+        while self.size + required_size > max_size {
+            let (hash, size) = match self.files.pop_lru() {
+                Some(entry) => entry,
+                None => break,
+            };
+            self.size -= size;
+            evicted.push(boc_file_path(dir, &hash));
+        }
+        evicted
+    }
+}
+
+/// Persistent BOC cache tier. Each BOC is stored in a separate file named
+/// by the BOC hash. Least recently used files are removed when the total
+/// size exceeds the limit. After restart the usage order is restored from
+/// the file modification times.
+///
+/// File operations are performed outside of the index lock on the blocking thread pool.
+pub(crate) struct DiskBocs {
+    dir: PathBuf,
+    stored: Mutex<StoredBocs>,
+    max_size: usize,
+}
+
+impl DiskBocs {
+    /// Scans the cache directory. Called once on the client context creation
+    pub(crate) fn new(dir: &str, max_size: usize) -> ClientResult<Self> {
+        let dir = PathBuf::from(dir);
+        std::fs::create_dir_all(&dir).map_err(|err| Error::cache_storage_error(err, &dir))?;
+
+        let mut files = Vec::new();
+        let entries = std::fs::read_dir(&dir).map_err(|err| Error::cache_storage_error(err, &dir))?;
+        for entry in entries {
+            let path = entry
+                .map_err(|err| Error::cache_storage_error(err, &dir))?
+                .path();
+            if path.extension() == Some(TEMP_FILE_EXTENSION.as_ref()) {
+                // unfinished write from the previous run
+                let _ = std::fs::remove_file(&path);
+                continue;
+            }
+            if path.extension() != Some(BOC_FILE_EXTENSION.as_ref()) {
+                continue;
+            }
+            let hash = match path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| UInt256::from_str(stem).ok())
+            {
+                Some(hash) => hash,
+                None => continue,
+            };
+            let metadata =
+                std::fs::metadata(&path).map_err(|err| Error::cache_storage_error(err, &path))?;
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            files.push((modified, hash, metadata.len() as usize));
+        }
+        files.sort_by_key(|(modified, _, _)| *modified);
+
+        let mut stored = StoredBocs {
+            files: LruCache::unbounded(),
+            pending: HashSet::new(),
+            size: 0,
+        };
+        for (_, hash, size) in files {
+            stored.files.put(hash, size);
+            stored.size += size;
+        }
+        remove_files(stored.evict(&dir, max_size, 0));
+
+        Ok(Self {
+            dir,
+            stored: Mutex::new(stored),
+            max_size,
+        })
+    }
+
+    pub(crate) async fn put(&self, hash: &UInt256, cell: &Cell) -> ClientResult<()> {
+        let bytes = serialize_cell_to_bytes(cell, "BOC")?;
+        if bytes.len() > self.max_size {
+            return Err(Error::insufficient_cache_size(self.max_size, bytes.len()));
+        }
+
+        let evicted = {
+            let mut lock = self.stored.lock().await;
+            if lock.files.get(hash).is_some() || lock.pending.contains(hash) {
+                return Ok(());
+            }
+            let evicted = lock.evict(&self.dir, self.max_size, bytes.len());
+            lock.pending.insert(hash.clone());
+            lock.size += bytes.len();
+            evicted
+        };
+
+        // write to temporary file first so the cache never contains partially written BOCs
+        let path = boc_file_path(&self.dir, hash);
+        let size = bytes.len();
+        let result = {
+            let path = path.clone();
+            blocking(&self.dir, move || {
+                remove_files(evicted);
+                let temp_path = path.with_extension(TEMP_FILE_EXTENSION);
+                std::fs::write(&temp_path, &bytes).and_then(|_| std::fs::rename(&temp_path, &path))
+            })
+            .await?
+        };
+
+        let mut lock = self.stored.lock().await;
+        lock.pending.remove(hash);
+        match result {
+            Ok(()) => {
+                lock.files.put(hash.clone(), size);
+                Ok(())
+            }
+            Err(err) => {
+                lock.size -= size;
+                Err(Error::cache_storage_error(err, &path))
+            }
+        }
+    }
+
+    pub(crate) async fn get(&self, hash: &UInt256) -> Option<Cell> {
+        self.stored.lock().await.files.get(hash)?;
+
+        let path = boc_file_path(&self.dir, hash);
+        let cell = {
+            let path = path.clone();
+            let hash = hash.clone();
+            blocking(&self.dir, move || {
+                std::fs::read(&path)
+                    .ok()
+                    .and_then(|bytes| deserialize_tree_of_cells(&mut bytes.as_slice()).ok())
+                    .filter(|cell| cell.repr_hash() == hash)
+            })
+            .await
+            .ok()?
+        };
+
+        if cell.is_none() {
+            log::warn!("BOC cache file {} is corrupted and will be removed", path.display());
+            {
+                let mut lock = self.stored.lock().await;
+                if let Some(size) = lock.files.pop(hash) {
+                    lock.size -= size;
+                }
+            }
+            let _ = blocking(&self.dir, move || remove_files(vec![path])).await;
+        }
+
+        cell
+    }
+}
@@ -0,0 +1,319 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+use crate::boc::internal::{deserialize_cell_from_boc, serialize_object_to_boc};
+use crate::boc::{BocCacheType, Error, ResultOfEncodeBoc};
+use crate::client::ClientContext;
+use crate::encoding::{account_decode, decode_abi_number};
+use crate::error::{ClientError, ClientResult};
+use num_traits::NumCast;
+use serde_json::Value;
+use ton_block::{
+    Account, CurrencyCollection, ExtOutMessageHeader, ExternalInboundMessageHeader,
+    InternalMessageHeader, Message, MsgAddressExt, MsgAddressInt, Number5, StateInit,
+    StateInitLib, TickTock,
+};
+use ton_types::{Cell, SliceData};
+
+const MSG_TYPE_INTERNAL: u8 = 0;
+const MSG_TYPE_EXT_IN: u8 = 1;
+const MSG_TYPE_EXT_OUT: u8 = 2;
+
+const ACC_TYPE_UNINIT: u8 = 0;
+const ACC_TYPE_ACTIVE: u8 = 1;
+const ACC_TYPE_FROZEN: u8 = 2;
+const ACC_TYPE_NON_EXIST: u8 = 3;
+
+#[derive(Serialize, Deserialize, Clone, ApiType, Default)]
+pub struct ParamsOfEncodeParsed {
+    /// JSON object in the form returned by the corresponding `parse_*` function.
+    ///
+    /// Numbers can be represented as JSON numbers, decimal strings
+    /// or `0x` prefixed hexadecimal strings. Cells are BOCs encoded
+    /// as base64 or BOC cache references.
+    pub parsed: Value,
+    /// Cache type to put the result. The BOC itself returned if no cache type provided.
+    pub boc_cache: Option<BocCacheType>,
+}
+
+struct ParsedObject<'a> {
+    value: &'a Value,
+    name: &'static str,
+}
+
+impl<'a> ParsedObject<'a> {
+    fn new(value: &'a Value, name: &'static str) -> ClientResult<Self> {
+        if value.is_object() {
+            Ok(Self { value, name })
+        } else {
+            Err(Error::serialization_error("JSON object expected", name))
+        }
+    }
+
+    fn field_error(&self, field: &str, err: impl std::fmt::Display) -> ClientError {
+        Error::serialization_error(format!("invalid `{}` field: {}", field, err), self.name)
+    }
+
+    fn get(&self, field: &str) -> Option<&'a Value> {
+        self.value.get(field).filter(|value| !value.is_null())
+    }
+
+    fn has_any(&self, fields: &[&str]) -> bool {
+        fields.iter().any(|field| self.get(field).is_some())
+    }
+
+    fn number<N: NumCast>(&self, field: &str) -> ClientResult<Option<N>> {
+        match self.get(field) {
+            None => Ok(None),
+            Some(Value::String(string)) => decode_abi_number(string)
+                .map(Some)
+                .map_err(|err| self.field_error(field, err)),
+            Some(Value::Number(number)) => decode_abi_number(&number.to_string())
+                .map(Some)
+                .map_err(|err| self.field_error(field, err)),
+            Some(_) => Err(self.field_error(field, "number expected")),
+        }
+    }
+
+    fn required_number<N: NumCast>(&self, field: &str) -> ClientResult<N> {
+        self.number(field)?
+            .ok_or_else(|| self.field_error(field, "field is missing"))
+    }
+
+    fn bool(&self, field: &str) -> ClientResult<Option<bool>> {
+        match self.get(field) {
+            None => Ok(None),
+            Some(Value::Bool(value)) => Ok(Some(*value)),
+            Some(_) => Err(self.field_error(field, "boolean expected")),
+        }
+    }
+
+    fn string(&self, field: &str) -> ClientResult<Option<&'a str>> {
+        match self.get(field) {
+            None => Ok(None),
+            Some(Value::String(value)) => Ok(Some(value.as_str())),
+            Some(_) => Err(self.field_error(field, "string expected")),
+        }
+    }
+
+    fn address(&self, field: &str) -> ClientResult<Option<MsgAddressInt>> {
+        match self.string(field)? {
+            None | Some("") => Ok(None),
+            Some(address) => account_decode(address)
+                .map(Some)
+                .map_err(|err| self.field_error(field, err)),
+        }
+    }
+
+    fn required_address(&self, field: &str) -> ClientResult<MsgAddressInt> {
+        self.address(field)?
+            .ok_or_else(|| self.field_error(field, "field is missing"))
+    }
+
+    fn ext_address(&self, field: &str) -> ClientResult<MsgAddressExt> {
+        match self.string(field)? {
+            None | Some("") => Ok(MsgAddressExt::AddrNone),
+            Some(address) => {
+                let data = address
+                    .strip_prefix(':')
+                    .ok_or_else(|| self.field_error(field, "external address must start with `:`"))?;
+                let bytes = hex::decode(data).map_err(|err| self.field_error(field, err))?;
+                let bits = bytes.len() * 8;
+                MsgAddressExt::with_extern(SliceData::from_raw(bytes, bits))
+                    .map_err(|err| self.field_error(field, err))
+            }
+        }
+    }
+
+    async fn cell(&self, context: &ClientContext, field: &str) -> ClientResult<Option<Cell>> {
+        match self.string(field)? {
+            None => Ok(None),
+            Some(boc) => Ok(Some(deserialize_cell_from_boc(context, boc, field).await?.1)),
+        }
+    }
+
+    fn currency_collection(&self, grams_field: &str, other_field: &str) -> ClientResult<CurrencyCollection> {
+        let mut currencies =
+            CurrencyCollection::with_grams(self.number(grams_field)?.unwrap_or(0));
+        if let Some(other) = self.get(other_field) {
+            let other = other
+                .as_array()
+                .ok_or_else(|| self.field_error(other_field, "array expected"))?;
+            for item in other {
+                let item = ParsedObject::new(item, self.name)?;
+                let currency: u32 = item.required_number("currency")?;
+                let value: u128 = item.required_number("value")?;
+                currencies
+                    .set_other(currency, value)
+                    .map_err(|err| self.field_error(other_field, err))?;
+            }
+        }
+        Ok(currencies)
+    }
+
+    fn has_state_init(&self) -> bool {
+        self.has_any(&["code", "data", "library", "split_depth", "tick", "tock"])
+    }
+
+    async fn state_init(&self, context: &ClientContext) -> ClientResult<StateInit> {
+        let special = match (self.bool("tick")?, self.bool("tock")?) {
+            (None, None) => None,
+            (tick, tock) => Some(TickTock {
+                tick: tick.unwrap_or_default(),
+                tock: tock.unwrap_or_default(),
+            }),
+        };
+        let split_depth = self
+            .number::<u32>("split_depth")?
+            .map(|depth| Number5::new(depth).map_err(|err| self.field_error("split_depth", err)))
+            .transpose()?;
+        Ok(StateInit {
+            code: self.cell(context, "code").await?,
+            data: self.cell(context, "data").await?,
+            library: StateInitLib::with_hashmap(self.cell(context, "library").await?),
+            split_depth,
+            special,
+        })
+    }
+}
+
+async fn message_from_parsed(context: &ClientContext, parsed: &Value) -> ClientResult<Message> {
+    let parsed = ParsedObject::new(parsed, "message")?;
+    let mut message = match parsed.required_number::<u8>("msg_type")? {
+        MSG_TYPE_INTERNAL => {
+            let mut header = InternalMessageHeader::default();
+            if let Some(src) = parsed.address("src")? {
+                header.set_src(src);
+            }
+            header.set_dst(parsed.required_address("dst")?);
+            header.value = parsed.currency_collection("value", "value_other")?;
+            header.ihr_disabled = parsed.bool("ihr_disabled")?.unwrap_or_default();
+            header.bounce = parsed.bool("bounce")?.unwrap_or_default();
+            header.bounced = parsed.bool("bounced")?.unwrap_or_default();
+            header.ihr_fee = parsed.number::<u64>("ihr_fee")?.unwrap_or(0).into();
+            header.fwd_fee = parsed.number::<u64>("fwd_fee")?.unwrap_or(0).into();
+            header.created_lt = parsed.number("created_lt")?.unwrap_or(0);
+            header.created_at = parsed.number::<u32>("created_at")?.unwrap_or(0).into();
+            Message::with_int_header(header)
+        }
+        MSG_TYPE_EXT_IN => Message::with_ext_in_header(ExternalInboundMessageHeader {
+            src: parsed.ext_address("src")?,
+            dst: parsed.required_address("dst")?,
+            import_fee: parsed.number::<u64>("import_fee")?.unwrap_or(0).into(),
+        }),
+        MSG_TYPE_EXT_OUT => {
+            let mut header = ExtOutMessageHeader::default();
+            if let Some(src) = parsed.address("src")? {
+                header.set_src(src);
+            }
+            header.set_dst(parsed.ext_address("dst")?);
+            header.created_lt = parsed.number("created_lt")?.unwrap_or(0);
+            header.created_at = parsed.number::<u32>("created_at")?.unwrap_or(0).into();
+            Message::with_ext_out_header(header)
+        }
+        msg_type => {
+            return Err(parsed.field_error("msg_type", format!("unknown message type {}", msg_type)))
+        }
+    };
+    if parsed.has_state_init() {
+        message.set_state_init(parsed.state_init(context).await?);
+    }
+    if let Some(body) = parsed.cell(context, "body").await? {
+        message.set_body(body.into());
+    }
+    Ok(message)
+}
+
+async fn account_from_parsed(context: &ClientContext, parsed: &Value) -> ClientResult<Account> {
+    let parsed = ParsedObject::new(parsed, "account")?;
+    let acc_type = parsed.number::<u8>("acc_type")?.unwrap_or(ACC_TYPE_ACTIVE);
+    if acc_type == ACC_TYPE_NON_EXIST {
+        return Ok(Account::default());
+    }
+    if parsed.get("due_payment").is_some() {
+        return Err(parsed.field_error("due_payment", "due payment is not supported"));
+    }
+    let address = parsed.required_address("id")?;
+    let balance = parsed.currency_collection("balance", "balance_other")?;
+    let mut account = Account::uninit(
+        address,
+        parsed.number("last_trans_lt")?.unwrap_or(0),
+        parsed.number("last_paid")?.unwrap_or(0),
+        balance,
+    );
+    match acc_type {
+        ACC_TYPE_UNINIT => {}
+        ACC_TYPE_ACTIVE => account.activate(parsed.state_init(context).await?),
+        ACC_TYPE_FROZEN => {
+            return Err(parsed.field_error("acc_type", "frozen accounts are not supported"))
+        }
+        _ => {
+            return Err(parsed.field_error("acc_type", format!("unknown account type {}", acc_type)))
+        }
+    }
+    account
+        .update_storage_stat()
+        .map_err(|err| Error::serialization_error(err, "account"))?;
+    Ok(account)
+}
+
+/// Encodes message BOC from a JSON
+///
+/// JSON structure is the same as returned by `parse_message` function.
+/// Only fields required to build the message are taken into account,
+/// computed fields like `id` or `boc` are ignored.
+#[api_function]
+pub async fn encode_message(
+    context: std::sync::Arc<ClientContext>,
+    params: ParamsOfEncodeParsed,
+) -> ClientResult<ResultOfEncodeBoc> {
+    let message = message_from_parsed(&context, &params.parsed).await?;
+    Ok(ResultOfEncodeBoc {
+        boc: serialize_object_to_boc(&context, &message, "message", params.boc_cache).await?,
+    })
+}
+
+/// Encodes account BOC from a JSON
+///
+/// JSON structure is the same as returned by `parse_account` function.
+/// Storage statistics are recalculated, computed fields like `boc`,
+/// `code_hash` or `data_hash` are ignored.
+/// Frozen accounts and accounts with a due payment are not supported.
+#[api_function]
+pub async fn encode_account(
+    context: std::sync::Arc<ClientContext>,
+    params: ParamsOfEncodeParsed,
+) -> ClientResult<ResultOfEncodeBoc> {
+    let account = account_from_parsed(&context, &params.parsed).await?;
+    Ok(ResultOfEncodeBoc {
+        boc: serialize_object_to_boc(&context, &account, "account", params.boc_cache).await?,
+    })
+}
+
+/// Encodes state init BOC from a JSON
+///
+/// JSON may contain `code`, `data`, `library`, `split_depth`, `tick` and `tock`
+/// fields in the same form as they are returned by `parse_account` and
+/// `parse_message` functions.
+#[api_function]
+pub async fn encode_state_init(
+    context: std::sync::Arc<ClientContext>,
+    params: ParamsOfEncodeParsed,
+) -> ClientResult<ResultOfEncodeBoc> {
+    let state_init = ParsedObject::new(&params.parsed, "state init")?
+        .state_init(&context)
+        .await?;
+    Ok(ResultOfEncodeBoc {
+        boc: serialize_object_to_boc(&context, &state_init, "state init", params.boc_cache).await?,
+    })
+}
@@ -23,6 +23,11 @@ pub enum ErrorCode {
     InsufficientCacheSize = 205,
     BocRefNotFound = 206,
     InvalidBocRef = 207,
+    InvalidCellQuery = 208,
+    InvalidTlbSchema = 209,
+    CacheStorageError = 210,
+    InvalidMerkleProof = 211,
+    InvalidMerkleUpdate = 212,
 }
 pub struct Error;
 
@@ -83,4 +88,39 @@ impl Error {
         error.data["boc_ref"] = boc_ref.into();
         error
     }
+
+    pub fn invalid_cell_query<E: Display>(err: E) -> ClientError {
+        error(
+            ErrorCode::InvalidCellQuery,
+            format!("Invalid cell query: {}", err),
+        )
+    }
+
+    pub fn invalid_tlb_schema<E: Display>(err: E) -> ClientError {
+        error(
+            ErrorCode::InvalidTlbSchema,
+            format!("Invalid TL-B schema: {}", err),
+        )
+    }
+
+    pub fn cache_storage_error<E: Display>(err: E, path: &std::path::Path) -> ClientError {
+        error(
+            ErrorCode::CacheStorageError,
+            format!("BOC cache storage error at `{}`: {}", path.display(), err),
+        )
+    }
+
+    pub fn invalid_merkle_proof<E: Display>(err: E) -> ClientError {
+        error(
+            ErrorCode::InvalidMerkleProof,
+            format!("Invalid Merkle proof: {}", err),
+        )
+    }
+
+    pub fn invalid_merkle_update<E: Display>(err: E) -> ClientError {
+        error(
+            ErrorCode::InvalidMerkleUpdate,
+            format!("Invalid Merkle update: {}", err),
+        )
+    }
 }
@@ -147,7 +147,7 @@ pub(crate) async fn serialize_cell_to_boc(
     context: &ClientContext, cell: ton_types::Cell, name: &str, boc_cache: Option<BocCacheType>,
 ) -> ClientResult<String> {
     if let Some(cache_type) = boc_cache {
-        context.bocs.add(cache_type, cell, None)
+        context.bocs.add(cache_type, cell)
             .await
             .map(|hash| format!("*{}", hash.to_hex_string()))
     } else {
@@ -0,0 +1,235 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+use crate::boc::internal::{
+    deserialize_cell_from_boc, deserialize_object_from_boc, serialize_cell_to_boc,
+    serialize_object_to_boc,
+};
+use crate::boc::{BocCacheType, Error};
+use crate::client::ClientContext;
+use crate::error::ClientResult;
+use std::collections::{HashMap, HashSet};
+use ton_block::{MerkleProof, MerkleUpdate};
+use ton_types::{Cell, UInt256};
+
+#[derive(Serialize, Deserialize, Clone, ApiType, Default)]
+pub struct ParamsOfCreateMerkleProof {
+    /// BOC encoded as base64 or BOC cache reference
+    pub boc: String,
+    /// Paths to the cells which must be kept in the proof.
+    ///
+    /// Each path is a list of reference indexes leading from the root cell
+    /// to the kept cell, e.g. `[0, 1]` is the second reference of the first
+    /// reference of the root. Cells along the path and the whole subtree
+    /// of the kept cell are included into the proof.
+    pub paths: Option<Vec<Vec<u8>>>,
+    /// Representation hashes of the cells which must be kept in the proof
+    /// encoded with hex.
+    ///
+    /// All occurrences of the cells with their subtrees and the cells leading to them
+    /// from the root are included into the proof.
+    pub hashes: Option<Vec<String>>,
+    /// Cache type to put the result. The BOC itself returned if no cache type provided.
+    pub boc_cache: Option<BocCacheType>,
+}
+
+#[derive(Serialize, Deserialize, Clone, ApiType, Default)]
+pub struct ResultOfCreateMerkleProof {
+    /// Merkle proof BOC encoded as base64 or BOC cache reference
+    pub proof: String,
+    /// Root hash of the proven BOC encoded with hex
+    pub root_hash: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, ApiType, Default)]
+pub struct ParamsOfVerifyMerkleProof {
+    /// Merkle proof BOC encoded as base64 or BOC cache reference
+    pub proof: String,
+    /// Expected root hash of the proven BOC encoded with hex
+    pub root_hash: String,
+    /// Cache type to put the result. The BOC itself returned if no cache type provided.
+    pub boc_cache: Option<BocCacheType>,
+}
+
+#[derive(Serialize, Deserialize, Clone, ApiType, Default)]
+pub struct ResultOfVerifyMerkleProof {
+    /// Proven cells encoded as BOC. Cells not included into the proof are
+    /// represented with pruned branch cells.
+    pub boc: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, ApiType, Default)]
+pub struct ParamsOfApplyMerkleUpdate {
+    /// Original BOC encoded as base64 or BOC cache reference
+    pub boc: String,
+    /// Merkle update BOC encoded as base64 or BOC cache reference
+    pub update: String,
+    /// Cache type to put the result. The BOC itself returned if no cache type provided.
+    pub boc_cache: Option<BocCacheType>,
+}
+
+#[derive(Serialize, Deserialize, Clone, ApiType, Default)]
+pub struct ResultOfApplyMerkleUpdate {
+    /// Updated BOC encoded as base64 or BOC cache reference
+    pub boc: String,
+}
+
+fn include_subtree(cell: &Cell, included: &mut HashSet<UInt256>) -> ClientResult<()> {
+    if !included.insert(cell.repr_hash()) {
+        return Ok(());
+    }
+    for i in 0..cell.references_count() {
+        let child = cell.reference(i).map_err(|err| Error::invalid_boc(err))?;
+        include_subtree(&child, included)?;
+    }
+    Ok(())
+}
+
+fn include_path(root: &Cell, path: &[u8], included: &mut HashSet<UInt256>) -> ClientResult<()> {
+    let mut cell = root.clone();
+    for (depth, index) in path.iter().enumerate() {
+        included.insert(cell.repr_hash());
+        cell = cell.reference(*index as usize).map_err(|_| {
+            Error::invalid_merkle_proof(format!(
+                "path {:?} has no reference {} at depth {}",
+                path, index, depth
+            ))
+        })?;
+    }
+    include_subtree(&cell, included)
+}
+
+fn include_hashes(
+    cell: &Cell,
+    hashes: &HashSet<UInt256>,
+    visited: &mut HashMap<UInt256, bool>,
+    included: &mut HashSet<UInt256>,
+) -> ClientResult<bool> {
+    let hash = cell.repr_hash();
+    if let Some(found) = visited.get(&hash) {
+        return Ok(*found);
+    }
+
+    let found = if hashes.contains(&hash) {
+        include_subtree(cell, included)?;
+        true
+    } else {
+        let mut found = false;
+        for i in 0..cell.references_count() {
+            let child = cell.reference(i).map_err(|err| Error::invalid_boc(err))?;
+            found |= include_hashes(&child, hashes, visited, included)?;
+        }
+        if found {
+            included.insert(hash.clone());
+        }
+        found
+    };
+
+    visited.insert(hash, found);
+    Ok(found)
+}
+
+/// Creates Merkle proof for the BOC
+///
+/// Proof contains the root cell and the requested cells, all other
+/// cells are replaced with pruned branches.
+#[api_function]
+pub async fn create_merkle_proof(
+    context: std::sync::Arc<ClientContext>,
+    params: ParamsOfCreateMerkleProof,
+) -> ClientResult<ResultOfCreateMerkleProof> {
+    let (_, root) = deserialize_cell_from_boc(&context, &params.boc, "BOC").await?;
+
+    let mut included = HashSet::new();
+    included.insert(root.repr_hash());
+    for path in params.paths.unwrap_or_default() {
+        include_path(&root, &path, &mut included)?;
+    }
+    let hashes = params.hashes
+        .unwrap_or_default()
+        .iter()
+        .map(|hash| UInt256::from_str(hash).map_err(|err| Error::invalid_merkle_proof(
+            format!("invalid cell hash `{}`: {}", hash, err)
+        )))
+        .collect::<ClientResult<HashSet<_>>>()?;
+    if !hashes.is_empty() {
+        include_hashes(&root, &hashes, &mut HashMap::new(), &mut included)?;
+    }
+
+    let proof = MerkleProof::create(&root, |hash| included.contains(hash))
+        .map_err(|err| Error::invalid_merkle_proof(err))?;
+
+    Ok(ResultOfCreateMerkleProof {
+        proof: serialize_object_to_boc(&context, &proof, "Merkle proof", params.boc_cache).await?,
+        root_hash: root.repr_hash().to_hex_string(),
+    })
+}
+
+/// Verifies Merkle proof against the expected root hash
+///
+/// Returns proven cells if the proof is valid.
+#[api_function]
+pub async fn verify_merkle_proof(
+    context: std::sync::Arc<ClientContext>,
+    params: ParamsOfVerifyMerkleProof,
+) -> ClientResult<ResultOfVerifyMerkleProof> {
+    let root_hash = UInt256::from_str(&params.root_hash)
+        .map_err(|err| Error::invalid_merkle_proof(format!("invalid root hash: {}", err)))?;
+    let proof = deserialize_object_from_boc::<MerkleProof>(&context, &params.proof, "Merkle proof")
+        .await?
+        .object;
+
+    if proof.hash != root_hash {
+        return Err(Error::invalid_merkle_proof(format!(
+            "proof is created for root hash {} but {} is expected",
+            proof.hash.to_hex_string(),
+            root_hash.to_hex_string(),
+        )));
+    }
+    // hash of the proven cells without pruning must be the same as the original BOC hash
+    if proof.proof.hash(0) != proof.hash || proof.proof.depth(0) != proof.depth {
+        return Err(Error::invalid_merkle_proof("proven cells don't match the proof root hash"));
+    }
+
+    Ok(ResultOfVerifyMerkleProof {
+        boc: serialize_cell_to_boc(&context, proof.proof, "proven cells", params.boc_cache).await?,
+    })
+}
+
+/// Applies Merkle update to the BOC
+///
+/// Checks that the update is created for the provided BOC and returns the updated BOC.
+#[api_function]
+pub async fn apply_merkle_update(
+    context: std::sync::Arc<ClientContext>,
+    params: ParamsOfApplyMerkleUpdate,
+) -> ClientResult<ResultOfApplyMerkleUpdate> {
+    let (_, old) = deserialize_cell_from_boc(&context, &params.boc, "BOC").await?;
+    let update = deserialize_object_from_boc::<MerkleUpdate>(&context, &params.update, "Merkle update")
+        .await?
+        .object;
+
+    if update.old_hash != old.repr_hash() {
+        return Err(Error::invalid_merkle_update(format!(
+            "update is created for BOC with hash {} but BOC hash is {}",
+            update.old_hash.to_hex_string(),
+            old.repr_hash().to_hex_string(),
+        )));
+    }
+    let new = update.apply_for(&old)
+        .map_err(|err| Error::invalid_merkle_update(err))?;
+
+    Ok(ResultOfApplyMerkleUpdate {
+        boc: serialize_cell_to_boc(&context, new, "updated BOC", params.boc_cache).await?,
+    })
+}
@@ -15,12 +15,18 @@ use serde::{Deserialize, Deserializer};
 
 pub(crate) mod blockchain_config;
 pub(crate) mod cache;
+#[cfg(not(feature = "wasm"))]
+pub(crate) mod disk_cache;
 mod errors;
 pub(crate) mod hash;
 pub(crate) mod internal;
+pub(crate) mod merkle;
 pub(crate) mod parse;
+pub(crate) mod reader;
+pub(crate) mod tlb;
 pub(crate) mod tvc;
 pub(crate) mod encode;
+pub(crate) mod encode_parsed;
 
 #[cfg(test)]
 pub(crate) mod tests;
@@ -40,8 +46,18 @@ pub use blockchain_config::{
 pub use encode::{
     encode_boc, ParamsOfEncodeBoc, ResultOfEncodeBoc, BuilderOp,
 };
+pub use encode_parsed::{
+    encode_account, encode_message, encode_state_init, ParamsOfEncodeParsed,
+};
 pub use errors::{Error, ErrorCode};
 pub use hash::{get_boc_hash, ParamsOfGetBocHash, ResultOfGetBocHash};
+pub use merkle::{
+    apply_merkle_update, create_merkle_proof, verify_merkle_proof, ParamsOfApplyMerkleUpdate,
+    ParamsOfCreateMerkleProof, ParamsOfVerifyMerkleProof, ResultOfApplyMerkleUpdate,
+    ResultOfCreateMerkleProof, ResultOfVerifyMerkleProof,
+};
+pub use reader::{query_cell, ParamsOfQueryCell, ResultOfQueryCell};
+pub use tlb::{parse_tlb, ParamsOfParseTlb};
 pub use tvc::{get_code_from_tvc, ParamsOfGetCodeFromTvc, ResultOfGetCodeFromTvc};
 
 pub fn default_cache_max_size() -> u32 {
@@ -52,20 +68,47 @@ fn deserialize_cache_max_size<'de, D: Deserializer<'de>>(deserializer: D) -> Res
     Ok(Option::deserialize(deserializer)?.unwrap_or(default_cache_max_size()))
 }
 
+pub fn default_cache_dir_max_size() -> u32 {
+    100 * 1024 // * 1024 = 100 MB
+}
+
+fn deserialize_cache_dir_max_size<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    Ok(Option::deserialize(deserializer)?.unwrap_or(default_cache_dir_max_size()))
+}
+
 #[derive(Deserialize, Debug, Clone, ApiType)]
 pub struct BocConfig {
     /// Maximum BOC cache size in kilobytes. Default is 10 MB
+    ///
+    /// Only unpinned BOCs are counted. Cells shared between cached BOCs are counted once.
     #[serde(
         default = "default_cache_max_size",
         deserialize_with = "deserialize_cache_max_size"
     )]
     pub cache_max_size: u32,
+    /// Directory of the persistent BOC cache.
+    ///
+    /// If specified, BOCs put into the cache with `boc.cache_set` are also stored
+    /// in this directory, so they survive client restarts and BOCs evicted from memory
+    /// can be reloaded by reference. BOCs cached by other functions with `boc_cache`
+    /// parameter are kept in memory only. Persistent cache is disabled by default
+    /// and is not supported in wasm.
+    #[serde(default)]
+    pub cache_dir: Option<String>,
+    /// Maximum persistent BOC cache size in kilobytes. Default is 100 MB
+    #[serde(
+        default = "default_cache_dir_max_size",
+        deserialize_with = "deserialize_cache_dir_max_size"
+    )]
+    pub cache_dir_max_size: u32,
 }
 
 impl Default for BocConfig {
     fn default() -> Self {
         Self {
             cache_max_size: default_cache_max_size(),
+            cache_dir: None,
+            cache_dir_max_size: default_cache_dir_max_size(),
         }
     }
 }
@@ -11,7 +11,7 @@
 * limitations under the License.
 */
 
-use crate::boc::internal::deserialize_object_from_boc;
+use crate::boc::internal::{deserialize_object_from_boc, DeserializedObject};
 use crate::boc::Error;
 use crate::client::ClientContext;
 use crate::error::ClientResult;
@@ -83,6 +83,15 @@ pub async fn parse_transaction(
     let object =
         deserialize_object_from_boc::<ton_block::Transaction>(&context, &params.boc, "transaction").await?;
 
+    Ok(ResultOfParse {
+        parsed: serialize_transaction(&object)?,
+    })
+}
+
+/// Serializes already deserialized transaction into a JSON
+pub(crate) fn serialize_transaction(
+    object: &DeserializedObject<ton_block::Transaction>,
+) -> ClientResult<Value> {
     let set = ton_block_json::TransactionSerializationSetEx {
         block_id: None,
         boc: &object.boc.bytes("transaction")?,
@@ -100,9 +109,7 @@ pub async fn parse_transaction(
     )
     .map_err(|err| Error::serialization_error(err, "transaction"))?;
 
-    Ok(ResultOfParse {
-        parsed: parsed.into(),
-    })
+    Ok(parsed.into())
 }
 
 /// Parses account boc into a JSON
@@ -0,0 +1,71 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+use crate::boc::internal::deserialize_cell_from_boc;
+use crate::client::ClientContext;
+use crate::error::ClientResult;
+use serde_json::Value;
+
+mod parser;
+pub(crate) mod query;
+
+use parser::CellQuery;
+
+#[derive(Serialize, Deserialize, Clone, ApiType, Default)]
+pub struct ParamsOfQueryCell {
+    /// Cell BOC encoded as base64 or BOC cache reference
+    pub boc: String,
+    /// Query describing the cell layout.
+    ///
+    /// Query is a sequence of fields `name:type` read from the cell data
+    /// one after another. Name can be omitted, in this case the field index
+    /// is used as a name. Field prefixed with `-` is read but skipped in the result.
+    ///
+    /// Supported types:
+    /// - `uN`, `iN` – unsigned and signed integers of `N` bits, e.g. `u32`, `i256`.
+    /// - `bool` – single bit.
+    /// - `grams` – `Grams` value.
+    /// - `address` – `MsgAddress` value.
+    /// - `bitsN` – bit string of `N` bits.
+    /// - `cell` or `cell(fields)` – nested cell read from the next reference.
+    ///   Returned as BOC if no fields are specified.
+    /// - `maybe(type)` – bit flag followed by the value if flag is set.
+    /// - `dict(key_type, fields)` – dictionary with keys of `uN`, `iN`, `bitsN`,
+    ///   `bool` or `address` type. Values are returned as BOCs if no fields are specified.
+    ///
+    /// Example: `seqno:u32 -u32 owner:address wallets:dict(u256, balance:grams)`
+    pub query: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, ApiType, Default)]
+pub struct ResultOfQueryCell {
+    /// JSON object containing values read from the cell.
+    ///
+    /// Integers and grams are represented as decimal strings, bit strings
+    /// as hexadecimal strings using the TVM bitstring notation.
+    pub parsed: Value,
+}
+
+/// Reads cell data according to the query
+#[api_function]
+pub async fn query_cell(
+    context: std::sync::Arc<ClientContext>,
+    params: ParamsOfQueryCell,
+) -> ClientResult<ResultOfQueryCell> {
+    let query = CellQuery::parse(params.query)?;
+    let (_, cell) = deserialize_cell_from_boc(&context, &params.boc, "cell").await?;
+
+    Ok(ResultOfQueryCell {
+        parsed: query::query_cell(&query, &cell)?,
+    })
+}
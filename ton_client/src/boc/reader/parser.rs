@@ -11,17 +11,43 @@
 * limitations under the License.
 */
 
+use crate::boc::Error;
 use crate::error::{ClientResult, ClientError};
 
+/// Standard internal address bit length: tag(2) + anycast(1) + workchain(8) + address(256).
+const STD_ADDRESS_BIT_LEN: usize = 267;
+
 #[derive(Debug)]
 pub(crate) enum CellValueReader {
     IntWithSize(usize),
     UIntWithSize(usize),
     Grams,
-    Dict(Vec<CellFieldReader>),
-    // Cell(Vec<CellFieldReader>),
+    Bool,
+    Address,
+    Bits(usize),
+    Cell(Vec<CellFieldReader>),
+    Maybe(Box<CellValueReader>),
+    Dict {
+        key: Box<CellValueReader>,
+        key_size: usize,
+        value: Vec<CellFieldReader>,
+    },
 }
 
+impl CellValueReader {
+    /// Returns the bit length of the value if it has a fixed size and can be used
+    /// as a dictionary key.
+    fn key_size(&self) -> Option<usize> {
+        match self {
+            CellValueReader::IntWithSize(size)
+            | CellValueReader::UIntWithSize(size)
+            | CellValueReader::Bits(size) => Some(*size),
+            CellValueReader::Bool => Some(1),
+            CellValueReader::Address => Some(STD_ADDRESS_BIT_LEN),
+            _ => None,
+        }
+    }
+}
 
 #[derive(Debug)]
 pub(crate) struct CellFieldReader {
@@ -38,6 +64,7 @@ pub(crate) struct CellQuery {
 enum Token {
     Minus,
     Colon,
+    Comma,
     Open,
     Close,
     Identifier(String),
@@ -52,6 +79,10 @@ impl Token {
         if let Self::Colon = self { Some(()) } else { None }
     }
 
+    fn is_comma(&self) -> Option<()> {
+        if let Self::Comma = self { Some(()) } else { None }
+    }
+
     fn is_open(&self) -> Option<()> {
         if let Self::Open = self { Some(()) } else { None }
     }
@@ -94,12 +125,12 @@ impl Parser {
     }
 
     fn tokenize_error(rest: &str) -> ClientError {
-        ClientError::cell_invalid_query(format!("invalid character (-> {})", rest))
+        Error::invalid_cell_query(format!("invalid character (-> {})", rest))
     }
 
     fn parse_error(&self, msg: &str) -> ClientError {
         // TODO: error message must point to error position related to self.tokens[self.pos]
-        ClientError::cell_invalid_query(format!("{}", msg))
+        Error::invalid_cell_query(msg)
     }
 
     fn tokenize(source: String) -> ClientResult<Self> {
@@ -110,7 +141,9 @@ impl Parser {
             next = chars.next();
             if let Some(token) = match current {
                 space if space <= ' ' => None,
+                '-' => Some(Token::Minus),
                 ':' => Some(Token::Colon),
+                ',' => Some(Token::Comma),
                 '(' => Some(Token::Open),
                 ')' => Some(Token::Close),
                 first_ident if Self::is_first_ident_char(first_ident) => {
@@ -152,6 +185,15 @@ impl Parser {
         value
     }
 
+    fn expect<F, R>(&mut self, expected: F, msg: &str) -> ClientResult<R>
+        where F: Fn(&Token) -> Option<R>
+    {
+        match self.pass(expected) {
+            Some(value) => Ok(value),
+            None => Err(self.parse_error(msg)),
+        }
+    }
+
     fn parse_commands(&mut self) -> ClientResult<Vec<CellFieldReader>> {
         let mut commands = Vec::new();
         while let Some(command) = self.parse_command()? {
@@ -163,11 +205,8 @@ impl Parser {
     fn parse_optional_parenthesis_enclosed_commands(&mut self) -> ClientResult<Vec<CellFieldReader>> {
         if self.pass(Token::is_open) != None {
             let commands = self.parse_commands()?;
-            if self.pass(Token::is_close) != None {
-                Ok(commands)
-            } else {
-                Err(self.parse_error(") expected"))
-            }
+            self.expect(Token::is_close, ") expected")?;
+            Ok(commands)
         } else {
             Ok(Vec::new())
         }
@@ -197,24 +236,62 @@ impl Parser {
         }
     }
 
+    fn parse_size(&self, type_name: &str, prefix: &str, max: usize) -> ClientResult<Option<usize>> {
+        if !type_name.starts_with(prefix) {
+            return Ok(None);
+        }
+        let size = &type_name[prefix.len()..];
+        if size.is_empty() || !size.chars().all(Self::is_digit) {
+            return Ok(None);
+        }
+        match usize::from_str_radix(size, 10) {
+            Ok(size) if size > 0 && size <= max => Ok(Some(size)),
+            _ => Err(self.parse_error(&format!("invalid size of type [{}]", type_name))),
+        }
+    }
+
+    fn parse_dict(&mut self) -> ClientResult<CellValueReader> {
+        self.expect(Token::is_open, "( expected")?;
+        let key_type = self.expect(Token::identifier, "dictionary key type expected")?;
+        let key = self.parse_value_reader(&key_type)?;
+        let key_size = key.key_size().ok_or_else(|| self.parse_error(
+            &format!("type [{}] can not be used as a dictionary key", key_type)
+        ))?;
+        self.expect(Token::is_comma, ", expected")?;
+        let value = self.parse_commands()?;
+        self.expect(Token::is_close, ") expected")?;
+        Ok(CellValueReader::Dict {
+            key: Box::new(key),
+            key_size,
+            value,
+        })
+    }
+
+    fn parse_maybe(&mut self) -> ClientResult<CellValueReader> {
+        self.expect(Token::is_open, "( expected")?;
+        let type_name = self.expect(Token::identifier, "type expected")?;
+        let value = self.parse_value_reader(&type_name)?;
+        self.expect(Token::is_close, ") expected")?;
+        Ok(CellValueReader::Maybe(Box::new(value)))
+    }
+
     fn parse_value_reader(&mut self, type_name: &str) -> ClientResult<CellValueReader> {
+        if let Some(size) = self.parse_size(type_name, "u", 256)? {
+            return Ok(CellValueReader::UIntWithSize(size));
+        }
+        if let Some(size) = self.parse_size(type_name, "i", 257)? {
+            return Ok(CellValueReader::IntWithSize(size));
+        }
+        if let Some(size) = self.parse_size(type_name, "bits", 1023)? {
+            return Ok(CellValueReader::Bits(size));
+        }
         Ok(match type_name {
-            "u1" => CellValueReader::UIntWithSize(1),
-            "u8" => CellValueReader::UIntWithSize(8),
-            "u16" => CellValueReader::UIntWithSize(16),
-            "u32" => CellValueReader::UIntWithSize(32),
-            "u64" => CellValueReader::UIntWithSize(64),
-            "u128" => CellValueReader::UIntWithSize(128),
-            "u256" => CellValueReader::UIntWithSize(256),
-            "i1" => CellValueReader::IntWithSize(1),
-            "i8" => CellValueReader::IntWithSize(8),
-            "i16" => CellValueReader::IntWithSize(16),
-            "i32" => CellValueReader::IntWithSize(32),
-            "i64" => CellValueReader::IntWithSize(64),
-            "i128" => CellValueReader::IntWithSize(128),
-            "i256" => CellValueReader::IntWithSize(256),
+            "bool" => CellValueReader::Bool,
             "grams" => CellValueReader::Grams,
-            "dict" => CellValueReader::Dict(self.parse_optional_parenthesis_enclosed_commands()?),
+            "address" => CellValueReader::Address,
+            "cell" => CellValueReader::Cell(self.parse_optional_parenthesis_enclosed_commands()?),
+            "maybe" => self.parse_maybe()?,
+            "dict" => self.parse_dict()?,
             _ => return Err(self.parse_error(&format!("unknown type [{}]", type_name)))
         })
     }
@@ -12,39 +12,110 @@
 */
 
 use serde_json::Value;
-use crate::error::{ClientResult, ClientError};
+use crate::boc::Error;
+use crate::boc::internal::serialize_cell_to_base64;
+use crate::error::ClientResult;
 use super::parser::{CellQuery, CellFieldReader, CellValueReader};
-use ton_types::{Cell, SliceData, HashmapE, HashmapType};
+use num_bigint::{BigInt, BigUint, Sign};
+use ton_block::{Deserializable, MsgAddress};
 use ton_block::types::Grams;
+use ton_types::{Cell, SliceData, HashmapE, HashmapType};
+
+pub(crate) fn read_error<E: std::fmt::Display>(err: E) -> crate::error::ClientError {
+    Error::invalid_boc(format!("cell data doesn't match the expected layout: {}", err))
+}
+
+pub(crate) fn read_unsigned(slice: &mut SliceData, size: usize) -> ClientResult<BigUint> {
+    let bytes = slice.get_next_bits(size).map_err(read_error)?;
+    Ok(BigUint::from_bytes_be(&bytes) >> (bytes.len() * 8 - size))
+}
+
+pub(crate) fn read_signed(slice: &mut SliceData, size: usize) -> ClientResult<BigInt> {
+    let unsigned = BigInt::from_biguint(Sign::Plus, read_unsigned(slice, size)?);
+    let sign_bit = BigInt::from(1) << (size - 1);
+    Ok(if unsigned >= sign_bit {
+        unsigned - (sign_bit << 1)
+    } else {
+        unsigned
+    })
+}
+
+/// Formats bit string using the TVM bitstring notation: hexadecimal digits
+/// followed by `_` completion tag if the bit length is not a multiple of 4.
+pub(crate) fn bits_to_string(mut data: Vec<u8>, size: usize) -> String {
+    if size % 4 == 0 {
+        let mut string = hex::encode(&data);
+        string.truncate(size / 4);
+        string
+    } else {
+        data[size / 8] |= 0x80 >> (size % 8);
+        let mut string = hex::encode(&data);
+        string.truncate(size / 4 + 1);
+        string.push('_');
+        string
+    }
+}
 
 fn read_value(slice: &mut SliceData, reader: &CellValueReader) -> ClientResult<Value> {
     Ok(match reader {
         CellValueReader::IntWithSize(size) => {
-            let n = slice.get_next_int(*size)
-                .map_err(|err| ClientError::cell_invalid_query(err))?;
-            Value::String(format!("{}", n))
+            Value::String(read_signed(slice, *size)?.to_string())
         }
         CellValueReader::UIntWithSize(size) => {
-            let n = slice.get_next_int(*size)
-                .map_err(|err| ClientError::cell_invalid_query(err))?;
-            Value::String(format!("{}", n))
+            Value::String(read_unsigned(slice, *size)?.to_string())
         }
         CellValueReader::Grams => {
-            let n = Grams::read_from_cell(slice)
-                .map_err(|err| ClientError::cell_invalid_query(err))?;
+            let n = Grams::construct_from(slice).map_err(read_error)?;
             Value::String(format!("{}", n))
         }
-        CellValueReader::Dict(_fields) => {
-            let mut dict = HashmapE::with_bit_len(256);
-            dict.read_hashmap_data(slice)
-                .map_err(|err| ClientError::cell_invalid_query(err))?;
-            let mut count = 0;
-            let result = dict.iterate(&mut |_key, _value| {
-                count += 1;
+        CellValueReader::Bool => {
+            Value::Bool(slice.get_next_bit().map_err(read_error)?)
+        }
+        CellValueReader::Address => {
+            let address = MsgAddress::construct_from(slice).map_err(read_error)?;
+            Value::String(address.to_string())
+        }
+        CellValueReader::Bits(size) => {
+            let data = slice.get_next_bits(*size).map_err(read_error)?;
+            Value::String(bits_to_string(data, *size))
+        }
+        CellValueReader::Cell(fields) => {
+            let cell = slice.checked_drain_reference().map_err(read_error)?;
+            if fields.is_empty() {
+                Value::String(serialize_cell_to_base64(&cell, "cell")?)
+            } else {
+                read(&mut SliceData::from(cell), fields)?
+            }
+        }
+        CellValueReader::Maybe(value) => {
+            if slice.get_next_bit().map_err(read_error)? {
+                read_value(slice, value)?
+            } else {
+                Value::Null
+            }
+        }
+        CellValueReader::Dict { key: key_reader, key_size, value: value_fields } => {
+            let mut dict = HashmapE::with_bit_len(*key_size);
+            dict.read_hashmap_data(slice).map_err(read_error)?;
+            let mut items = Vec::new();
+            dict.iterate_slices(|key, value| {
+                items.push((key, value));
                 Ok(true)
-            });
-            result.map_err(|err| ClientError::cell_invalid_query(err))?;
-            Value::from(count)
+            }).map_err(read_error)?;
+            let mut values = serde_json::Map::new();
+            for (mut key_slice, mut value_slice) in items {
+                let key = match read_value(&mut key_slice, key_reader)? {
+                    Value::String(string) => string,
+                    other => other.to_string(),
+                };
+                let value = if value_fields.is_empty() {
+                    Value::String(serialize_cell_to_base64(&value_slice.into_cell(), "dict value")?)
+                } else {
+                    read(&mut value_slice, value_fields)?
+                };
+                values.insert(key, value);
+            }
+            Value::Object(values)
         }
     })
 }
@@ -52,8 +123,12 @@ fn read_value(slice: &mut SliceData, reader: &CellValueReader) -> ClientResult<V
 fn read(slice: &mut SliceData, commands: &Vec<CellFieldReader>) -> ClientResult<Value> {
     let mut values = serde_json::Map::new();
     for (index, command) in commands.iter().enumerate() {
+        let value = read_value(slice, &command.value)?;
+        if command.skip {
+            continue;
+        }
         let name = if command.name.is_empty() { format!("{}", index) } else { command.name.clone() };
-        values.insert(name, read_value(slice, &command.value)?);
+        values.insert(name, value);
     }
     Ok(Value::Object(values))
 }
@@ -0,0 +1,510 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+use super::schema::{Constructor, Field, NatKind, Schema, TypeExpr};
+use crate::boc::internal::serialize_cell_to_base64;
+use crate::boc::reader::query::{bits_to_string, read_error, read_signed, read_unsigned};
+use crate::boc::Error;
+use crate::error::ClientResult;
+use num_traits::ToPrimitive;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use ton_block::{Deserializable, Grams, MsgAddress, MsgAddressExt, MsgAddressInt};
+use ton_types::{Cell, HashmapE, HashmapType, SliceData};
+
+const MAX_DEPTH: usize = 256;
+/// Array items that don't consume any data (e.g. `n * Unit`) allowed
+/// in addition to the items bounded by the remaining data.
+const MAX_EMPTY_ITEMS: u64 = 1024;
+
+/// Values bound to the constructor parameters and previously read fields.
+#[derive(Default, Clone)]
+struct Env {
+    nats: HashMap<String, u64>,
+    types: HashMap<String, TypeExpr>,
+}
+
+type Decoded = (Value, Option<u64>);
+
+pub(crate) struct Decoder<'a> {
+    schema: &'a Schema,
+}
+
+fn bit_len(n: u64) -> usize {
+    64 - n.leading_zeros() as usize
+}
+
+fn bit_of(n: u64, bit: u32) -> ClientResult<u64> {
+    n.checked_shr(bit)
+        .map(|n| n & 1)
+        .ok_or_else(|| Error::invalid_tlb_schema(format!("bit index {} is out of range", bit)))
+}
+
+fn nat_value(value: num_bigint::BigUint) -> Decoded {
+    let nat = value.to_u64();
+    (Value::String(value.to_string()), nat)
+}
+
+fn arg<'a>(args: &'a [TypeExpr], name: &str, index: usize) -> ClientResult<&'a TypeExpr> {
+    args.get(index).ok_or_else(|| {
+        Error::invalid_tlb_schema(format!("type `{}` expects {} arguments", name, index + 1))
+    })
+}
+
+fn unsupported(what: &str) -> crate::error::ClientError {
+    Error::invalid_tlb_schema(format!("{} are not supported", what))
+}
+
+impl<'a> Decoder<'a> {
+    pub(crate) fn new(schema: &'a Schema) -> Self {
+        Self { schema }
+    }
+
+    pub(crate) fn decode(&self, type_expr: &TypeExpr, cell: Cell) -> ClientResult<Value> {
+        let mut slice = SliceData::from(cell);
+        Ok(self.decode_type(&mut slice, type_expr, &Env::default(), 0)?.0)
+    }
+
+    fn eval_nat(&self, expr: &TypeExpr, env: &Env) -> ClientResult<u64> {
+        match expr {
+            TypeExpr::Nat(n) => Ok(*n),
+            TypeExpr::Apply(name, args) if args.is_empty() && env.nats.contains_key(name) => {
+                Ok(env.nats[name])
+            }
+            TypeExpr::Add(a, b) => self
+                .eval_nat(a, env)?
+                .checked_add(self.eval_nat(b, env)?)
+                .ok_or_else(|| Error::invalid_tlb_schema(format!("{:?} overflows", expr))),
+            TypeExpr::Mul(a, b) => self
+                .eval_nat(a, env)?
+                .checked_mul(self.eval_nat(b, env)?)
+                .ok_or_else(|| Error::invalid_tlb_schema(format!("{:?} overflows", expr))),
+            _ => Err(Error::invalid_tlb_schema(format!(
+                "can not evaluate natural number from {:?}",
+                expr
+            ))),
+        }
+    }
+
+    /// Substitutes parameters bound in `env` so the expression can be
+    /// passed to another constructor.
+    fn resolve(&self, expr: &TypeExpr, env: &Env) -> TypeExpr {
+        let resolve = |expr: &TypeExpr| Box::new(self.resolve(expr, env));
+        match expr {
+            TypeExpr::Apply(name, args) if args.is_empty() => {
+                if let Some(n) = env.nats.get(name) {
+                    TypeExpr::Nat(*n)
+                } else if let Some(bound) = env.types.get(name) {
+                    bound.clone()
+                } else {
+                    expr.clone()
+                }
+            }
+            TypeExpr::Apply(name, args) => TypeExpr::Apply(
+                name.clone(),
+                args.iter().map(|arg| self.resolve(arg, env)).collect(),
+            ),
+            TypeExpr::Add(a, b) | TypeExpr::Mul(a, b) => {
+                let is_add = matches!(expr, TypeExpr::Add(..));
+                let (a, b) = (self.resolve(a, env), self.resolve(b, env));
+                if let (TypeExpr::Nat(x), TypeExpr::Nat(y)) = (&a, &b) {
+                    // Overflowing expressions are left unfolded, so `eval_nat` reports them
+                    if let Some(n) = if is_add { x.checked_add(*y) } else { x.checked_mul(*y) } {
+                        return TypeExpr::Nat(n);
+                    }
+                }
+                if is_add {
+                    TypeExpr::Add(Box::new(a), Box::new(b))
+                } else {
+                    TypeExpr::Mul(Box::new(a), Box::new(b))
+                }
+            }
+            TypeExpr::NatType(kind, arg) => TypeExpr::NatType(*kind, arg.as_ref().map(|arg| resolve(arg))),
+            TypeExpr::Ref(inner) => TypeExpr::Ref(resolve(inner)),
+            TypeExpr::Cond(cond, inner) => TypeExpr::Cond(resolve(cond), resolve(inner)),
+            TypeExpr::BitOf(name, bit) => match env.nats.get(name).map(|n| bit_of(*n, *bit)) {
+                Some(Ok(n)) => TypeExpr::Nat(n),
+                _ => expr.clone(),
+            },
+            TypeExpr::Record(fields) => TypeExpr::Record(
+                fields
+                    .iter()
+                    .map(|field| Field {
+                        name: field.name.clone(),
+                        type_expr: self.resolve(&field.type_expr, env),
+                        implicit: field.implicit,
+                    })
+                    .collect(),
+            ),
+            TypeExpr::Nat(_) | TypeExpr::Negate(_) => expr.clone(),
+        }
+    }
+
+    fn decode_type(
+        &self,
+        slice: &mut SliceData,
+        expr: &TypeExpr,
+        env: &Env,
+        depth: usize,
+    ) -> ClientResult<Decoded> {
+        if depth > MAX_DEPTH {
+            return Err(Error::invalid_boc(format!(
+                "TL-B type nesting exceeds {} levels",
+                MAX_DEPTH
+            )));
+        }
+        let depth = depth + 1;
+        match expr {
+            TypeExpr::NatType(kind, arg) => {
+                let bound = arg.as_ref().map(|arg| self.eval_nat(arg, env)).transpose()?.unwrap_or(0);
+                let size = match kind {
+                    NatKind::Nat32 => 32,
+                    NatKind::Bits => bound as usize,
+                    NatKind::Less => bit_len(bound.saturating_sub(1)),
+                    NatKind::LessOrEqual => bit_len(bound),
+                };
+                if size == 0 {
+                    return Ok((Value::String("0".into()), Some(0)));
+                }
+                Ok(nat_value(read_unsigned(slice, size)?))
+            }
+            TypeExpr::Ref(inner) => {
+                let cell = slice.checked_drain_reference().map_err(read_error)?;
+                match inner.as_ref() {
+                    TypeExpr::Apply(name, args)
+                        if args.is_empty() && (name == "Cell" || name == "Any") =>
+                    {
+                        Ok((Value::String(serialize_cell_to_base64(&cell, "cell")?), None))
+                    }
+                    _ => self.decode_type(&mut SliceData::from(cell), inner, env, depth),
+                }
+            }
+            TypeExpr::Mul(count, item) => {
+                let count = self.eval_nat(count, env)?;
+                if let TypeExpr::Apply(name, args) = item.as_ref() {
+                    if name == "Bit" && args.is_empty() && !self.schema.types.contains_key(name) {
+                        if count == 0 {
+                            return Ok((Value::String(String::new()), None));
+                        }
+                        let data = slice.get_next_bits(count as usize).map_err(read_error)?;
+                        return Ok((Value::String(bits_to_string(data, count as usize)), None));
+                    }
+                }
+                let remaining =
+                    slice.remaining_bits() as u64 + slice.remaining_references() as u64;
+                if count > remaining + MAX_EMPTY_ITEMS {
+                    return Err(Error::invalid_boc(format!(
+                        "array of {} items does not fit into the remaining data",
+                        count
+                    )));
+                }
+                let mut items = Vec::new();
+                for _ in 0..count {
+                    items.push(self.decode_type(slice, item, env, depth)?.0);
+                }
+                Ok((Value::Array(items), None))
+            }
+            TypeExpr::Cond(cond, inner) => {
+                let flag = match cond.as_ref() {
+                    TypeExpr::BitOf(name, bit) => {
+                        bit_of(self.eval_nat(&TypeExpr::Apply(name.clone(), vec![]), env)?, *bit)?
+                    }
+                    cond => self.eval_nat(cond, env)?,
+                };
+                if flag != 0 {
+                    self.decode_type(slice, inner, env, depth)
+                } else {
+                    Ok((Value::Null, None))
+                }
+            }
+            TypeExpr::Record(fields) => {
+                Ok((self.decode_fields(slice, fields, env.clone(), depth)?, None))
+            }
+            TypeExpr::Apply(name, args) => {
+                if args.is_empty() {
+                    if let Some(bound) = env.types.get(name) {
+                        return self.decode_type(slice, bound, &Env::default(), depth);
+                    }
+                }
+                let args: Vec<TypeExpr> = args.iter().map(|arg| self.resolve(arg, env)).collect();
+                if name != "HashmapE" {
+                    if let Some(constructors) = self.schema.types.get(name) {
+                        return self.decode_constructor(slice, name, constructors, &args, depth);
+                    }
+                }
+                self.decode_builtin(slice, name, &args, depth)
+            }
+            TypeExpr::Negate(_) => Err(unsupported("output type arguments (`~`)")),
+            TypeExpr::Nat(_) | TypeExpr::Add(..) | TypeExpr::BitOf(..) => {
+                Err(Error::invalid_tlb_schema(format!(
+                    "natural number expression {:?} can not be used as a type",
+                    expr
+                )))
+            }
+        }
+    }
+
+    fn decode_fields(
+        &self,
+        slice: &mut SliceData,
+        fields: &[Field],
+        mut env: Env,
+        depth: usize,
+    ) -> ClientResult<Value> {
+        let mut values = Map::new();
+        for (index, field) in fields.iter().enumerate() {
+            if field.implicit {
+                continue;
+            }
+            let (value, nat) = self.decode_type(slice, &field.type_expr, &env, depth)?;
+            match &field.name {
+                Some(name) => {
+                    if let Some(nat) = nat {
+                        env.nats.insert(name.clone(), nat);
+                    }
+                    values.insert(name.clone(), value);
+                }
+                None => {
+                    values.insert(index.to_string(), value);
+                }
+            }
+        }
+        Ok(Value::Object(values))
+    }
+
+    /// Binds constructor result type argument `pattern` to the `actual` argument.
+    /// Returns `false` if the constructor doesn't match.
+    fn match_arg(
+        &self,
+        pattern: &TypeExpr,
+        actual: &TypeExpr,
+        params: &HashSet<&str>,
+        env: &mut Env,
+    ) -> ClientResult<bool> {
+        match (pattern, actual) {
+            (TypeExpr::Negate(_), _) => Err(unsupported("output type arguments (`~`)")),
+            (TypeExpr::Apply(name, args), _) if args.is_empty() && params.contains(name.as_str()) => {
+                if let TypeExpr::Nat(n) = actual {
+                    if let Some(bound) = env.nats.get(name) {
+                        return Ok(bound == n);
+                    }
+                    env.nats.insert(name.clone(), *n);
+                } else {
+                    env.types.insert(name.clone(), actual.clone());
+                }
+                Ok(true)
+            }
+            (TypeExpr::Add(a, b), TypeExpr::Nat(n)) => {
+                let (var, constant) = match (a.as_ref(), b.as_ref()) {
+                    (var, TypeExpr::Nat(constant)) | (TypeExpr::Nat(constant), var) => (var, *constant),
+                    _ => return Ok(pattern == actual),
+                };
+                if *n < constant {
+                    return Ok(false);
+                }
+                self.match_arg(var, &TypeExpr::Nat(n - constant), params, env)
+            }
+            _ => Ok(pattern == actual),
+        }
+    }
+
+    fn tag_matches(slice: &SliceData, tag: &[bool]) -> bool {
+        if slice.remaining_bits() < tag.len() {
+            return false;
+        }
+        let mut slice = slice.clone();
+        tag.iter().all(|bit| slice.get_next_bit().ok() == Some(*bit))
+    }
+
+    fn decode_constructor(
+        &self,
+        slice: &mut SliceData,
+        type_name: &str,
+        constructors: &[Constructor],
+        args: &[TypeExpr],
+        depth: usize,
+    ) -> ClientResult<Decoded> {
+        let mut matched: Option<(&Constructor, Env)> = None;
+        for constructor in constructors {
+            if constructor.args.len() != args.len() {
+                continue;
+            }
+            if let Some((current, _)) = &matched {
+                if current.tag.len() >= constructor.tag.len() {
+                    continue;
+                }
+            }
+            if !Self::tag_matches(slice, &constructor.tag) {
+                continue;
+            }
+            let params: HashSet<&str> = constructor
+                .fields
+                .iter()
+                .filter(|field| field.implicit)
+                .filter_map(|field| field.name.as_deref())
+                .collect();
+            let mut env = Env::default();
+            let mut arguments_match = true;
+            for (pattern, actual) in constructor.args.iter().zip(args) {
+                if !self.match_arg(pattern, actual, &params, &mut env)? {
+                    arguments_match = false;
+                    break;
+                }
+            }
+            if arguments_match {
+                matched = Some((constructor, env));
+            }
+        }
+        let (constructor, env) = matched.ok_or_else(|| {
+            Error::invalid_boc(format!("no constructor of type `{}` matches the cell data", type_name))
+        })?;
+        if !constructor.tag.is_empty() {
+            slice.get_next_bits(constructor.tag.len()).map_err(read_error)?;
+        }
+        let mut value = self.decode_fields(slice, &constructor.fields, env, depth)?;
+        if constructor.name != "_" {
+            value["@type"] = Value::String(constructor.name.clone());
+        }
+        Ok((value, None))
+    }
+
+    fn decode_builtin(
+        &self,
+        slice: &mut SliceData,
+        name: &str,
+        args: &[TypeExpr],
+        depth: usize,
+    ) -> ClientResult<Decoded> {
+        let empty = Env::default();
+        let sized = |prefix: &str| -> ClientResult<Option<usize>> {
+            if args.len() == 1 && name == prefix {
+                return Ok(Some(self.eval_nat(&args[0], &empty)? as usize));
+            }
+            let size = match name.strip_prefix(prefix) {
+                Some(size) if args.is_empty() && !size.is_empty() => size,
+                _ => return Ok(None),
+            };
+            Ok(size.parse().ok())
+        };
+        if let Some(size) = sized("uint")? {
+            return Ok(if size == 0 {
+                (Value::String("0".into()), Some(0))
+            } else {
+                nat_value(read_unsigned(slice, size)?)
+            });
+        }
+        if let Some(size) = sized("int")? {
+            return Ok(if size == 0 {
+                (Value::String("0".into()), None)
+            } else {
+                (Value::String(read_signed(slice, size)?.to_string()), None)
+            });
+        }
+        if let Some(size) = sized("bits")? {
+            return Ok(if size == 0 {
+                (Value::String(String::new()), None)
+            } else {
+                let data = slice.get_next_bits(size).map_err(read_error)?;
+                (Value::String(bits_to_string(data, size)), None)
+            });
+        }
+        match name {
+            "Bit" | "Bool" => {
+                let bit = slice.get_next_bit().map_err(read_error)?;
+                Ok((Value::Bool(bit), Some(bit as u64)))
+            }
+            "True" | "Unit" => Ok((Value::Null, None)),
+            "Cell" | "Any" => {
+                let rest = slice.clone();
+                if slice.remaining_bits() > 0 {
+                    slice.get_next_bits(slice.remaining_bits()).map_err(read_error)?;
+                }
+                while slice.remaining_references() > 0 {
+                    slice.checked_drain_reference().map_err(read_error)?;
+                }
+                Ok((Value::String(serialize_cell_to_base64(&rest.into_cell(), "cell")?), None))
+            }
+            "Maybe" => {
+                if slice.get_next_bit().map_err(read_error)? {
+                    self.decode_type(slice, arg(args, name, 0)?, &empty, depth)
+                } else {
+                    Ok((Value::Null, None))
+                }
+            }
+            "Either" => {
+                let (constructor, inner) = if slice.get_next_bit().map_err(read_error)? {
+                    ("right", arg(args, name, 1)?)
+                } else {
+                    ("left", arg(args, name, 0)?)
+                };
+                Ok((json!({
+                    "@type": constructor,
+                    "value": self.decode_type(slice, inner, &empty, depth)?.0,
+                }), None))
+            }
+            "Both" => Ok((json!({
+                "first": self.decode_type(slice, arg(args, name, 0)?, &empty, depth)?.0,
+                "second": self.decode_type(slice, arg(args, name, 1)?, &empty, depth)?.0,
+            }), None)),
+            "Grams" | "Coins" => {
+                let grams = Grams::construct_from(slice).map_err(read_error)?;
+                Ok((Value::String(grams.to_string()), None))
+            }
+            "VarUInteger" | "VarInteger" => {
+                let len_size = bit_len(self.eval_nat(arg(args, name, 0)?, &empty)?.saturating_sub(1));
+                let len = read_unsigned(slice, len_size)?.to_usize().unwrap_or(0);
+                if len == 0 {
+                    return Ok((Value::String("0".into()), Some(0)));
+                }
+                let size = len
+                    .checked_mul(8)
+                    .ok_or_else(|| Error::invalid_boc(format!("{} length {} is too big", name, len)))?;
+                if name == "VarUInteger" {
+                    Ok(nat_value(read_unsigned(slice, size)?))
+                } else {
+                    Ok((Value::String(read_signed(slice, size)?.to_string()), None))
+                }
+            }
+            "MsgAddress" => {
+                let address = MsgAddress::construct_from(slice).map_err(read_error)?;
+                Ok((Value::String(address.to_string()), None))
+            }
+            "MsgAddressInt" => {
+                let address = MsgAddressInt::construct_from(slice).map_err(read_error)?;
+                Ok((Value::String(address.to_string()), None))
+            }
+            "MsgAddressExt" => {
+                let address = MsgAddressExt::construct_from(slice).map_err(read_error)?;
+                Ok((Value::String(address.to_string()), None))
+            }
+            "HashmapE" => {
+                let key_size = self.eval_nat(arg(args, name, 0)?, &empty)? as usize;
+                let value_type = arg(args, name, 1)?;
+                let mut dict = HashmapE::with_bit_len(key_size);
+                dict.read_hashmap_data(slice).map_err(read_error)?;
+                let mut items = Vec::new();
+                dict.iterate_slices(|key, value| {
+                    items.push((key, value));
+                    Ok(true)
+                }).map_err(read_error)?;
+                let mut values = Map::new();
+                for (mut key, mut value) in items {
+                    let key = read_unsigned(&mut key, key_size)?.to_string();
+                    values.insert(key, self.decode_type(&mut value, value_type, &empty, depth)?.0);
+                }
+                Ok((Value::Object(values), None))
+            }
+            _ => Err(Error::invalid_tlb_schema(format!("unknown type `{}`", name))),
+        }
+    }
+}
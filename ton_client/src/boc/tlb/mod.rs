@@ -0,0 +1,61 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+use crate::boc::internal::deserialize_cell_from_boc;
+use crate::boc::ResultOfParse;
+use crate::client::ClientContext;
+use crate::error::ClientResult;
+
+mod decoder;
+mod schema;
+
+use decoder::Decoder;
+use schema::Schema;
+
+#[derive(Serialize, Deserialize, Clone, ApiType, Default)]
+pub struct ParamsOfParseTlb {
+    /// BOC encoded as base64 or BOC cache reference
+    pub boc: String,
+    /// TL-B schema containing constructor declarations
+    /// terminated with `;`, e.g. `wallet$_ seqno:uint32 owner:bits256 = Wallet;`
+    ///
+    /// Limitations:
+    /// - constructors without explicit `#` or `$` tag are treated as having an empty tag;
+    /// - output type arguments (`~n`) are not supported;
+    /// - constraints in curly braces are not checked;
+    /// - `HashmapE n X` is always decoded natively.
+    pub schema: String,
+    /// Root type expression, e.g. `Wallet` or `HashmapE 32 Grams`
+    pub root_type: String,
+}
+
+/// Parses BOC into a JSON using TL-B schema
+///
+/// Each constructor is represented as a JSON object containing its
+/// named fields and constructor name in the `@type` field.
+/// Integers are represented as decimal strings, bit strings
+/// as hexadecimal strings using the TVM bitstring notation,
+/// `Cell` and `Any` values as BOCs encoded with base64.
+#[api_function]
+pub async fn parse_tlb(
+    context: std::sync::Arc<ClientContext>,
+    params: ParamsOfParseTlb,
+) -> ClientResult<ResultOfParse> {
+    let schema = Schema::parse(&params.schema)?;
+    let root_type = schema.parse_type(&params.root_type)?;
+    let (_, cell) = deserialize_cell_from_boc(&context, &params.boc, "TL-B object").await?;
+
+    Ok(ResultOfParse {
+        parsed: Decoder::new(&schema).decode(&root_type, cell)?,
+    })
+}
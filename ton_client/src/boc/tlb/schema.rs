@@ -0,0 +1,491 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+use crate::boc::Error;
+use crate::error::{ClientError, ClientResult};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum TypeExpr {
+    /// Natural number literal.
+    Nat(u64),
+    /// Named type applied to arguments. Also used for references to
+    /// type parameters and previously read fields.
+    Apply(String, Vec<TypeExpr>),
+    /// `#`, `## n`, `#< n`, `#<= n`
+    NatType(NatKind, Option<Box<TypeExpr>>),
+    /// `^T`
+    Ref(Box<TypeExpr>),
+    /// `a + b`
+    Add(Box<TypeExpr>, Box<TypeExpr>),
+    /// `a * b` – multiplication of naturals or repetition of a type.
+    Mul(Box<TypeExpr>, Box<TypeExpr>),
+    /// `flag?T`
+    Cond(Box<TypeExpr>, Box<TypeExpr>),
+    /// `flags.n` used as a condition.
+    BitOf(String, u32),
+    /// `[ fields ]` – anonymous record.
+    Record(Vec<Field>),
+    /// `~n` – output type argument.
+    Negate(Box<TypeExpr>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum NatKind {
+    /// `#` – 32-bit unsigned natural
+    Nat32,
+    /// `## n` – natural of `n` bits
+    Bits,
+    /// `#< n` – natural less than `n`
+    Less,
+    /// `#<= n` – natural less or equal to `n`
+    LessOrEqual,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Field {
+    pub name: Option<String>,
+    pub type_expr: TypeExpr,
+    pub implicit: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Constructor {
+    pub name: String,
+    pub tag: Vec<bool>,
+    pub fields: Vec<Field>,
+    pub type_name: String,
+    pub args: Vec<TypeExpr>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Schema {
+    pub types: HashMap<String, Vec<Constructor>>,
+    arities: HashMap<String, usize>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(u64),
+    Tag(Vec<bool>),
+    Punct(&'static str),
+}
+
+const PUNCTS: [&str; 24] = [
+    "#<=", "##", "#<", "<=", ">=", "!=", "#", ":", "=", ";", "(", ")", "[", "]", "{", "}", "^",
+    "?", ".", "+", "*", "~", "<", ">",
+];
+
+const BUILTIN_ARITIES: [(&str, usize); 9] = [
+    ("Maybe", 1),
+    ("Either", 2),
+    ("Both", 2),
+    ("HashmapE", 2),
+    ("uint", 1),
+    ("int", 1),
+    ("bits", 1),
+    ("VarUInteger", 1),
+    ("VarInteger", 1),
+];
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn schema_error(msg: impl std::fmt::Display) -> ClientError {
+    Error::invalid_tlb_schema(msg)
+}
+
+fn hex_tag(source: &str) -> ClientResult<Vec<bool>> {
+    let (digits, completion) = match source.strip_suffix('_') {
+        Some(digits) => (digits, true),
+        None => (source, false),
+    };
+    let mut bits = Vec::new();
+    for c in digits.chars() {
+        let digit = c
+            .to_digit(16)
+            .ok_or_else(|| schema_error(format!("invalid hexadecimal tag #{}", source)))?;
+        for i in (0..4).rev() {
+            bits.push(digit & (1 << i) != 0);
+        }
+    }
+    if completion {
+        while bits.last() == Some(&false) {
+            bits.pop();
+        }
+        bits.pop();
+    }
+    Ok(bits)
+}
+
+fn binary_tag(source: &str) -> ClientResult<Vec<bool>> {
+    let digits = source.strip_suffix('_').unwrap_or(source);
+    digits
+        .chars()
+        .map(|c| match c {
+            '0' => Ok(false),
+            '1' => Ok(true),
+            _ => Err(schema_error(format!("invalid binary tag ${}", source))),
+        })
+        .collect()
+}
+
+fn tokenize(source: &str) -> ClientResult<Vec<Token>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+    while pos < chars.len() {
+        let c = chars[pos];
+        if c.is_whitespace() {
+            pos += 1;
+        } else if c == '/' && chars.get(pos + 1) == Some(&'/') {
+            while pos < chars.len() && chars[pos] != '\n' {
+                pos += 1;
+            }
+        } else if c == '/' && chars.get(pos + 1) == Some(&'*') {
+            pos += 2;
+            while pos < chars.len() && !(chars[pos] == '*' && chars.get(pos + 1) == Some(&'/')) {
+                pos += 1;
+            }
+            pos += 2;
+        } else if c.is_ascii_digit() {
+            let start = pos;
+            while pos < chars.len() && chars[pos].is_ascii_digit() {
+                pos += 1;
+            }
+            let number: String = chars[start..pos].iter().collect();
+            tokens.push(Token::Number(
+                number.parse().map_err(|err| schema_error(format!("invalid number {}: {}", number, err)))?,
+            ));
+        } else if is_ident_char(c) {
+            let start = pos;
+            while pos < chars.len() && is_ident_char(chars[pos]) {
+                pos += 1;
+            }
+            tokens.push(Token::Ident(chars[start..pos].iter().collect()));
+            // constructor tag immediately follows the constructor name
+            if let Some(&prefix) = chars.get(pos) {
+                if prefix == '#' || prefix == '$' {
+                    let start = pos + 1;
+                    pos = start;
+                    while pos < chars.len() && is_ident_char(chars[pos]) {
+                        pos += 1;
+                    }
+                    let tag: String = chars[start..pos].iter().collect();
+                    tokens.push(Token::Tag(if prefix == '#' { hex_tag(&tag)? } else { binary_tag(&tag)? }));
+                }
+            }
+        } else {
+            let rest: String = chars[pos..chars.len().min(pos + 3)].iter().collect();
+            let punct = PUNCTS
+                .iter()
+                .find(|punct| rest.starts_with(*punct))
+                .ok_or_else(|| schema_error(format!("invalid character `{}`", c)))?;
+            pos += punct.chars().count();
+            tokens.push(Token::Punct(*punct));
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    arities: &'a HashMap<String, usize>,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset)
+    }
+
+    fn is_punct(&self, punct: &str) -> bool {
+        matches!(self.peek(), Some(Token::Punct(p)) if *p == punct)
+    }
+
+    fn pass_punct(&mut self, punct: &str) -> bool {
+        if self.is_punct(punct) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_punct(&mut self, punct: &str) -> ClientResult<()> {
+        if self.pass_punct(punct) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("`{}` expected", punct)))
+        }
+    }
+
+    fn expect_ident(&mut self) -> ClientResult<String> {
+        match self.peek() {
+            Some(Token::Ident(ident)) => {
+                let ident = ident.clone();
+                self.pos += 1;
+                Ok(ident)
+            }
+            _ => Err(self.error("identifier expected")),
+        }
+    }
+
+    fn error(&self, msg: &str) -> ClientError {
+        schema_error(format!("{} at token {} ({:?})", msg, self.pos, self.peek()))
+    }
+
+    fn eof(&self) -> bool {
+        self.pos >= self.tokens.len()
+    }
+
+    /// Returns `true` if the next token can start a type term.
+    fn is_term_start(&self) -> bool {
+        match self.peek() {
+            Some(Token::Ident(_)) | Some(Token::Number(_)) => true,
+            Some(Token::Punct(p)) => ["(", "[", "^", "~", "#", "##", "#<", "#<="].contains(p),
+            _ => false,
+        }
+    }
+
+    fn parse_constructor(&mut self) -> ClientResult<Constructor> {
+        let name = self.expect_ident()?;
+        let tag = if let Some(Token::Tag(tag)) = self.peek() {
+            let tag = tag.clone();
+            self.pos += 1;
+            tag
+        } else {
+            Vec::new()
+        };
+        let fields = self.parse_fields(&["="])?;
+        self.expect_punct("=")?;
+        let type_name = self.expect_ident()?;
+        let mut args = Vec::new();
+        while !self.is_punct(";") {
+            args.push(self.parse_term(false)?);
+        }
+        self.expect_punct(";")?;
+        Ok(Constructor {
+            name,
+            tag,
+            fields,
+            type_name,
+            args,
+        })
+    }
+
+    fn parse_fields(&mut self, terminators: &[&str]) -> ClientResult<Vec<Field>> {
+        let mut fields = Vec::new();
+        while !self.eof() && !terminators.iter().any(|t| self.is_punct(t)) {
+            if self.pass_punct("{") {
+                if let (Some(Token::Ident(_)), Some(Token::Punct(":"))) = (self.peek(), self.peek_at(1)) {
+                    let name = self.expect_ident()?;
+                    self.expect_punct(":")?;
+                    let type_expr = self.parse_expr()?;
+                    self.expect_punct("}")?;
+                    fields.push(Field { name: Some(name), type_expr, implicit: true });
+                } else {
+                    // constraints are not checked
+                    while !self.pass_punct("}") {
+                        if self.eof() {
+                            return Err(self.error("`}` expected"));
+                        }
+                        self.pos += 1;
+                    }
+                }
+                continue;
+            }
+            let name = if let (Some(Token::Ident(_)), Some(Token::Punct(":"))) = (self.peek(), self.peek_at(1)) {
+                let name = self.expect_ident()?;
+                self.pos += 1;
+                if name == "_" { None } else { Some(name) }
+            } else {
+                None
+            };
+            fields.push(Field {
+                name,
+                type_expr: self.parse_expr()?,
+                implicit: false,
+            });
+        }
+        Ok(fields)
+    }
+
+    fn parse_expr(&mut self) -> ClientResult<TypeExpr> {
+        let expr = self.parse_sum()?;
+        if self.pass_punct("?") {
+            Ok(TypeExpr::Cond(Box::new(expr), Box::new(self.parse_expr()?)))
+        } else {
+            Ok(expr)
+        }
+    }
+
+    fn parse_sum(&mut self) -> ClientResult<TypeExpr> {
+        let mut expr = self.parse_product()?;
+        while self.pass_punct("+") {
+            expr = TypeExpr::Add(Box::new(expr), Box::new(self.parse_product()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_product(&mut self) -> ClientResult<TypeExpr> {
+        let mut expr = self.parse_term(true)?;
+        while self.pass_punct("*") {
+            expr = TypeExpr::Mul(Box::new(expr), Box::new(self.parse_term(true)?));
+        }
+        Ok(expr)
+    }
+
+    /// Parses single type term. Type applications take as many arguments as
+    /// the applied type expects if `apply` is set.
+    fn parse_term(&mut self, apply: bool) -> ClientResult<TypeExpr> {
+        if self.pass_punct("~") {
+            return Ok(TypeExpr::Negate(Box::new(self.parse_term(false)?)));
+        }
+        if self.pass_punct("^") {
+            return Ok(TypeExpr::Ref(Box::new(self.parse_term(apply)?)));
+        }
+        if self.pass_punct("(") {
+            let expr = self.parse_expr()?;
+            self.expect_punct(")")?;
+            return Ok(expr);
+        }
+        if self.pass_punct("[") {
+            let fields = self.parse_fields(&["]"])?;
+            self.expect_punct("]")?;
+            return Ok(TypeExpr::Record(fields));
+        }
+        if self.pass_punct("#") {
+            return Ok(TypeExpr::NatType(NatKind::Nat32, None));
+        }
+        for (punct, kind) in &[("##", NatKind::Bits), ("#<", NatKind::Less), ("#<=", NatKind::LessOrEqual)] {
+            if self.pass_punct(punct) {
+                return Ok(TypeExpr::NatType(*kind, Some(Box::new(self.parse_term(false)?))));
+            }
+        }
+        match self.peek().cloned() {
+            Some(Token::Number(number)) => {
+                self.pos += 1;
+                Ok(TypeExpr::Nat(number))
+            }
+            Some(Token::Ident(name)) => {
+                self.pos += 1;
+                if self.pass_punct(".") {
+                    return match self.peek().cloned() {
+                        Some(Token::Number(bit)) if bit < 64 => {
+                            self.pos += 1;
+                            Ok(TypeExpr::BitOf(name, bit as u32))
+                        }
+                        _ => Err(self.error("bit number from 0 to 63 expected")),
+                    };
+                }
+                let mut args = Vec::new();
+                if apply {
+                    let arity = self.arities.get(&name).cloned().unwrap_or(0);
+                    while args.len() < arity && self.is_term_start() {
+                        args.push(self.parse_term(false)?);
+                    }
+                }
+                Ok(TypeExpr::Apply(name, args))
+            }
+            _ => Err(self.error("type expected")),
+        }
+    }
+}
+
+/// Collects type arities from the constructor result types:
+/// everything between `=` and `;` is a type name followed by its arguments.
+fn collect_arities(tokens: &[Token]) -> HashMap<String, usize> {
+    let mut arities: HashMap<String, usize> = BUILTIN_ARITIES
+        .iter()
+        .map(|(name, arity)| (name.to_string(), *arity))
+        .collect();
+    let mut pos = 0;
+    while pos < tokens.len() {
+        if tokens[pos] != Token::Punct("=") {
+            pos += 1;
+            continue;
+        }
+        pos += 1;
+        let name = match tokens.get(pos) {
+            Some(Token::Ident(name)) => name.clone(),
+            _ => continue,
+        };
+        pos += 1;
+        let mut arity = 0;
+        let mut depth = 0;
+        while pos < tokens.len() && tokens[pos] != Token::Punct(";") {
+            match &tokens[pos] {
+                Token::Punct("(") => {
+                    if depth == 0 {
+                        arity += 1;
+                    }
+                    depth += 1;
+                }
+                Token::Punct(")") => depth -= 1,
+                Token::Punct("~") | Token::Punct("^") => {}
+                _ => {
+                    if depth == 0 {
+                        arity += 1;
+                    }
+                }
+            }
+            pos += 1;
+        }
+        arities.insert(name, arity);
+    }
+    arities
+}
+
+impl Schema {
+    pub(crate) fn parse(source: &str) -> ClientResult<Self> {
+        let tokens = tokenize(source)?;
+        let arities = collect_arities(&tokens);
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            arities: &arities,
+        };
+        let mut types: HashMap<String, Vec<Constructor>> = HashMap::new();
+        while !parser.eof() {
+            let constructor = parser.parse_constructor()?;
+            types
+                .entry(constructor.type_name.clone())
+                .or_default()
+                .push(constructor);
+        }
+        Ok(Self { types, arities })
+    }
+
+    /// Parses type expression (e.g. root type `HashmapE 32 Grams`) using
+    /// the type arities known from this schema.
+    pub(crate) fn parse_type(&self, source: &str) -> ClientResult<TypeExpr> {
+        let mut parser = Parser {
+            tokens: tokenize(source)?,
+            pos: 0,
+            arities: &self.arities,
+        };
+        let expr = parser.parse_expr()?;
+        if parser.eof() {
+            Ok(expr)
+        } else {
+            Err(parser.error("unexpected token"))
+        }
+    }
+}
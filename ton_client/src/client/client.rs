@@ -20,10 +20,10 @@ use tokio::sync::{Mutex, mpsc, oneshot, RwLock};
 
 use super::{ParamsOfAppRequest, Error, AppRequestResult};
 use crate::error::ClientResult;
-use crate::abi::AbiConfig;
+use crate::abi::{AbiConfig, registry::ParsedAbi};
 use crate::boc::{BocConfig, cache::Bocs};
 use crate::crypto::CryptoConfig;
-use crate::crypto::boxes::SigningBox;
+use crate::crypto::boxes::{EncryptionBox, SigningBox};
 use crate::debot::DEngine;
 use crate::json_interface::request::Request;
 use crate::json_interface::interop::ResponseType;
@@ -37,6 +37,7 @@ use super::wasm_client_env::ClientEnv;
 #[derive(Default)]
 pub struct Boxes {
     pub(crate) signing_boxes: LockfreeMap<u32, Box<dyn SigningBox + Send + Sync>>,
+    pub(crate) encryption_boxes: LockfreeMap<u32, Box<dyn EncryptionBox + Send + Sync>>,
 }
 
 pub struct NetworkContext {
@@ -51,6 +52,7 @@ pub struct ClientContext {
     pub(crate) debots: LockfreeMap<u32, Mutex<DEngine>>,
     pub(crate) boxes: Boxes,
     pub(crate) bocs: Bocs,
+    pub(crate) abis: LockfreeMap<u32, Arc<ParsedAbi>>,
     pub(crate) blockchain_config: RwLock<Option<Arc<ton_executor::BlockchainConfig>>>,
 
     pub(crate) app_requests: Mutex<HashMap<u32, oneshot::Sender<AppRequestResult>>>,
@@ -94,7 +96,8 @@ Note that default values are used if parameters are omitted in config"#,
             env,
             debots: LockfreeMap::new(),
             boxes: Default::default(),
-            bocs: Bocs::new(config.boc.cache_max_size),
+            bocs: Bocs::new(&config.boc)?,
+            abis: LockfreeMap::new(),
             app_requests: Mutex::new(HashMap::new()),
             next_id: AtomicU32::new(1),
             config,
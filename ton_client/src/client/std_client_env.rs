@@ -137,6 +137,9 @@ impl ClientEnv {
                 return Ok(ws);
             }
         }
+        if let Some(emulator) = crate::net::find_emulator(url) {
+            return Ok(emulator.websocket_connect(self));
+        }
         let mut request = tokio_tungstenite::tungstenite::handshake::client::Request::builder()
             .method("GET")
             .uri(url);
@@ -193,6 +196,9 @@ impl ClientEnv {
                 return fetch.get_result(&self, url).await;
             }
         }
+        if let Some(emulator) = crate::net::find_emulator(url) {
+            return emulator.fetch(url, body).await;
+        }
         let method = Method::from_str(method.as_str())
             .map_err(|err| Error::http_request_create_error(err))?;
 
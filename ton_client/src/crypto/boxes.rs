@@ -1,7 +1,9 @@
 use super::KeyPair;
 use super::Error;
 use crate::client::ClientContext;
+use crate::encoding::hex_decode;
 use crate::error::ClientResult;
+use rand::RngCore;
 use std::sync::Arc;
 
 #[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default, PartialEq)]
@@ -147,3 +149,339 @@ pub fn remove_signing_box(
     context.boxes.signing_boxes.remove(&params.handle.0);
     Ok(())
 }
+
+// Encryption boxes
+
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default, PartialEq)]
+pub struct EncryptionBoxHandle(pub u32);
+
+impl From<u32> for EncryptionBoxHandle {
+    fn from(handle: u32) -> Self {
+        Self(handle)
+    }
+}
+
+/// Encryption box information
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default, PartialEq)]
+pub struct EncryptionBoxInfo {
+    /// Derivation path, for instance "m/44'/396'/0'/0/0"
+    pub hdpath: Option<String>,
+    /// Cryptographic algorithm, used by this encryption box
+    pub algorithm: Option<String>,
+    /// Options, depends on algorithm and specific encryption box implementation
+    pub options: Option<serde_json::Value>,
+    /// Public information, depends on algorithm
+    pub public: Option<serde_json::Value>,
+}
+
+#[async_trait::async_trait]
+pub trait EncryptionBox {
+    /// Gets encryption box information
+    async fn get_info(&self) -> ClientResult<EncryptionBoxInfo>;
+    /// Encrypts data
+    async fn encrypt(&self, data: &[u8]) -> ClientResult<Vec<u8>>;
+    /// Decrypts data
+    async fn decrypt(&self, data: &[u8]) -> ClientResult<Vec<u8>>;
+}
+
+const CHACHA20_NONCE_SIZE: usize = 12;
+const NACL_NONCE_SIZE: usize = 24;
+
+fn random_nonce(size: usize) -> Vec<u8> {
+    let mut nonce = vec![0u8; size];
+    rand::thread_rng().fill_bytes(&mut nonce);
+    nonce
+}
+
+/// Splits nonce prepended to the encrypted data by the built-in encryption boxes
+fn split_nonce(data: &[u8], size: usize) -> ClientResult<(&[u8], &[u8])> {
+    if data.len() < size {
+        return Err(Error::invalid_encrypted_data(format!(
+            "data is shorter than {} bytes nonce",
+            size
+        )));
+    }
+    Ok(data.split_at(size))
+}
+
+/// Built-in encryption boxes generate a random nonce for every `encrypt` call
+/// and prepend it to the encrypted data, so a nonce is never reused with the same key.
+pub(crate) struct ChaCha20EncryptionBox {
+    key: Vec<u8>,
+}
+
+#[async_trait::async_trait]
+impl EncryptionBox for ChaCha20EncryptionBox {
+    async fn get_info(&self) -> ClientResult<EncryptionBoxInfo> {
+        Ok(EncryptionBoxInfo {
+            algorithm: Some("ChaCha20".into()),
+            ..Default::default()
+        })
+    }
+
+    async fn encrypt(&self, data: &[u8]) -> ClientResult<Vec<u8>> {
+        let mut result = random_nonce(CHACHA20_NONCE_SIZE);
+        let mut encrypted = data.to_vec();
+        super::encryption::chacha20_apply(&self.key, &result, &mut encrypted)?;
+        result.append(&mut encrypted);
+        Ok(result)
+    }
+
+    async fn decrypt(&self, data: &[u8]) -> ClientResult<Vec<u8>> {
+        let (nonce, encrypted) = split_nonce(data, CHACHA20_NONCE_SIZE)?;
+        let mut data = encrypted.to_vec();
+        super::encryption::chacha20_apply(&self.key, nonce, &mut data)?;
+        Ok(data)
+    }
+}
+
+pub(crate) struct NaclEncryptionBox {
+    their_public: Vec<u8>,
+    secret: Vec<u8>,
+}
+
+#[async_trait::async_trait]
+impl EncryptionBox for NaclEncryptionBox {
+    async fn get_info(&self) -> ClientResult<EncryptionBoxInfo> {
+        Ok(EncryptionBoxInfo {
+            algorithm: Some("NaclBox".into()),
+            options: Some(json!({
+                "their_public": hex::encode(&self.their_public),
+            })),
+            public: Some(hex::encode(super::nacl::box_public_from_secret(&self.secret)?).into()),
+            ..Default::default()
+        })
+    }
+
+    async fn encrypt(&self, data: &[u8]) -> ClientResult<Vec<u8>> {
+        let mut result = random_nonce(NACL_NONCE_SIZE);
+        let mut encrypted =
+            super::nacl::box_encrypt(&data.to_vec(), &result, &self.their_public, &self.secret)?;
+        result.append(&mut encrypted);
+        Ok(result)
+    }
+
+    async fn decrypt(&self, data: &[u8]) -> ClientResult<Vec<u8>> {
+        let (nonce, encrypted) = split_nonce(data, NACL_NONCE_SIZE)?;
+        super::nacl::box_decrypt(
+            &encrypted.to_vec(),
+            &nonce.to_vec(),
+            &self.their_public,
+            &self.secret,
+        )
+    }
+}
+
+pub(crate) struct NaclSecretEncryptionBox {
+    key: Vec<u8>,
+}
+
+#[async_trait::async_trait]
+impl EncryptionBox for NaclSecretEncryptionBox {
+    async fn get_info(&self) -> ClientResult<EncryptionBoxInfo> {
+        Ok(EncryptionBoxInfo {
+            algorithm: Some("NaclSecretBox".into()),
+            ..Default::default()
+        })
+    }
+
+    async fn encrypt(&self, data: &[u8]) -> ClientResult<Vec<u8>> {
+        let mut result = random_nonce(NACL_NONCE_SIZE);
+        let mut encrypted = super::nacl::secret_box_encrypt(&data.to_vec(), &result, &self.key)?;
+        result.append(&mut encrypted);
+        Ok(result)
+    }
+
+    async fn decrypt(&self, data: &[u8]) -> ClientResult<Vec<u8>> {
+        let (nonce, encrypted) = split_nonce(data, NACL_NONCE_SIZE)?;
+        super::nacl::secret_box_decrypt(&encrypted.to_vec(), &nonce.to_vec(), &self.key)
+    }
+}
+
+/// Encryption algorithm of the built-in encryption box.
+///
+/// Encrypted data is prefixed with a random nonce generated for every `encrypt` call:
+/// 12 bytes for ChaCha20 and 24 bytes for NaCl boxes.
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, PartialEq)]
+#[serde(tag = "type")]
+pub enum EncryptionAlgorithm {
+    /// `chacha20` stream cipher
+    ChaCha20 {
+        /// 256-bit key. Must be encoded with `hex`.
+        key: String,
+    },
+    /// NaCl public key authenticated encryption
+    NaclBox {
+        /// Receiver's public key - unprefixed 0-padded to 64 symbols hex string
+        their_public: String,
+        /// Sender's private key - unprefixed 0-padded to 64 symbols hex string
+        secret: String,
+    },
+    /// NaCl secret key authenticated encryption
+    NaclSecretBox {
+        /// Secret key - unprefixed 0-padded to 64 symbols hex string
+        key: String,
+    },
+}
+
+impl Default for EncryptionAlgorithm {
+    fn default() -> Self {
+        EncryptionAlgorithm::ChaCha20 { key: String::new() }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default, PartialEq)]
+pub struct ParamsOfCreateEncryptionBox {
+    /// Encryption algorithm specifier including cipher parameters (key, nonce, etc)
+    pub algorithm: EncryptionAlgorithm,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default, PartialEq)]
+pub struct RegisteredEncryptionBox {
+    /// Handle of the encryption box
+    pub handle: EncryptionBoxHandle,
+}
+
+/// Creates encryption box with specified algorithm.
+///
+/// Keys are kept inside the box, so the caller needs only the box handle
+/// to encrypt and decrypt data.
+#[api_function]
+pub async fn create_encryption_box(
+    context: Arc<ClientContext>,
+    params: ParamsOfCreateEncryptionBox,
+) -> ClientResult<RegisteredEncryptionBox> {
+    // check parameters before the box is registered
+    match params.algorithm {
+        EncryptionAlgorithm::ChaCha20 { key } => {
+            let encryption_box = ChaCha20EncryptionBox { key: hex_decode(&key)? };
+            encryption_box.encrypt(&[]).await?;
+            register_encryption_box(context, encryption_box).await
+        }
+        EncryptionAlgorithm::NaclBox { their_public, secret } => {
+            let encryption_box = NaclEncryptionBox {
+                their_public: hex_decode(&their_public)?,
+                secret: hex_decode(&secret)?,
+            };
+            encryption_box.encrypt(&[]).await?;
+            register_encryption_box(context, encryption_box).await
+        }
+        EncryptionAlgorithm::NaclSecretBox { key } => {
+            let encryption_box = NaclSecretEncryptionBox { key: hex_decode(&key)? };
+            encryption_box.encrypt(&[]).await?;
+            register_encryption_box(context, encryption_box).await
+        }
+    }
+}
+
+/// Registers an application implemented encryption box.
+pub async fn register_encryption_box(
+    context: std::sync::Arc<ClientContext>,
+    encryption_box: impl EncryptionBox + Send + Sync + 'static,
+) -> ClientResult<RegisteredEncryptionBox> {
+    let id = context.get_next_id();
+    context.boxes.encryption_boxes.insert(id, Box::new(encryption_box));
+
+    Ok(RegisteredEncryptionBox {
+        handle: EncryptionBoxHandle(id),
+    })
+}
+
+/// Removes encryption box from SDK
+#[api_function]
+pub fn remove_encryption_box(
+    context: Arc<ClientContext>,
+    params: RegisteredEncryptionBox,
+) -> ClientResult<()> {
+    context.boxes.encryption_boxes.remove(&params.handle.0);
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default, PartialEq)]
+pub struct ParamsOfEncryptionBoxGetInfo {
+    /// Encryption box handle
+    pub encryption_box: EncryptionBoxHandle,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default, PartialEq)]
+pub struct ResultOfEncryptionBoxGetInfo {
+    /// Encryption box information
+    pub info: EncryptionBoxInfo,
+}
+
+/// Queries info from the given encryption box
+#[api_function]
+pub async fn encryption_box_get_info(
+    context: Arc<ClientContext>,
+    params: ParamsOfEncryptionBoxGetInfo,
+) -> ClientResult<ResultOfEncryptionBoxGetInfo> {
+    let encryption_box = context.boxes.encryption_boxes
+        .get(&params.encryption_box.0)
+        .ok_or(Error::encryption_box_not_registered(params.encryption_box.0))?;
+
+    Ok(ResultOfEncryptionBoxGetInfo {
+        info: encryption_box.1.get_info().await?,
+    })
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default, PartialEq)]
+pub struct ParamsOfEncryptionBoxEncrypt {
+    /// Encryption box handle
+    pub encryption_box: EncryptionBoxHandle,
+    /// Data to be encrypted, encoded in Base64
+    pub data: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default, PartialEq)]
+pub struct ResultOfEncryptionBoxEncrypt {
+    /// Encrypted data, encoded in Base64
+    pub data: String,
+}
+
+/// Encrypts data using given encryption box
+#[api_function]
+pub async fn encryption_box_encrypt(
+    context: Arc<ClientContext>,
+    params: ParamsOfEncryptionBoxEncrypt,
+) -> ClientResult<ResultOfEncryptionBoxEncrypt> {
+    let encryption_box = context.boxes.encryption_boxes
+        .get(&params.encryption_box.0)
+        .ok_or(Error::encryption_box_not_registered(params.encryption_box.0))?;
+
+    let data = crate::encoding::base64_decode(&params.data)?;
+
+    Ok(ResultOfEncryptionBoxEncrypt {
+        data: base64::encode(&encryption_box.1.encrypt(&data).await?),
+    })
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default, PartialEq)]
+pub struct ParamsOfEncryptionBoxDecrypt {
+    /// Encryption box handle
+    pub encryption_box: EncryptionBoxHandle,
+    /// Data to be decrypted, encoded in Base64
+    pub data: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default, PartialEq)]
+pub struct ResultOfEncryptionBoxDecrypt {
+    /// Decrypted data, encoded in Base64
+    pub data: String,
+}
+
+/// Decrypts data using given encryption box
+#[api_function]
+pub async fn encryption_box_decrypt(
+    context: Arc<ClientContext>,
+    params: ParamsOfEncryptionBoxDecrypt,
+) -> ClientResult<ResultOfEncryptionBoxDecrypt> {
+    let encryption_box = context.boxes.encryption_boxes
+        .get(&params.encryption_box.0)
+        .ok_or(Error::encryption_box_not_registered(params.encryption_box.0))?;
+
+    let data = crate::encoding::base64_decode(&params.data)?;
+
+    Ok(ResultOfEncryptionBoxDecrypt {
+        data: base64::encode(&encryption_box.1.decrypt(&data).await?),
+    })
+}
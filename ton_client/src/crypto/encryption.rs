@@ -13,6 +13,8 @@
  */
 
 use crate::client::ClientContext;
+use crate::crypto::internal::key256;
+use crate::crypto::Error;
 use crate::encoding::{base64_decode, hex_decode};
 use crate::error::ClientResult;
 use chacha20::cipher::{NewStreamCipher, SyncStreamCipher};
@@ -41,12 +43,19 @@ pub fn chacha20(
     _context: Arc<ClientContext>,
     params: ParamsOfChaCha20,
 ) -> ClientResult<ResultOfChaCha20> {
-    let key = hex_decode(&params.key)?;
-    let nonce = hex_decode(&params.nonce)?;
-    let mut cipher = chacha20::ChaCha20::new(Key::from_slice(&key), Nonce::from_slice(&nonce));
     let mut data = base64_decode(&params.data)?;
-    cipher.apply_keystream(&mut data);
+    chacha20_apply(&hex_decode(&params.key)?, &hex_decode(&params.nonce)?, &mut data)?;
     Ok(ResultOfChaCha20 {
         data: base64::encode(&data),
     })
 }
+
+pub(crate) fn chacha20_apply(key: &[u8], nonce: &[u8], data: &mut [u8]) -> ClientResult<()> {
+    let key = key256(key)?;
+    if nonce.len() != 12 {
+        return Err(Error::invalid_key_size(nonce.len(), 12));
+    }
+    let mut cipher = chacha20::ChaCha20::new(Key::from_slice(&key), Nonce::from_slice(nonce));
+    cipher.apply_keystream(data);
+    Ok(())
+}
@@ -23,6 +23,16 @@ pub enum ErrorCode {
     MnemonicFromEntropyFailed = 120,
     SigningBoxNotRegistered = 121,
     InvalidSignature = 122,
+    SigningBackendFailed = 123,
+    EncryptionBoxNotRegistered = 124,
+    InvalidKeystore = 125,
+    KeystoreInvalidPassword = 126,
+    KeystoreEntryNotFound = 127,
+    KeystoreEntryAlreadyExists = 128,
+    InvalidSecretSharing = 129,
+    InvalidSecretShare = 130,
+    InvalidHashSize = 131,
+    InvalidEncryptedData = 132,
 }
 
 pub struct Error;
@@ -168,4 +178,74 @@ impl Error {
             format!("Signing box is not registered. ID {}", id),
         )
     }
+
+    pub fn encryption_box_not_registered(id: u32) -> ClientError {
+        error(
+            ErrorCode::EncryptionBoxNotRegistered,
+            format!("Encryption box is not registered. ID {}", id),
+        )
+    }
+
+    pub fn signing_backend_failed<E: Display>(backend: &str, err: E) -> ClientError {
+        error(
+            ErrorCode::SigningBackendFailed,
+            format!("Signing backend `{}` failed: {}", backend, err),
+        )
+    }
+
+    pub fn invalid_keystore<E: Display>(err: E) -> ClientError {
+        error(
+            ErrorCode::InvalidKeystore,
+            format!("Invalid keystore: {}", err),
+        )
+    }
+
+    pub fn keystore_invalid_password() -> ClientError {
+        error(
+            ErrorCode::KeystoreInvalidPassword,
+            "Invalid keystore password".into(),
+        )
+    }
+
+    pub fn keystore_entry_not_found(name: &str) -> ClientError {
+        error(
+            ErrorCode::KeystoreEntryNotFound,
+            format!("Keystore entry `{}` not found", name),
+        )
+    }
+
+    pub fn keystore_entry_already_exists(name: &str) -> ClientError {
+        error(
+            ErrorCode::KeystoreEntryAlreadyExists,
+            format!("Keystore entry `{}` already exists", name),
+        )
+    }
+
+    pub fn invalid_secret_sharing<E: Display>(err: E) -> ClientError {
+        error(
+            ErrorCode::InvalidSecretSharing,
+            format!("Invalid secret sharing parameters: {}", err),
+        )
+    }
+
+    pub fn invalid_secret_share<E: Display>(err: E) -> ClientError {
+        error(
+            ErrorCode::InvalidSecretShare,
+            format!("Invalid secret share: {}", err),
+        )
+    }
+
+    pub fn invalid_hash_size(actual: usize, expected: usize) -> ClientError {
+        error(
+            ErrorCode::InvalidHashSize,
+            format!("Invalid hash size {}. Expected {}.", actual, expected),
+        )
+    }
+
+    pub fn invalid_encrypted_data<E: Display>(err: E) -> ClientError {
+        error(
+            ErrorCode::InvalidEncryptedData,
+            format!("Invalid encrypted data: {}", err),
+        )
+    }
 }
@@ -12,6 +12,7 @@
 */
 
 use crate::client::ClientContext;
+use crate::crypto::hdkey::Ripemd160;
 use crate::encoding::base64_decode;
 use crate::error::ClientResult;
 use sha2::Digest;
@@ -55,3 +56,33 @@ pub fn sha512(
         hash: hex::encode(hasher.result().to_vec()),
     })
 }
+
+/// Calculates Keccak-256 hash of the specified data.
+///
+/// This is the original Keccak used by Ethereum, not the standardized SHA3-256.
+#[api_function]
+pub fn keccak256(
+    _context: std::sync::Arc<ClientContext>,
+    params: ParamsOfHash,
+) -> ClientResult<ResultOfHash> {
+    let mut hasher = sha3::Keccak256::new();
+    hasher.input(base64_decode(&params.data)?);
+    Ok(ResultOfHash {
+        hash: hex::encode(hasher.result().to_vec()),
+    })
+}
+
+/// Calculates RIPEMD-160 hash of the specified data.
+#[api_function]
+pub fn ripemd160(
+    _context: std::sync::Arc<ClientContext>,
+    params: ParamsOfHash,
+) -> ClientResult<ResultOfHash> {
+    Ok(ResultOfHash {
+        hash: hex::encode(
+            Ripemd160::new()
+                .update(&base64_decode(&params.data)?)
+                .digest(),
+        ),
+    })
+}
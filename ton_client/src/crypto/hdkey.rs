@@ -14,7 +14,8 @@
 use crate::client::ClientContext;
 use crate::crypto;
 use crate::crypto::internal::{key256, key512, sha256, Key256, Key264};
-use crate::crypto::mnemonic::{check_phrase, mnemonics};
+use crate::crypto::keys::KeyPair;
+use crate::crypto::mnemonic::{check_phrase, ed25519_keys_from_secret_bytes, mnemonics};
 use crate::error::{ClientError, ClientResult};
 use base58::*;
 use byteorder::{BigEndian, ByteOrder, LittleEndian};
@@ -176,6 +177,133 @@ pub fn hdkey_derive_from_xprv_path(
     })
 }
 
+//---------------------------------------------------------------------- crypto.hdkey_xpub_from_xprv
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfHDKeyXPubFromXPrv {
+    /// Serialized extended private key
+    pub xprv: String,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfHDKeyXPubFromXPrv {
+    /// Serialized extended public key
+    pub xpub: String,
+}
+
+#[doc(summary = "Returns the extended public key of the extended private key")]
+/// Returns the BIP-0032 extended public key of the specified extended private key.
+///
+/// The extended public key allows to derive non-hardened child public keys
+/// without the private key (see `hdkey_derive_from_xpub`).
+#[api_function]
+pub fn hdkey_xpub_from_xprv(
+    _context: std::sync::Arc<ClientContext>,
+    params: ParamsOfHDKeyXPubFromXPrv,
+) -> ClientResult<ResultOfHDKeyXPubFromXPrv> {
+    let xprv = HDPrivateKey::from_serialized_string(&params.xprv)?;
+    Ok(ResultOfHDKeyXPubFromXPrv {
+        xpub: HDPublicKey::from_private(&xprv).serialize_to_string(),
+    })
+}
+
+//-------------------------------------------------------------------- crypto.hdkey_derive_from_xpub
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfHDKeyDeriveFromXPub {
+    /// Serialized extended public key
+    pub xpub: String,
+    /// Child index (see BIP-0032). Must be less than `2^31`,
+    /// hardened keys can not be derived from the public key.
+    pub child_index: u32,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfHDKeyDeriveFromXPub {
+    /// Serialized extended public key
+    pub xpub: String,
+}
+
+#[doc(summary = "Derives the non-hardened extended public key")]
+/// Returns extended public key derived from the specified extended public key and child index.
+///
+/// The result is the same as the extended public key of the non-hardened child derived
+/// with `hdkey_derive_from_xprv`, so the child keys can be derived on the hosts which
+/// don't hold the private keys.
+#[api_function]
+pub fn hdkey_derive_from_xpub(
+    _context: std::sync::Arc<ClientContext>,
+    params: ParamsOfHDKeyDeriveFromXPub,
+) -> ClientResult<ResultOfHDKeyDeriveFromXPub> {
+    let xpub = HDPublicKey::from_serialized_string(&params.xpub)?;
+    Ok(ResultOfHDKeyDeriveFromXPub {
+        xpub: xpub.derive(params.child_index)?.serialize_to_string(),
+    })
+}
+
+//-------------------------------------------------------------------- crypto.hdkey_public_from_xpub
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfHDKeyPublicFromXPub {
+    /// Serialized extended public key
+    pub xpub: String,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfHDKeyPublicFromXPub {
+    /// Compressed secp256k1 public key - 66 symbols hex string
+    pub public: String,
+}
+
+#[doc(summary = "Extracts the public key from the extended public key")]
+/// Extracts the compressed secp256k1 public key from the serialized extended public key.
+///
+/// Note that it is not the ed25519 public key returned by `hdkey_public_from_xprv`:
+/// ed25519 keys can not be derived from the public key only.
+#[api_function]
+pub fn hdkey_public_from_xpub(
+    _context: std::sync::Arc<ClientContext>,
+    params: ParamsOfHDKeyPublicFromXPub,
+) -> ClientResult<ResultOfHDKeyPublicFromXPub> {
+    Ok(ResultOfHDKeyPublicFromXPub {
+        public: hex::encode(&HDPublicKey::from_serialized_string(&params.xpub)?.key[..]),
+    })
+}
+
+//---------------------------------------------------------- crypto.hdkey_slip10_keys_from_mnemonic
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfHDKeySlip10KeysFromMnemonic {
+    /// String with seed phrase
+    pub phrase: String,
+    /// Dictionary identifier
+    pub dictionary: Option<u8>,
+    /// Mnemonic word count
+    pub word_count: Option<u8>,
+    /// Derivation path, for instance "m/44'/396'/0'/0'/0'".
+    /// All the path components must be hardened.
+    pub path: String,
+}
+
+#[doc(summary = "Derives the ed25519 key pair from the seed phrase using SLIP-0010")]
+/// Derives the ed25519 signing key pair from the seed phrase according to SLIP-0010.
+///
+/// Unlike `mnemonic_derive_sign_keys`, which derives secp256k1 private keys with BIP-0032
+/// and uses them as ed25519 secrets, this derivation is the same as the one used by
+/// other ed25519 wallets. SLIP-0010 supports hardened derivation only.
+#[api_function]
+pub fn hdkey_slip10_keys_from_mnemonic(
+    context: std::sync::Arc<ClientContext>,
+    params: ParamsOfHDKeySlip10KeysFromMnemonic,
+) -> ClientResult<KeyPair> {
+    check_phrase(
+        &*mnemonics(&context.config.crypto, params.dictionary, params.word_count)?,
+        &params.phrase,
+    )?;
+    let key = Slip10Ed25519Key::from_seed(&bip39_seed(&params.phrase)).derive_path(&params.path)?;
+    ed25519_keys_from_secret_bytes(&key.secret())
+}
+
 // Internals
 
 #[derive(Default, Clone)]
@@ -201,9 +329,7 @@ impl HDPrivateKey {
     }
 
     pub(crate) fn from_mnemonic(phrase: &String) -> ClientResult<HDPrivateKey> {
-        let salt = "mnemonic";
-        let mut seed = vec![0u8; 64];
-        pbkdf2::<Hmac<Sha512>>(phrase.as_bytes(), salt.as_bytes(), 2048, &mut seed);
+        let seed = bip39_seed(phrase);
         let mut hmac: Hmac<Sha512> = Hmac::new_varkey(b"Bitcoin seed").unwrap();
         hmac.input(&seed);
         let child_chain_with_key = key512(&hmac.result().code())?;
@@ -254,15 +380,10 @@ impl HDPrivateKey {
         compliant: bool,
     ) -> ClientResult<HDPrivateKey> {
         let mut child: HDPrivateKey = Default::default();
-        child.depth = self.depth + 1;
+        child.depth = next_depth(self.depth)?;
 
         let public = self.public();
-        let mut sha_hasher = sha2::Sha256::new();
-        sha_hasher.input(&public.as_ref());
-        let sha: Key256 = sha_hasher.result().into();
-        let fingerprint = Ripemd160::new().update(&sha).digest();
-
-        child.parent_fingerprint.copy_from_slice(&fingerprint[0..4]);
+        child.parent_fingerprint = key_fingerprint(&public);
 
         let child_index = if hardened {
             0x80000000 | child_index
@@ -372,7 +493,202 @@ impl HDPrivateKey {
     }
 }
 
-struct Ripemd160 {
+fn bip39_seed(phrase: &String) -> Vec<u8> {
+    let salt = "mnemonic";
+    let mut seed = vec![0u8; 64];
+    pbkdf2::<Hmac<Sha512>>(phrase.as_bytes(), salt.as_bytes(), 2048, &mut seed);
+    seed
+}
+
+fn key_fingerprint(public: &Key264) -> [u8; 4] {
+    let mut sha_hasher = sha2::Sha256::new();
+    sha_hasher.input(&public.as_ref());
+    let sha: Key256 = sha_hasher.result().into();
+    let digest = Ripemd160::new().update(&sha).digest();
+    let mut fingerprint = [0u8; 4];
+    fingerprint.copy_from_slice(&digest[0..4]);
+    fingerprint
+}
+
+fn next_depth(depth: u8) -> ClientResult<u8> {
+    depth.checked_add(1).ok_or_else(|| {
+        crypto::Error::bip32_invalid_derive_path(format!(
+            "can not derive a child of the key with the maximum depth {}",
+            depth
+        ))
+    })
+}
+
+#[derive(Clone)]
+pub(crate) struct HDPublicKey {
+    depth: u8,
+    parent_fingerprint: [u8; 4],
+    child_number: [u8; 4],
+    child_chain: Key256,
+    key: Key264,
+}
+
+static XPUB_VERSION: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+
+impl HDPublicKey {
+    pub(crate) fn from_private(xprv: &HDPrivateKey) -> HDPublicKey {
+        HDPublicKey {
+            depth: xprv.depth,
+            parent_fingerprint: xprv.parent_fingerprint,
+            child_number: xprv.child_number,
+            child_chain: xprv.child_chain,
+            key: xprv.public(),
+        }
+    }
+
+    pub(crate) fn derive(&self, child_index: u32) -> ClientResult<HDPublicKey> {
+        if child_index & 0x80000000 != 0 {
+            return Err(crypto::Error::bip32_invalid_derive_path(format!(
+                "hardened child {} can not be derived from the public key",
+                child_index & 0x7FFFFFFF
+            )));
+        }
+        let mut child_number = [0u8; 4];
+        BigEndian::write_u32(&mut child_number, child_index);
+
+        let mut hmac: Hmac<Sha512> = Hmac::new_varkey(&self.child_chain)
+            .map_err(|err| crypto::Error::bip32_invalid_key(err))?;
+        hmac.input(&self.key);
+        hmac.input(&child_number);
+        let result = hmac.result().code();
+        let (tweak_bytes, chain_code) = result.split_at(32);
+
+        let tweak = SecretKey::parse_slice(&tweak_bytes)
+            .map_err(|err| HDPrivateKey::map_secp_error(err))?;
+        let mut child_key = PublicKey::parse_compressed(&self.key)
+            .map_err(|err| HDPrivateKey::map_secp_error(err))?;
+        child_key
+            .tweak_add_assign(&tweak)
+            .map_err(|err| HDPrivateKey::map_secp_error(err))?;
+
+        let mut child_chain: Key256 = Default::default();
+        child_chain.copy_from_slice(&chain_code);
+        Ok(HDPublicKey {
+            depth: next_depth(self.depth)?,
+            parent_fingerprint: key_fingerprint(&self.key),
+            child_number,
+            child_chain,
+            key: child_key.serialize_compressed(),
+        })
+    }
+
+    // Serialization
+
+    fn from_serialized(bytes: &[u8]) -> ClientResult<HDPublicKey> {
+        if bytes.len() != 82 || bytes[0..4] != XPUB_VERSION {
+            return Err(crypto::Error::bip32_invalid_key(bytes.to_base58()));
+        }
+        if sha256(&sha256(&bytes[0..78]))[0..4] != bytes[78..82] {
+            return Err(crypto::Error::bip32_invalid_key(bytes.to_base58()));
+        }
+        let mut xpub = HDPublicKey {
+            depth: bytes[4],
+            parent_fingerprint: Default::default(),
+            child_number: Default::default(),
+            child_chain: Default::default(),
+            key: [0u8; 33],
+        };
+        xpub.parent_fingerprint.copy_from_slice(&bytes[5..9]);
+        xpub.child_number.copy_from_slice(&bytes[9..13]);
+        xpub.child_chain.copy_from_slice(&bytes[13..45]);
+        xpub.key.copy_from_slice(&bytes[45..78]);
+        PublicKey::parse_compressed(&xpub.key).map_err(|err| HDPrivateKey::map_secp_error(err))?;
+        Ok(xpub)
+    }
+
+    fn serialize(&self) -> Vec<u8> {
+        let mut bytes: Vec<u8> = Vec::new();
+        bytes.extend(&XPUB_VERSION);
+        bytes.push(self.depth);
+        bytes.extend(&self.parent_fingerprint);
+        bytes.extend(&self.child_number);
+        bytes.extend(&self.child_chain);
+        bytes.extend(&self.key[..]);
+        bytes.extend(&sha256(&sha256(&bytes))[0..4]);
+        bytes
+    }
+
+    fn from_serialized_string(string: &String) -> ClientResult<HDPublicKey> {
+        Self::from_serialized(
+            &string
+                .from_base58()
+                .map_err(|_| crypto::Error::bip32_invalid_key(string))?,
+        )
+    }
+
+    fn serialize_to_string(&self) -> String {
+        self.serialize().to_base58()
+    }
+}
+
+/// SLIP-0010 ed25519 extended private key
+pub(crate) struct Slip10Ed25519Key {
+    child_chain: Key256,
+    key: Key256,
+}
+
+impl Slip10Ed25519Key {
+    fn from_hmac(hmac: Hmac<Sha512>) -> Slip10Ed25519Key {
+        let result = hmac.result().code();
+        let mut key = Slip10Ed25519Key {
+            child_chain: Default::default(),
+            key: Default::default(),
+        };
+        key.key.copy_from_slice(&result[..32]);
+        key.child_chain.copy_from_slice(&result[32..]);
+        key
+    }
+
+    pub(crate) fn from_seed(seed: &[u8]) -> Slip10Ed25519Key {
+        let mut hmac: Hmac<Sha512> = Hmac::new_varkey(b"ed25519 seed").unwrap();
+        hmac.input(seed);
+        Self::from_hmac(hmac)
+    }
+
+    pub(crate) fn derive_hardened(&self, child_index: u32) -> Slip10Ed25519Key {
+        let mut child_number = [0u8; 4];
+        BigEndian::write_u32(&mut child_number, 0x80000000 | child_index);
+        let mut hmac: Hmac<Sha512> = Hmac::new_varkey(&self.child_chain).unwrap();
+        hmac.input(&[0]);
+        hmac.input(&self.key);
+        hmac.input(&child_number);
+        Self::from_hmac(hmac)
+    }
+
+    pub(crate) fn derive_path(&self, path: &String) -> ClientResult<Slip10Ed25519Key> {
+        let mut child = Slip10Ed25519Key {
+            child_chain: self.child_chain,
+            key: self.key,
+        };
+        for step in path.split("/") {
+            if step == "m" {
+                continue;
+            }
+            if !step.ends_with('\'') {
+                return Err(crypto::Error::bip32_invalid_derive_path(path));
+            }
+            let index: u32 = step[0..(step.len() - 1)]
+                .parse()
+                .map_err(|_| crypto::Error::bip32_invalid_derive_path(path))?;
+            if index & 0x80000000 != 0 {
+                return Err(crypto::Error::bip32_invalid_derive_path(path));
+            }
+            child = child.derive_hardened(index);
+        }
+        Ok(child)
+    }
+
+    pub(crate) fn secret(&self) -> Key256 {
+        self.key
+    }
+}
+
+pub(crate) struct Ripemd160 {
     pending: Vec<u8>,
     pending_total: usize,
     pad_length: usize,
@@ -383,7 +699,7 @@ struct Ripemd160 {
 }
 
 impl Ripemd160 {
-    fn new() -> Ripemd160 {
+    pub(crate) fn new() -> Ripemd160 {
         Ripemd160 {
             h: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0],
             pending: Vec::new(),
@@ -413,12 +729,14 @@ impl Ripemd160 {
         res
     }
 
-    fn update(&mut self, msg: &[u8]) -> &mut Self {
+    pub(crate) fn update(&mut self, msg: &[u8]) -> &mut Self {
         self.pending.extend_from_slice(msg);
         self.pending_total += msg.len();
         if self.pending.len() >= self._delta8 {
-            let msg = self.pending.split_off(self.pending.len() % self._delta8);
-            let msg = Ripemd160::join32(&msg);
+            let tail = self
+                .pending
+                .split_off(self.pending.len() - self.pending.len() % self._delta8);
+            let msg = Ripemd160::join32(&std::mem::replace(&mut self.pending, tail));
             let mut i = 0;
             while i < msg.len() {
                 self._update(&msg[i..(i + self._delta32)]);
@@ -428,7 +746,7 @@ impl Ripemd160 {
         self
     }
 
-    fn digest(&mut self) -> Vec<u8> {
+    pub(crate) fn digest(&mut self) -> Vec<u8> {
         self.update(&self._pad());
         assert_eq!(self.pending.len(), 0);
         self._digest()
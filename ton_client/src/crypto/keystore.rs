@@ -0,0 +1,617 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+use crate::client::ClientContext;
+use crate::crypto;
+use crate::crypto::boxes::{get_signing_box, RegisteredSigningBox};
+use crate::crypto::encscrypt::{scrypt, ParamsOfScrypt};
+use crate::crypto::hdkey::{
+    hdkey_derive_from_xprv_path, hdkey_public_from_xprv, hdkey_secret_from_xprv,
+    ParamsOfHDKeyDeriveFromXPrvPath, ParamsOfHDKeyPublicFromXPrv, ParamsOfHDKeySecretFromXPrv,
+};
+use crate::crypto::keys::KeyPair;
+use crate::crypto::mnemonic::{mnemonic_derive_sign_keys, ParamsOfMnemonicDeriveSignKeys};
+use crate::crypto::nacl::{secret_box_decrypt, secret_box_encrypt};
+use crate::encoding::{base64_decode, hex_decode};
+use crate::error::ClientResult;
+use rand::RngCore;
+use std::sync::Arc;
+
+const KEYSTORE_VERSION: u32 = 1;
+const KEYSTORE_KDF: &str = "scrypt";
+const KEYSTORE_CIPHER: &str = "nacl_secret_box";
+const KEYSTORE_KEY_LEN: u32 = 32;
+const KEYSTORE_SALT_LEN: usize = 32;
+const KEYSTORE_NONCE_LEN: usize = 24;
+const KEYSTORE_PASSWORD_CHECK: &[u8] = b"TON SDK keystore";
+const KEYSTORE_MAX_LOG_N: u8 = 20;
+const KEYSTORE_MAX_R: u32 = 32;
+const KEYSTORE_MAX_P: u32 = 16;
+/// `scrypt` allocates `128 * r * N` bytes
+const KEYSTORE_MAX_MEMORY: u64 = 256 * 1024 * 1024;
+const KEYSTORE_MAX_P_BY_R: u32 = 64;
+
+#[derive(Serialize, Deserialize, ApiType, Clone, Debug, PartialEq)]
+pub struct KeystoreKdfParams {
+    /// The log2 of the `scrypt` parameter `N`. Must not exceed 20.
+    /// `128 * r * 2^log_n` (the memory used by `scrypt`) must not exceed 256 MiB.
+    pub log_n: u8,
+    /// The `scrypt` parameter `r`. Must not exceed 32.
+    pub r: u32,
+    /// The `scrypt` parameter `p`. Must not exceed 16.
+    /// `p * r` must not exceed 64.
+    pub p: u32,
+}
+
+impl Default for KeystoreKdfParams {
+    fn default() -> Self {
+        Self {
+            log_n: 15,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ApiType, Clone, Copy, Debug, PartialEq)]
+pub enum KeystoreEntryType {
+    KeyPair,
+    Mnemonic,
+    XPrv,
+}
+
+impl Default for KeystoreEntryType {
+    fn default() -> Self {
+        KeystoreEntryType::KeyPair
+    }
+}
+
+#[derive(Serialize, Deserialize, ApiType, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum KeystoreSecret {
+    /// Ed25519 key pair.
+    KeyPair { keys: KeyPair },
+    /// Seed phrase. The signing key pair is derived from the phrase
+    /// with the specified path.
+    ///
+    /// Omitted derivation parameters are taken from the client crypto config
+    /// on import, so the stored entry does not depend on the config later.
+    Mnemonic {
+        phrase: String,
+        path: Option<String>,
+        dictionary: Option<u8>,
+        word_count: Option<u8>,
+    },
+    /// Serialized extended private key. If `path` is specified, the signing
+    /// key pair is derived from the child key with this path.
+    XPrv { xprv: String, path: Option<String> },
+}
+
+impl Default for KeystoreSecret {
+    fn default() -> Self {
+        KeystoreSecret::KeyPair {
+            keys: Default::default(),
+        }
+    }
+}
+
+impl KeystoreSecret {
+    fn entry_type(&self) -> KeystoreEntryType {
+        match self {
+            KeystoreSecret::KeyPair { .. } => KeystoreEntryType::KeyPair,
+            KeystoreSecret::Mnemonic { .. } => KeystoreEntryType::Mnemonic,
+            KeystoreSecret::XPrv { .. } => KeystoreEntryType::XPrv,
+        }
+    }
+
+    fn resolve_defaults(self, context: &ClientContext) -> Self {
+        match self {
+            KeystoreSecret::Mnemonic {
+                phrase,
+                path,
+                dictionary,
+                word_count,
+            } => KeystoreSecret::Mnemonic {
+                phrase,
+                path: path.or_else(|| Some(context.config.crypto.hdkey_derivation_path.clone())),
+                dictionary: dictionary.or(Some(context.config.crypto.mnemonic_dictionary)),
+                word_count: word_count.or(Some(context.config.crypto.mnemonic_word_count)),
+            },
+            secret => secret,
+        }
+    }
+
+    fn signing_keys(&self, context: &Arc<ClientContext>) -> ClientResult<KeyPair> {
+        match self {
+            KeystoreSecret::KeyPair { keys } => {
+                let decoded = keys.decode()?;
+                let public = ed25519_dalek::PublicKey::from(&decoded.secret);
+                if public.as_bytes() != decoded.public.as_bytes() {
+                    return Err(crypto::Error::invalid_public_key(
+                        "public key does not match the secret key",
+                        &keys.public,
+                    ));
+                }
+                Ok(keys.clone())
+            }
+            KeystoreSecret::Mnemonic {
+                phrase,
+                path,
+                dictionary,
+                word_count,
+            } => mnemonic_derive_sign_keys(
+                context.clone(),
+                ParamsOfMnemonicDeriveSignKeys {
+                    phrase: phrase.clone(),
+                    path: path.clone(),
+                    dictionary: *dictionary,
+                    word_count: *word_count,
+                },
+            ),
+            KeystoreSecret::XPrv { xprv, path } => {
+                let xprv = match path {
+                    Some(path) => {
+                        hdkey_derive_from_xprv_path(
+                            context.clone(),
+                            ParamsOfHDKeyDeriveFromXPrvPath {
+                                xprv: xprv.clone(),
+                                path: path.clone(),
+                            },
+                        )?
+                        .xprv
+                    }
+                    None => xprv.clone(),
+                };
+                Ok(KeyPair::new(
+                    hdkey_public_from_xprv(
+                        context.clone(),
+                        ParamsOfHDKeyPublicFromXPrv { xprv: xprv.clone() },
+                    )?
+                    .public,
+                    hdkey_secret_from_xprv(context.clone(), ParamsOfHDKeySecretFromXPrv { xprv })?
+                        .secret,
+                ))
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, Clone, Debug, PartialEq)]
+pub struct KeystoreEntryInfo {
+    /// Entry name, unique within the keystore.
+    pub name: String,
+    /// Type of the stored secret.
+    pub entry_type: KeystoreEntryType,
+    /// Public key of the signing key pair. Encoded with `hex`.
+    pub public: String,
+}
+
+// Keystore file format
+
+#[derive(Serialize, Deserialize, Clone)]
+struct EncryptedData {
+    /// Encoded with `hex`.
+    nonce: String,
+    /// Encoded with `base64`.
+    ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct KeystoreKdf {
+    name: String,
+    /// Encoded with `hex`.
+    salt: String,
+    log_n: u8,
+    r: u32,
+    p: u32,
+    dk_len: u32,
+}
+
+impl KeystoreKdf {
+    fn check_limits(&self) -> ClientResult<()> {
+        if self.log_n == 0
+            || self.log_n > KEYSTORE_MAX_LOG_N
+            || self.r == 0
+            || self.r > KEYSTORE_MAX_R
+            || self.p == 0
+            || self.p > KEYSTORE_MAX_P
+            || self.p * self.r > KEYSTORE_MAX_P_BY_R
+            || (128 * u64::from(self.r)) << self.log_n > KEYSTORE_MAX_MEMORY
+            || self.dk_len != KEYSTORE_KEY_LEN
+        {
+            return Err(crypto::Error::invalid_keystore(format!(
+                "KDF parameters are out of range: log_n = {}, r = {}, p = {}, dk_len = {}",
+                self.log_n, self.r, self.p, self.dk_len
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Entry info is stored in plain text so that entries can be listed without
+/// the password, and is also encrypted along with the secret, so any change
+/// of the plain text copy is detected on decryption.
+#[derive(Serialize, Deserialize, Clone)]
+struct KeystoreEntry {
+    #[serde(flatten)]
+    info: KeystoreEntryInfo,
+    #[serde(flatten)]
+    encrypted: EncryptedData,
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeystoreEntryPayload {
+    info: KeystoreEntryInfo,
+    secret: KeystoreSecret,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct Keystore {
+    version: u32,
+    kdf: KeystoreKdf,
+    cipher: String,
+    /// Known data encrypted with the keystore key, used to check the password.
+    check: EncryptedData,
+    entries: Vec<KeystoreEntry>,
+}
+
+struct KeystoreKey(Vec<u8>);
+
+impl KeystoreKey {
+    fn encrypt(&self, data: &[u8]) -> ClientResult<EncryptedData> {
+        let mut nonce = vec![0u8; KEYSTORE_NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let ciphertext = secret_box_encrypt(&data.to_vec(), &nonce, &self.0)?;
+        Ok(EncryptedData {
+            nonce: hex::encode(&nonce),
+            ciphertext: base64::encode(&ciphertext),
+        })
+    }
+
+    fn decrypt(&self, data: &EncryptedData) -> ClientResult<Vec<u8>> {
+        secret_box_decrypt(
+            &base64_decode(&data.ciphertext)?,
+            &hex_decode(&data.nonce)?,
+            &self.0,
+        )
+    }
+}
+
+fn derive_key(
+    context: &Arc<ClientContext>,
+    password: &str,
+    kdf: &KeystoreKdf,
+) -> ClientResult<KeystoreKey> {
+    let key = scrypt(
+        context.clone(),
+        ParamsOfScrypt {
+            password: password.to_string(),
+            salt: base64::encode(&hex_decode(&kdf.salt)?),
+            log_n: kdf.log_n,
+            r: kdf.r,
+            p: kdf.p,
+            dk_len: kdf.dk_len,
+        },
+    )?
+    .key;
+    Ok(KeystoreKey(hex_decode(&key)?))
+}
+
+impl Keystore {
+    fn parse(keystore: &str) -> ClientResult<Self> {
+        let keystore: Self =
+            serde_json::from_str(keystore).map_err(|err| crypto::Error::invalid_keystore(err))?;
+        if keystore.version != KEYSTORE_VERSION {
+            return Err(crypto::Error::invalid_keystore(format!(
+                "unsupported version {}",
+                keystore.version
+            )));
+        }
+        if keystore.kdf.name != KEYSTORE_KDF {
+            return Err(crypto::Error::invalid_keystore(format!(
+                "unsupported KDF `{}`",
+                keystore.kdf.name
+            )));
+        }
+        if keystore.cipher != KEYSTORE_CIPHER {
+            return Err(crypto::Error::invalid_keystore(format!(
+                "unsupported cipher `{}`",
+                keystore.cipher
+            )));
+        }
+        keystore.kdf.check_limits()?;
+        Ok(keystore)
+    }
+
+    fn serialize(&self) -> ClientResult<String> {
+        serde_json::to_string(self).map_err(|err| crypto::Error::invalid_keystore(err))
+    }
+
+    fn unlock(&self, context: &Arc<ClientContext>, password: &str) -> ClientResult<KeystoreKey> {
+        let key = derive_key(context, password, &self.kdf)?;
+        match key.decrypt(&self.check) {
+            Ok(check) if check == KEYSTORE_PASSWORD_CHECK => Ok(key),
+            _ => Err(crypto::Error::keystore_invalid_password()),
+        }
+    }
+
+    fn find(&self, name: &str) -> ClientResult<usize> {
+        self.entries
+            .iter()
+            .position(|entry| entry.info.name == name)
+            .ok_or_else(|| crypto::Error::keystore_entry_not_found(name))
+    }
+
+    fn decrypt_entry(
+        &self,
+        key: &KeystoreKey,
+        entry: &KeystoreEntry,
+    ) -> ClientResult<KeystoreSecret> {
+        let decrypted = key.decrypt(&entry.encrypted)?;
+        let payload: KeystoreEntryPayload = serde_json::from_slice(&decrypted)
+            .map_err(|err| crypto::Error::invalid_keystore(err))?;
+        if payload.info != entry.info {
+            return Err(crypto::Error::invalid_keystore(format!(
+                "entry `{}` does not match its encrypted data",
+                entry.info.name
+            )));
+        }
+        Ok(payload.secret)
+    }
+
+    fn decrypt_secret(&self, key: &KeystoreKey, name: &str) -> ClientResult<KeystoreSecret> {
+        self.decrypt_entry(key, &self.entries[self.find(name)?])
+    }
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfKeystore {
+    /// Serialized keystore.
+    pub keystore: String,
+}
+
+//-------------------------------------------------------------------------- crypto.keystore_create
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfKeystoreCreate {
+    /// Keystore password. Must be encoded with `base64`.
+    pub password: String,
+    /// Key derivation parameters.
+    /// If not specified, `log_n = 15`, `r = 8` and `p = 1` are used.
+    /// Parameters above the limits are rejected, since the keystore
+    /// could not be unlocked with them in reasonable time and memory.
+    pub kdf: Option<KeystoreKdfParams>,
+}
+
+#[doc(summary = "Creates an empty keystore")]
+/// Creates an empty keystore protected with the password.
+///
+/// The keystore is a versioned JSON document. The encryption key is derived
+/// from the password with `scrypt` using a random salt, and every secret is
+/// encrypted with `nacl_secret_box` using its own random nonce.
+/// Entry names, types and public keys are stored both unencrypted, to list
+/// the entries without the password, and inside the encrypted data, so that
+/// they can't be altered unnoticed.
+#[api_function]
+pub fn keystore_create(
+    context: Arc<ClientContext>,
+    params: ParamsOfKeystoreCreate,
+) -> ClientResult<ResultOfKeystore> {
+    let kdf_params = params.kdf.unwrap_or_default();
+    let mut salt = vec![0u8; KEYSTORE_SALT_LEN];
+    rand::thread_rng().fill_bytes(&mut salt);
+    let kdf = KeystoreKdf {
+        name: KEYSTORE_KDF.to_string(),
+        salt: hex::encode(&salt),
+        log_n: kdf_params.log_n,
+        r: kdf_params.r,
+        p: kdf_params.p,
+        dk_len: KEYSTORE_KEY_LEN,
+    };
+    kdf.check_limits()?;
+    let key = derive_key(&context, &params.password, &kdf)?;
+    let keystore = Keystore {
+        version: KEYSTORE_VERSION,
+        kdf,
+        cipher: KEYSTORE_CIPHER.to_string(),
+        check: key.encrypt(KEYSTORE_PASSWORD_CHECK)?,
+        entries: Vec::new(),
+    };
+    Ok(ResultOfKeystore {
+        keystore: keystore.serialize()?,
+    })
+}
+
+//-------------------------------------------------------------------------- crypto.keystore_import
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfKeystoreImport {
+    /// Serialized keystore.
+    pub keystore: String,
+    /// Keystore password. Must be encoded with `base64`.
+    pub password: String,
+    /// Name of the new entry. Must be unique within the keystore.
+    pub name: String,
+    /// Secret to store.
+    pub secret: KeystoreSecret,
+}
+
+/// Adds an encrypted secret to the keystore and returns the updated keystore.
+#[api_function]
+pub fn keystore_import(
+    context: Arc<ClientContext>,
+    params: ParamsOfKeystoreImport,
+) -> ClientResult<ResultOfKeystore> {
+    let mut keystore = Keystore::parse(&params.keystore)?;
+    if keystore.find(&params.name).is_ok() {
+        return Err(crypto::Error::keystore_entry_already_exists(&params.name));
+    }
+    let key = keystore.unlock(&context, &params.password)?;
+    let secret = params.secret.resolve_defaults(&context);
+    let keys = secret.signing_keys(&context)?;
+    let payload = KeystoreEntryPayload {
+        info: KeystoreEntryInfo {
+            name: params.name,
+            entry_type: secret.entry_type(),
+            public: keys.public,
+        },
+        secret,
+    };
+    let serialized =
+        serde_json::to_vec(&payload).map_err(|err| crypto::Error::invalid_keystore(err))?;
+    keystore.entries.push(KeystoreEntry {
+        encrypted: key.encrypt(&serialized)?,
+        info: payload.info,
+    });
+    Ok(ResultOfKeystore {
+        keystore: keystore.serialize()?,
+    })
+}
+
+//-------------------------------------------------------------------------- crypto.keystore_export
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfKeystoreEntry {
+    /// Serialized keystore.
+    pub keystore: String,
+    /// Keystore password. Must be encoded with `base64`.
+    pub password: String,
+    /// Entry name.
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfKeystoreExport {
+    /// Decrypted secret.
+    pub secret: KeystoreSecret,
+}
+
+/// Decrypts and returns the secret stored in the keystore entry.
+#[api_function]
+pub fn keystore_export(
+    context: Arc<ClientContext>,
+    params: ParamsOfKeystoreEntry,
+) -> ClientResult<ResultOfKeystoreExport> {
+    let keystore = Keystore::parse(&params.keystore)?;
+    let key = keystore.unlock(&context, &params.password)?;
+    Ok(ResultOfKeystoreExport {
+        secret: keystore.decrypt_secret(&key, &params.name)?,
+    })
+}
+
+/// Runs `scrypt` key derivation on the blocking thread pool
+/// so it doesn't stall the async executor
+#[cfg(not(feature = "wasm"))]
+async fn blocking<R, F>(f: F) -> ClientResult<R>
+where
+    R: Send + 'static,
+    F: FnOnce() -> ClientResult<R> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|err| crate::client::Error::internal_error(&err.to_string()))?
+}
+
+#[cfg(feature = "wasm")]
+async fn blocking<R, F>(f: F) -> ClientResult<R>
+where
+    F: FnOnce() -> ClientResult<R>,
+{
+    f()
+}
+
+//-------------------------------------------------------------------------- crypto.keystore_unlock
+
+#[doc(summary = "Creates a signing box for the keystore entry")]
+/// Decrypts the keystore entry, derives the signing key pair from the secret
+/// and registers a signing box with it. The key pair is never returned to the caller.
+///
+/// Remove the signing box with `remove_signing_box` when it is no longer needed.
+#[api_function]
+pub async fn keystore_unlock(
+    context: Arc<ClientContext>,
+    params: ParamsOfKeystoreEntry,
+) -> ClientResult<RegisteredSigningBox> {
+    let keys = blocking({
+        let context = context.clone();
+        move || {
+            let keystore = Keystore::parse(&params.keystore)?;
+            let key = keystore.unlock(&context, &params.password)?;
+            keystore
+                .decrypt_secret(&key, &params.name)?
+                .signing_keys(&context)
+        }
+    })
+    .await?;
+    get_signing_box(context, keys).await
+}
+
+//---------------------------------------------------------------------------- crypto.keystore_list
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfKeystoreList {
+    /// Serialized keystore.
+    pub keystore: String,
+    /// Keystore password. Must be encoded with `base64`.
+    /// If specified, every entry is decrypted to verify its info.
+    pub password: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfKeystoreList {
+    /// Keystore entries in the order they were imported.
+    pub entries: Vec<KeystoreEntryInfo>,
+}
+
+/// Returns names, types and public keys of the keystore entries.
+///
+/// Password is not required, but without it the returned info is not
+/// authenticated. Pass the password to make sure the info has not been altered.
+#[api_function]
+pub fn keystore_list(
+    context: Arc<ClientContext>,
+    params: ParamsOfKeystoreList,
+) -> ClientResult<ResultOfKeystoreList> {
+    let keystore = Keystore::parse(&params.keystore)?;
+    if let Some(password) = &params.password {
+        let key = keystore.unlock(&context, password)?;
+        for entry in &keystore.entries {
+            keystore.decrypt_entry(&key, entry)?;
+        }
+    }
+    Ok(ResultOfKeystoreList {
+        entries: keystore
+            .entries
+            .into_iter()
+            .map(|entry| entry.info)
+            .collect(),
+    })
+}
+
+//-------------------------------------------------------------------------- crypto.keystore_delete
+
+/// Removes the entry from the keystore and returns the updated keystore.
+#[api_function]
+pub fn keystore_delete(
+    context: Arc<ClientContext>,
+    params: ParamsOfKeystoreEntry,
+) -> ClientResult<ResultOfKeystore> {
+    let mut keystore = Keystore::parse(&params.keystore)?;
+    let key = keystore.unlock(&context, &params.password)?;
+    let index = keystore.find(&params.name)?;
+    keystore.decrypt_entry(&key, &keystore.entries[index])?;
+    keystore.entries.remove(index);
+    Ok(ResultOfKeystore {
+        keystore: keystore.serialize()?,
+    })
+}
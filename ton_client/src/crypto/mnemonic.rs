@@ -258,7 +258,7 @@ impl Bip39Mnemonic {
     }
 }
 
-fn ed25519_keys_from_secret_bytes(bytes: &[u8]) -> ClientResult<KeyPair> {
+pub(super) fn ed25519_keys_from_secret_bytes(bytes: &[u8]) -> ClientResult<KeyPair> {
     let secret = SecretKey::from_bytes(bytes)
         .map_err(|_| crypto::Error::bip32_invalid_key(&hex::encode(bytes)))?;
     let public = PublicKey::from(&secret);
@@ -18,9 +18,13 @@ pub(crate) mod hash;
 pub(crate) mod hdkey;
 pub(crate) mod internal;
 pub(crate) mod keys;
+pub(crate) mod keystore;
 pub(crate) mod math;
 pub(crate) mod mnemonic;
 pub(crate) mod nacl;
+pub(crate) mod secp256k1_keys;
+pub(crate) mod secret_sharing;
+pub(crate) mod signers;
 
 pub use errors::{Error, ErrorCode};
 pub(crate) mod encryption;
@@ -28,24 +32,40 @@ pub(crate) mod encryption;
 mod tests;
 
 pub use crate::crypto::boxes::{
-    get_signing_box, register_signing_box, remove_signing_box, signing_box_get_public_key,
-    signing_box_sign, ParamsOfSigningBoxSign, RegisteredSigningBox, ResultOfSigningBoxGetPublicKey,
-    ResultOfSigningBoxSign, SigningBox, SigningBoxHandle,
+    create_encryption_box, encryption_box_decrypt, encryption_box_encrypt,
+    encryption_box_get_info, get_signing_box, register_encryption_box, register_signing_box,
+    remove_encryption_box, remove_signing_box, signing_box_get_public_key, signing_box_sign,
+    EncryptionAlgorithm, EncryptionBox, EncryptionBoxHandle, EncryptionBoxInfo,
+    ParamsOfCreateEncryptionBox, ParamsOfEncryptionBoxDecrypt, ParamsOfEncryptionBoxEncrypt,
+    ParamsOfEncryptionBoxGetInfo, ParamsOfSigningBoxSign, RegisteredEncryptionBox,
+    RegisteredSigningBox, ResultOfEncryptionBoxDecrypt, ResultOfEncryptionBoxEncrypt,
+    ResultOfEncryptionBoxGetInfo, ResultOfSigningBoxGetPublicKey, ResultOfSigningBoxSign,
+    SigningBox, SigningBoxHandle,
 };
 pub use crate::crypto::encscrypt::{scrypt, ParamsOfScrypt, ResultOfScrypt};
-pub use crate::crypto::hash::{sha256, sha512, ParamsOfHash, ResultOfHash};
+pub use crate::crypto::hash::{keccak256, ripemd160, sha256, sha512, ParamsOfHash, ResultOfHash};
 pub use crate::crypto::hdkey::{
-    hdkey_derive_from_xprv, hdkey_derive_from_xprv_path, hdkey_public_from_xprv,
-    hdkey_secret_from_xprv, hdkey_xprv_from_mnemonic, ParamsOfHDKeyDeriveFromXPrv,
-    ParamsOfHDKeyDeriveFromXPrvPath, ParamsOfHDKeyPublicFromXPrv, ParamsOfHDKeySecretFromXPrv,
-    ParamsOfHDKeyXPrvFromMnemonic, ResultOfHDKeyDeriveFromXPrv, ResultOfHDKeyDeriveFromXPrvPath,
-    ResultOfHDKeyPublicFromXPrv, ResultOfHDKeySecretFromXPrv, ResultOfHDKeyXPrvFromMnemonic,
+    hdkey_derive_from_xprv, hdkey_derive_from_xprv_path, hdkey_derive_from_xpub,
+    hdkey_public_from_xprv, hdkey_public_from_xpub, hdkey_secret_from_xprv,
+    hdkey_slip10_keys_from_mnemonic, hdkey_xprv_from_mnemonic, hdkey_xpub_from_xprv,
+    ParamsOfHDKeyDeriveFromXPrv, ParamsOfHDKeyDeriveFromXPrvPath, ParamsOfHDKeyDeriveFromXPub,
+    ParamsOfHDKeyPublicFromXPrv, ParamsOfHDKeyPublicFromXPub, ParamsOfHDKeySecretFromXPrv,
+    ParamsOfHDKeySlip10KeysFromMnemonic, ParamsOfHDKeyXPrvFromMnemonic, ParamsOfHDKeyXPubFromXPrv,
+    ResultOfHDKeyDeriveFromXPrv, ResultOfHDKeyDeriveFromXPrvPath, ResultOfHDKeyDeriveFromXPub,
+    ResultOfHDKeyPublicFromXPrv, ResultOfHDKeyPublicFromXPub, ResultOfHDKeySecretFromXPrv,
+    ResultOfHDKeyXPrvFromMnemonic, ResultOfHDKeyXPubFromXPrv,
 };
 pub use crate::crypto::keys::{
     convert_public_key_to_ton_safe_format, generate_random_sign_keys, sign, verify_signature,
     KeyPair, ParamsOfConvertPublicKeyToTonSafeFormat, ParamsOfSign, ParamsOfVerifySignature,
     ResultOfConvertPublicKeyToTonSafeFormat, ResultOfSign, ResultOfVerifySignature,
 };
+pub use crate::crypto::keystore::{
+    keystore_create, keystore_delete, keystore_export, keystore_import, keystore_list,
+    keystore_unlock, KeystoreEntryInfo, KeystoreEntryType, KeystoreKdfParams, KeystoreSecret,
+    ParamsOfKeystoreCreate, ParamsOfKeystoreEntry, ParamsOfKeystoreImport, ParamsOfKeystoreList,
+    ResultOfKeystore, ResultOfKeystoreExport, ResultOfKeystoreList,
+};
 pub use crate::crypto::math::{
     factorize, generate_random_bytes, modular_power, ton_crc16, ParamsOfFactorize,
     ParamsOfGenerateRandomBytes, ParamsOfModularPower, ParamsOfTonCrc16, ResultOfFactorize,
@@ -68,6 +88,25 @@ pub use crate::crypto::nacl::{
     ResultOfNaclBox, ResultOfNaclBoxOpen, ResultOfNaclSign, ResultOfNaclSignDetached,
     ResultOfNaclSignDetachedVerify, ResultOfNaclSignOpen,
 };
+pub use crate::crypto::secp256k1_keys::{
+    secp256k1_generate_keypair, secp256k1_keypair_from_secret, secp256k1_recover_public_key,
+    secp256k1_sign, secp256k1_verify, ParamsOfSecp256k1GenerateKeyPair,
+    ParamsOfSecp256k1KeyPairFromSecret, ParamsOfSecp256k1RecoverPublicKey, ParamsOfSecp256k1Sign,
+    ParamsOfSecp256k1Verify, ResultOfSecp256k1RecoverPublicKey, ResultOfSecp256k1Sign,
+    ResultOfSecp256k1Verify,
+};
+pub use crate::crypto::secret_sharing::{
+    secret_combine, secret_split, ParamsOfSecretCombine, ParamsOfSecretSplit,
+    ResultOfSecretCombine, ResultOfSecretSplit,
+};
+pub use crate::crypto::signers::{
+    split_signing_key, KeyShareSigner, SigningCommitment, ThresholdKeyShare, ThresholdSigner,
+    ThresholdSigningBox,
+};
+#[cfg(all(unix, feature = "std"))]
+pub use crate::crypto::signers::AgentSigningBox;
+#[cfg(feature = "pkcs11")]
+pub use crate::crypto::signers::{Pkcs11Config, Pkcs11SigningBox};
 pub use encryption::{chacha20, ParamsOfChaCha20, ResultOfChaCha20};
 
 use serde::{Deserialize, Deserializer};
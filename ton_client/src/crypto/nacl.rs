@@ -270,24 +270,14 @@ pub fn nacl_box(
     _context: std::sync::Arc<ClientContext>,
     params: ParamsOfNaclBox,
 ) -> ClientResult<ResultOfNaclBox> {
-    let (mut padded_output, padded_input, nonce, secret) = prepare_to_convert(
+    let encrypted = box_encrypt(
         &base64_decode(&params.decrypted)?,
         &hex_decode(&params.nonce)?,
+        &hex_decode(&params.their_public)?,
         &hex_decode(&params.secret)?,
-        32,
     )?;
-
-    sodalite::box_(
-        &mut padded_output,
-        &padded_input,
-        &nonce,
-        &key256(&hex_decode(&params.their_public)?)?,
-        &secret,
-    )
-    .map_err(|_| crypto::Error::nacl_box_failed("box failed"))?;
-    padded_output.drain(..16);
     Ok(ResultOfNaclBox {
-        encrypted: base64::encode(&padded_output),
+        encrypted: base64::encode(&encrypted),
     })
 }
 
@@ -319,23 +309,14 @@ pub fn nacl_box_open(
     _context: std::sync::Arc<ClientContext>,
     params: ParamsOfNaclBoxOpen,
 ) -> ClientResult<ResultOfNaclBoxOpen> {
-    let (mut padded_output, padded_input, nonce, secret) = prepare_to_convert(
+    let decrypted = box_decrypt(
         &base64_decode(&params.encrypted)?,
         &hex_decode(&params.nonce)?,
+        &hex_decode(&params.their_public)?,
         &hex_decode(&params.secret)?,
-        16,
     )?;
-    sodalite::box_open(
-        &mut padded_output,
-        &padded_input,
-        &nonce,
-        &key256(&hex_decode(&params.their_public)?)?,
-        &secret,
-    )
-    .map_err(|_| crypto::Error::nacl_box_failed("box open failed"))?;
-    padded_output.drain(..32);
     Ok(ResultOfNaclBoxOpen {
-        decrypted: base64::encode(&padded_output),
+        decrypted: base64::encode(&decrypted),
     })
 }
 
@@ -360,18 +341,13 @@ pub fn nacl_secret_box(
     _context: std::sync::Arc<ClientContext>,
     params: ParamsOfNaclSecretBox,
 ) -> ClientResult<ResultOfNaclBox> {
-    let (mut padded_output, padded_input, nonce, key) = prepare_to_convert(
+    let encrypted = secret_box_encrypt(
         &base64_decode(&params.decrypted)?,
         &hex_decode(&params.nonce)?,
         &hex_decode(&params.key)?,
-        32,
     )?;
-
-    sodalite::secretbox(&mut padded_output, &padded_input, &nonce, &key)
-        .map_err(|_| crypto::Error::nacl_secret_box_failed("secret box failed"))?;
-    padded_output.drain(..16);
     Ok(ResultOfNaclBox {
-        encrypted: base64::encode(&padded_output),
+        encrypted: base64::encode(&encrypted),
     })
 }
 
@@ -394,22 +370,91 @@ pub fn nacl_secret_box_open(
     _context: std::sync::Arc<ClientContext>,
     params: ParamsOfNaclSecretBoxOpen,
 ) -> ClientResult<ResultOfNaclBoxOpen> {
-    let (mut padded_output, padded_input, nonce, key) = prepare_to_convert(
+    let decrypted = secret_box_decrypt(
         &base64_decode(&params.encrypted)?,
         &hex_decode(&params.nonce)?,
         &hex_decode(&params.key)?,
-        16,
     )?;
+    Ok(ResultOfNaclBoxOpen {
+        decrypted: base64::encode(&decrypted),
+    })
+}
+
+// Internals
+
+pub(crate) fn box_encrypt(
+    decrypted: &Vec<u8>,
+    nonce: &Vec<u8>,
+    their_public: &Vec<u8>,
+    secret: &Vec<u8>,
+) -> ClientResult<Vec<u8>> {
+    let (mut padded_output, padded_input, nonce, secret) =
+        prepare_to_convert(decrypted, nonce, secret, 32)?;
+    sodalite::box_(
+        &mut padded_output,
+        &padded_input,
+        &nonce,
+        &key256(their_public)?,
+        &secret,
+    )
+    .map_err(|_| crypto::Error::nacl_box_failed("box failed"))?;
+    padded_output.drain(..16);
+    Ok(padded_output)
+}
+
+pub(crate) fn box_decrypt(
+    encrypted: &Vec<u8>,
+    nonce: &Vec<u8>,
+    their_public: &Vec<u8>,
+    secret: &Vec<u8>,
+) -> ClientResult<Vec<u8>> {
+    let (mut padded_output, padded_input, nonce, secret) =
+        prepare_to_convert(encrypted, nonce, secret, 16)?;
+    sodalite::box_open(
+        &mut padded_output,
+        &padded_input,
+        &nonce,
+        &key256(their_public)?,
+        &secret,
+    )
+    .map_err(|_| crypto::Error::nacl_box_failed("box open failed"))?;
+    padded_output.drain(..32);
+    Ok(padded_output)
+}
 
+pub(crate) fn secret_box_encrypt(
+    decrypted: &Vec<u8>,
+    nonce: &Vec<u8>,
+    key: &Vec<u8>,
+) -> ClientResult<Vec<u8>> {
+    let (mut padded_output, padded_input, nonce, key) =
+        prepare_to_convert(decrypted, nonce, key, 32)?;
+    sodalite::secretbox(&mut padded_output, &padded_input, &nonce, &key)
+        .map_err(|_| crypto::Error::nacl_secret_box_failed("secret box failed"))?;
+    padded_output.drain(..16);
+    Ok(padded_output)
+}
+
+pub(crate) fn secret_box_decrypt(
+    encrypted: &Vec<u8>,
+    nonce: &Vec<u8>,
+    key: &Vec<u8>,
+) -> ClientResult<Vec<u8>> {
+    let (mut padded_output, padded_input, nonce, key) =
+        prepare_to_convert(encrypted, nonce, key, 16)?;
     sodalite::secretbox_open(&mut padded_output, &padded_input, &nonce, &key)
         .map_err(|_| crypto::Error::nacl_secret_box_failed("secret box open failed"))?;
     padded_output.drain(..32);
-    Ok(ResultOfNaclBoxOpen {
-        decrypted: base64::encode(&padded_output),
-    })
+    Ok(padded_output)
 }
 
-// Internals
+pub(crate) fn box_public_from_secret(secret: &Vec<u8>) -> ClientResult<Vec<u8>> {
+    let seed = key256(secret)?;
+    let mut sk = [0u8; 32];
+    let mut pk = [0u8; 32];
+    sodalite::box_keypair_seed(&mut pk, &mut sk, &seed);
+    Ok(pk.to_vec())
+}
 
 fn sign(unsigned: Vec<u8>, secret: Vec<u8>) -> ClientResult<Vec<u8>> {
     let mut signed: Vec<u8> = Vec::new();
@@ -0,0 +1,227 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+use crate::client::ClientContext;
+use crate::crypto;
+use crate::crypto::keys::KeyPair;
+use crate::encoding::hex_decode;
+use crate::error::ClientResult;
+use rand::RngCore;
+use secp256k1::{Message, PublicKey, RecoveryId, SecretKey, Signature};
+use std::sync::Arc;
+
+const HASH_SIZE: usize = 32;
+
+//------------------------------------------------------------------- secp256k1_keypair_from_secret
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfSecp256k1KeyPairFromSecret {
+    /// Secret key - 32 bytes encoded with `hex`.
+    pub secret: String,
+    /// Return the public key in the compressed 33 bytes format.
+    /// Otherwise the uncompressed 65 bytes format is used.
+    ///
+    /// Default is `true`.
+    pub compressed: Option<bool>,
+}
+
+/// Calculates secp256k1 public key from the secret key.
+#[api_function]
+pub fn secp256k1_keypair_from_secret(
+    _context: Arc<ClientContext>,
+    params: ParamsOfSecp256k1KeyPairFromSecret,
+) -> ClientResult<KeyPair> {
+    let secret = decode_secret(&params.secret)?;
+    Ok(KeyPair::new(
+        encode_public(&PublicKey::from_secret_key(&secret), params.compressed),
+        hex::encode(secret.serialize()),
+    ))
+}
+
+//------------------------------------------------------------------- secp256k1_generate_keypair
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfSecp256k1GenerateKeyPair {
+    /// Return the public key in the compressed 33 bytes format.
+    /// Otherwise the uncompressed 65 bytes format is used.
+    ///
+    /// Default is `true`.
+    pub compressed: Option<bool>,
+}
+
+/// Generates random secp256k1 key pair.
+#[api_function]
+pub fn secp256k1_generate_keypair(
+    _context: Arc<ClientContext>,
+    params: ParamsOfSecp256k1GenerateKeyPair,
+) -> ClientResult<KeyPair> {
+    let mut rng = rand::thread_rng();
+    let mut bytes = [0u8; 32];
+    let secret = loop {
+        rng.fill_bytes(&mut bytes);
+        if let Ok(secret) = SecretKey::parse(&bytes) {
+            break secret;
+        }
+    };
+    Ok(KeyPair::new(
+        encode_public(&PublicKey::from_secret_key(&secret), params.compressed),
+        hex::encode(secret.serialize()),
+    ))
+}
+
+//---------------------------------------------------------------------------------- secp256k1_sign
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfSecp256k1Sign {
+    /// Hash of the data to be signed - 32 bytes encoded with `hex`.
+    pub hash: String,
+    /// Secret key - 32 bytes encoded with `hex`.
+    pub secret: String,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfSecp256k1Sign {
+    /// Signature in the `r || s` form - 64 bytes encoded with `hex`.
+    pub signature: String,
+    /// Recovery identifier (0..3) required to recover the public key from the signature.
+    pub recovery_id: u8,
+}
+
+#[doc(summary = "Signs the hash with the secp256k1 secret key")]
+/// Produces deterministic (RFC 6979) ECDSA signature with the low `s` value.
+///
+/// The function signs the hash as is, so the data must be hashed by the caller.
+/// Ethereum signatures are made over `keccak256` hash of the data and use
+/// `recovery_id + 27` as the `v` value.
+#[api_function]
+pub fn secp256k1_sign(
+    _context: Arc<ClientContext>,
+    params: ParamsOfSecp256k1Sign,
+) -> ClientResult<ResultOfSecp256k1Sign> {
+    let message = decode_hash(&params.hash)?;
+    let secret = decode_secret(&params.secret)?;
+    let (signature, recovery_id) = secp256k1::sign(&message, &secret);
+    Ok(ResultOfSecp256k1Sign {
+        signature: hex::encode(&signature.serialize()[..]),
+        recovery_id: recovery_id.serialize(),
+    })
+}
+
+//-------------------------------------------------------------------------------- secp256k1_verify
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfSecp256k1Verify {
+    /// Hash of the signed data - 32 bytes encoded with `hex`.
+    pub hash: String,
+    /// Signature in the `r || s` form - 64 bytes encoded with `hex`.
+    pub signature: String,
+    /// Public key in the compressed or uncompressed format encoded with `hex`.
+    pub public: String,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfSecp256k1Verify {
+    /// `true` if the signature is valid.
+    pub succeeded: bool,
+}
+
+/// Verifies secp256k1 signature of the hash.
+#[api_function]
+pub fn secp256k1_verify(
+    _context: Arc<ClientContext>,
+    params: ParamsOfSecp256k1Verify,
+) -> ClientResult<ResultOfSecp256k1Verify> {
+    let message = decode_hash(&params.hash)?;
+    let signature = decode_signature(&params.signature)?;
+    let public = decode_public(&params.public)?;
+    Ok(ResultOfSecp256k1Verify {
+        succeeded: secp256k1::verify(&message, &signature, &public),
+    })
+}
+
+//-------------------------------------------------------------------- secp256k1_recover_public_key
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfSecp256k1RecoverPublicKey {
+    /// Hash of the signed data - 32 bytes encoded with `hex`.
+    pub hash: String,
+    /// Signature in the `r || s` form - 64 bytes encoded with `hex`.
+    pub signature: String,
+    /// Recovery identifier (0..3) returned by `secp256k1_sign`.
+    pub recovery_id: u8,
+    /// Return the public key in the compressed 33 bytes format.
+    /// Otherwise the uncompressed 65 bytes format is used.
+    ///
+    /// Default is `true`.
+    pub compressed: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfSecp256k1RecoverPublicKey {
+    /// Public key of the signer encoded with `hex`.
+    pub public: String,
+}
+
+#[doc(summary = "Recovers the signer public key from the secp256k1 signature")]
+/// Ethereum `v` value is `recovery_id + 27` (or `recovery_id + 35 + chain_id * 2`
+/// for EIP-155 transactions) and must be converted back by the caller.
+#[api_function]
+pub fn secp256k1_recover_public_key(
+    _context: Arc<ClientContext>,
+    params: ParamsOfSecp256k1RecoverPublicKey,
+) -> ClientResult<ResultOfSecp256k1RecoverPublicKey> {
+    let message = decode_hash(&params.hash)?;
+    let signature = decode_signature(&params.signature)?;
+    let recovery_id = RecoveryId::parse(params.recovery_id)
+        .map_err(|err| crypto::Error::invalid_signature(format!("{:?}", err), &params.signature))?;
+    let public = secp256k1::recover(&message, &signature, &recovery_id)
+        .map_err(|err| crypto::Error::invalid_signature(format!("{:?}", err), &params.signature))?;
+    Ok(ResultOfSecp256k1RecoverPublicKey {
+        public: encode_public(&public, params.compressed),
+    })
+}
+
+// Internals
+
+fn decode_hash(hash: &str) -> ClientResult<Message> {
+    let bytes = hex_decode(hash)?;
+    if bytes.len() != HASH_SIZE {
+        return Err(crypto::Error::invalid_hash_size(bytes.len(), HASH_SIZE));
+    }
+    let mut message = [0u8; HASH_SIZE];
+    message.copy_from_slice(&bytes);
+    Ok(Message::parse(&message))
+}
+
+fn decode_secret(secret: &String) -> ClientResult<SecretKey> {
+    SecretKey::parse_slice(&hex_decode(secret)?)
+        .map_err(|err| crypto::Error::invalid_secret_key(format!("{:?}", err), secret))
+}
+
+fn decode_public(public: &String) -> ClientResult<PublicKey> {
+    PublicKey::parse_slice(&hex_decode(public)?, None)
+        .map_err(|err| crypto::Error::invalid_public_key(format!("{:?}", err), public))
+}
+
+fn decode_signature(signature: &String) -> ClientResult<Signature> {
+    Signature::parse_slice(&hex_decode(signature)?)
+        .map_err(|err| crypto::Error::invalid_signature(format!("{:?}", err), signature))
+}
+
+fn encode_public(public: &PublicKey, compressed: Option<bool>) -> String {
+    if compressed.unwrap_or(true) {
+        hex::encode(&public.serialize_compressed()[..])
+    } else {
+        hex::encode(&public.serialize()[..])
+    }
+}
@@ -0,0 +1,321 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+use crate::client::ClientContext;
+use crate::crypto;
+use crate::crypto::internal::sha256;
+use crate::crypto::mnemonic::mnemonics;
+use crate::encoding::hex_decode;
+use crate::error::ClientResult;
+use rand::RngCore;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const SHARE_HEADER_LEN: usize = 5;
+const SHARE_CHECKSUM_LEN: usize = 4;
+const WORD_BITS: usize = 11;
+const WORD_COUNT: usize = 1 << WORD_BITS;
+
+//------------------------------------------------------------------------------------ secret_split
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfSecretSplit {
+    /// Secret to split, e.g. mnemonic entropy or a secret key. Must be encoded with `hex`.
+    /// Up to 255 bytes.
+    pub secret: String,
+    /// Number of shares required to restore the secret.
+    pub threshold: u8,
+    /// Total number of shares.
+    pub share_count: u8,
+    /// Dictionary identifier used to encode the shares.
+    pub dictionary: Option<u8>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfSecretSplit {
+    /// Shares encoded as space separated word lists.
+    pub shares: Vec<String>,
+}
+
+#[doc(summary = "Splits a secret into shares using Shamir's secret sharing")]
+/// Splits the secret into `share_count` shares so that any `threshold` of them
+/// restore the secret with `secret_combine` and fewer shares reveal nothing about it.
+///
+/// Splitting is performed byte by byte over GF(256). Each share is encoded as a word list
+/// of the mnemonic dictionary and contains a random split identifier, the threshold, the
+/// share index and a checksum, so mistyped words and shares of different splits are detected.
+#[api_function]
+pub fn secret_split(
+    context: Arc<ClientContext>,
+    params: ParamsOfSecretSplit,
+) -> ClientResult<ResultOfSecretSplit> {
+    let secret = hex_decode(&params.secret)?;
+    if secret.is_empty() || secret.len() > 255 {
+        return Err(crypto::Error::invalid_secret_sharing(format!(
+            "secret length must be from 1 to 255 bytes, got {}",
+            secret.len()
+        )));
+    }
+    if params.threshold == 0 || params.threshold > params.share_count {
+        return Err(crypto::Error::invalid_secret_sharing(format!(
+            "threshold must be from 1 to share count {}, got {}",
+            params.share_count, params.threshold
+        )));
+    }
+    let words = dictionary_words(&context, params.dictionary)?;
+
+    let mut rng = rand::thread_rng();
+    let mut id = [0u8; 2];
+    rng.fill_bytes(&mut id);
+    let mut shares: Vec<Vec<u8>> = (1..=params.share_count)
+        .map(|x| {
+            let mut share = Vec::with_capacity(SHARE_HEADER_LEN + secret.len());
+            share.extend_from_slice(&id);
+            share.push(params.threshold);
+            share.push(x);
+            share.push(secret.len() as u8);
+            share
+        })
+        .collect();
+
+    let mut coefficients = vec![0u8; params.threshold as usize];
+    for byte in &secret {
+        coefficients[0] = *byte;
+        rng.fill_bytes(&mut coefficients[1..]);
+        for (index, share) in shares.iter_mut().enumerate() {
+            share.push(gf256::evaluate(&coefficients, index as u8 + 1));
+        }
+    }
+
+    Ok(ResultOfSecretSplit {
+        shares: shares
+            .into_iter()
+            .map(|share| encode_share(share, &words))
+            .collect(),
+    })
+}
+
+//---------------------------------------------------------------------------------- secret_combine
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfSecretCombine {
+    /// Shares returned by `secret_split`. At least `threshold` shares are required.
+    pub shares: Vec<String>,
+    /// Dictionary identifier used to encode the shares.
+    pub dictionary: Option<u8>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ResultOfSecretCombine {
+    /// Restored secret. Encoded with `hex`.
+    pub secret: String,
+}
+
+/// Restores the secret from the shares returned by `secret_split`.
+#[api_function]
+pub fn secret_combine(
+    context: Arc<ClientContext>,
+    params: ParamsOfSecretCombine,
+) -> ClientResult<ResultOfSecretCombine> {
+    let words = dictionary_words(&context, params.dictionary)?;
+    let indices: HashMap<&str, u16> = words
+        .iter()
+        .enumerate()
+        .map(|(index, word)| (word.as_str(), index as u16))
+        .collect();
+
+    let mut shares = Vec::new();
+    for share in &params.shares {
+        shares.push(decode_share(share, &indices)?);
+    }
+    let first = shares
+        .first()
+        .ok_or_else(|| crypto::Error::invalid_secret_share("no shares specified"))?;
+    let header = &first[..SHARE_HEADER_LEN];
+    let threshold = header[2] as usize;
+    for share in &shares {
+        if share[0..3] != header[0..3] || share[4] != header[4] {
+            return Err(crypto::Error::invalid_secret_share(
+                "shares belong to different secrets",
+            ));
+        }
+    }
+    let mut xs: Vec<u8> = Vec::new();
+    let mut selected = Vec::new();
+    for share in &shares {
+        if !xs.contains(&share[3]) {
+            xs.push(share[3]);
+            selected.push(share);
+        }
+    }
+    if selected.len() < threshold {
+        return Err(crypto::Error::invalid_secret_share(format!(
+            "{} shares are required, got {}",
+            threshold,
+            selected.len()
+        )));
+    }
+    xs.truncate(threshold);
+    selected.truncate(threshold);
+
+    let secret_len = header[4] as usize;
+    let secret: Vec<u8> = (0..secret_len)
+        .map(|i| {
+            let ys: Vec<u8> = selected
+                .iter()
+                .map(|share| share[SHARE_HEADER_LEN + i])
+                .collect();
+            gf256::interpolate_at_zero(&xs, &ys)
+        })
+        .collect();
+    Ok(ResultOfSecretCombine {
+        secret: hex::encode(&secret),
+    })
+}
+
+// Internals
+
+fn dictionary_words(context: &ClientContext, dictionary: Option<u8>) -> ClientResult<Vec<String>> {
+    let words: Vec<String> = mnemonics(&context.config.crypto, dictionary, None)?
+        .get_words()?
+        .split_whitespace()
+        .map(|word| word.to_string())
+        .collect();
+    if words.len() != WORD_COUNT {
+        return Err(crypto::Error::invalid_secret_sharing(format!(
+            "dictionary must contain {} words",
+            WORD_COUNT
+        )));
+    }
+    Ok(words)
+}
+
+/// Share layout: split id (2 bytes), threshold, share index, secret length,
+/// share data and the first bytes of `sha256` of all the previous bytes.
+/// Bytes are packed into 11-bit word indices padded with zero bits.
+fn encode_share(mut share: Vec<u8>, words: &[String]) -> String {
+    let checksum = sha256(&share);
+    share.extend_from_slice(&checksum[..SHARE_CHECKSUM_LEN]);
+
+    let mut result = Vec::new();
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    for byte in share {
+        acc = (acc << 8) | byte as u32;
+        bits += 8;
+        while bits >= WORD_BITS {
+            bits -= WORD_BITS;
+            result.push(words[((acc >> bits) as usize) & (WORD_COUNT - 1)].as_str());
+        }
+    }
+    if bits > 0 {
+        result.push(words[((acc << (WORD_BITS - bits)) as usize) & (WORD_COUNT - 1)].as_str());
+    }
+    result.join(" ")
+}
+
+fn decode_share(share: &str, indices: &HashMap<&str, u16>) -> ClientResult<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut acc: u32 = 0;
+    let mut bits = 0;
+    for word in share.split_whitespace() {
+        let index = indices.get(word).ok_or_else(|| {
+            crypto::Error::invalid_secret_share(format!("unknown word `{}`", word))
+        })?;
+        acc = (acc << WORD_BITS) | *index as u32;
+        bits += WORD_BITS;
+        while bits >= 8 {
+            bits -= 8;
+            bytes.push((acc >> bits) as u8);
+        }
+        acc &= (1 << bits) - 1;
+    }
+    if bytes.len() < SHARE_HEADER_LEN {
+        return Err(crypto::Error::invalid_secret_share("share is too short"));
+    }
+    let len = SHARE_HEADER_LEN + bytes[4] as usize;
+    if bytes.len() < len + SHARE_CHECKSUM_LEN
+        || bytes[len + SHARE_CHECKSUM_LEN..]
+            .iter()
+            .any(|byte| *byte != 0)
+        || acc != 0
+    {
+        return Err(crypto::Error::invalid_secret_share("invalid share length"));
+    }
+    if sha256(&bytes[..len])[..SHARE_CHECKSUM_LEN] != bytes[len..len + SHARE_CHECKSUM_LEN] {
+        return Err(crypto::Error::invalid_secret_share(
+            "invalid share checksum",
+        ));
+    }
+    if bytes[2] == 0 || bytes[3] == 0 {
+        return Err(crypto::Error::invalid_secret_share("invalid share header"));
+    }
+    bytes.truncate(len);
+    Ok(bytes)
+}
+
+/// Arithmetic in GF(2^8) with the AES reduction polynomial `x^8 + x^4 + x^3 + x + 1`
+mod gf256 {
+    pub(super) fn mul(mut a: u8, mut b: u8) -> u8 {
+        let mut result = 0u8;
+        for _ in 0..8 {
+            result ^= a & 0u8.wrapping_sub(b & 1);
+            let carry = 0u8.wrapping_sub(a >> 7);
+            a = (a << 1) ^ (carry & 0x1b);
+            b >>= 1;
+        }
+        result
+    }
+
+    pub(super) fn inv(a: u8) -> u8 {
+        // a^254 = a^-1
+        let mut result = 1u8;
+        let mut base = a;
+        let mut exp = 254u8;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = mul(result, base);
+            }
+            base = mul(base, base);
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// Evaluates the polynomial with the specified coefficients
+    /// (starting from the free term) at `x`.
+    pub(super) fn evaluate(coefficients: &[u8], x: u8) -> u8 {
+        coefficients
+            .iter()
+            .rev()
+            .fold(0u8, |acc, coefficient| mul(acc, x) ^ coefficient)
+    }
+
+    /// Lagrange interpolation of the polynomial value at zero.
+    /// `xs` must be distinct and non zero.
+    pub(super) fn interpolate_at_zero(xs: &[u8], ys: &[u8]) -> u8 {
+        let mut result = 0u8;
+        for (i, (xi, yi)) in xs.iter().zip(ys).enumerate() {
+            let mut numerator = 1u8;
+            let mut denominator = 1u8;
+            for (j, xj) in xs.iter().enumerate() {
+                if i != j {
+                    numerator = mul(numerator, *xj);
+                    denominator = mul(denominator, xj ^ xi);
+                }
+            }
+            result ^= mul(*yi, mul(numerator, inv(denominator)));
+        }
+        result
+    }
+}
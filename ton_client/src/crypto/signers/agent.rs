@@ -0,0 +1,110 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+use crate::crypto::boxes::SigningBox;
+use crate::crypto::Error;
+use crate::error::ClientResult;
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const BACKEND: &str = "agent";
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Signing box which delegates signing to a local agent listening on a Unix socket.
+///
+/// Each request opens a new connection and sends a single line JSON request
+/// `{"method":"get_public_key"}` or `{"method":"sign","unsigned":"<base64>"}`.
+/// Agent responds with a single line JSON `{"result":{"public_key":"<hex>"}}`,
+/// `{"result":{"signature":"<hex>"}}` or `{"error":"<message>"}`.
+pub struct AgentSigningBox {
+    path: PathBuf,
+    timeout: Duration,
+}
+
+impl AgentSigningBox {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Sets maximum time to wait for the agent response. Default is 60 seconds
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn call_blocking(path: &PathBuf, timeout: Duration, request: &Value) -> Result<Value, String> {
+        let mut stream = UnixStream::connect(path)
+            .map_err(|err| format!("cannot connect to {}: {}", path.display(), err))?;
+        stream.set_read_timeout(Some(timeout)).map_err(|err| err.to_string())?;
+        stream.set_write_timeout(Some(timeout)).map_err(|err| err.to_string())?;
+
+        let mut line = request.to_string();
+        line.push('\n');
+        stream.write_all(line.as_bytes()).map_err(|err| err.to_string())?;
+        stream.flush().map_err(|err| err.to_string())?;
+
+        let mut response = String::new();
+        BufReader::new(stream)
+            .read_line(&mut response)
+            .map_err(|err| err.to_string())?;
+        let mut response: Value = serde_json::from_str(&response)
+            .map_err(|err| format!("invalid response: {}", err))?;
+
+        if let Some(error) = response.get("error").filter(|error| !error.is_null()) {
+            return Err(error.as_str().map(|error| error.to_owned()).unwrap_or(error.to_string()));
+        }
+        match response.get_mut("result") {
+            Some(result) => Ok(result.take()),
+            None => Err("response has neither `result` nor `error` field".to_owned()),
+        }
+    }
+
+    async fn call(&self, request: Value, field: &str) -> ClientResult<Vec<u8>> {
+        // socket IO is blocking, so it runs on the blocking thread pool
+        let path = self.path.clone();
+        let timeout = self.timeout;
+        let result =
+            tokio::task::spawn_blocking(move || Self::call_blocking(&path, timeout, &request))
+                .await
+                .map_err(|err| Error::signing_backend_failed(BACKEND, err))?
+                .map_err(|err| Error::signing_backend_failed(BACKEND, err))?;
+
+        let value = result[field].as_str().ok_or_else(|| {
+            Error::signing_backend_failed(BACKEND, format!("response has no `{}` field", field))
+        })?;
+        hex::decode(value).map_err(|err| {
+            Error::signing_backend_failed(BACKEND, format!("invalid `{}` field: {}", field, err))
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SigningBox for AgentSigningBox {
+    async fn get_public_key(&self) -> ClientResult<Vec<u8>> {
+        self.call(json!({ "method": "get_public_key" }), "public_key").await
+    }
+
+    async fn sign(&self, unsigned: &[u8]) -> ClientResult<Vec<u8>> {
+        self.call(
+            json!({ "method": "sign", "unsigned": base64::encode(unsigned) }),
+            "signature",
+        )
+        .await
+    }
+}
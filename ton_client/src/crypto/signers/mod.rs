@@ -0,0 +1,33 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+//! Signing box implementations which don't keep secret keys in the client process.
+//!
+//! Each backend implements `SigningBox` trait and can be registered in the client context
+//! with `register_signing_box` function. Registered box can be used for message signing
+//! via `Signer::SigningBox` with the returned handle.
+
+#[cfg(all(unix, feature = "std"))]
+pub(crate) mod agent;
+#[cfg(feature = "pkcs11")]
+pub(crate) mod pkcs11;
+pub(crate) mod threshold;
+
+#[cfg(all(unix, feature = "std"))]
+pub use agent::AgentSigningBox;
+#[cfg(feature = "pkcs11")]
+pub use pkcs11::{Pkcs11Config, Pkcs11SigningBox};
+pub use threshold::{
+    split_signing_key, KeyShareSigner, SigningCommitment, ThresholdKeyShare, ThresholdSigner,
+    ThresholdSigningBox,
+};
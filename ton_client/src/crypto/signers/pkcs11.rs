@@ -0,0 +1,144 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+use crate::crypto::boxes::SigningBox;
+use crate::crypto::Error;
+use crate::error::{ClientError, ClientResult};
+use cryptoki::context::{CInitializeArgs, Pkcs11};
+use cryptoki::mechanism::Mechanism;
+use cryptoki::object::{Attribute, AttributeType, KeyType, ObjectClass, ObjectHandle};
+use cryptoki::session::{Session, SessionFlags, UserType};
+use std::sync::{Arc, Mutex};
+
+const BACKEND: &str = "pkcs11";
+const ED25519_KEY_LEN: usize = 32;
+
+fn backend_error(err: impl std::fmt::Display) -> ClientError {
+    Error::signing_backend_failed(BACKEND, err)
+}
+
+/// PKCS#11 token and Ed25519 key pair location
+#[derive(Clone, Debug)]
+pub struct Pkcs11Config {
+    /// Path to the PKCS#11 module library, e.g. `/usr/lib/softhsm/libsofthsm2.so`
+    pub module_path: String,
+    /// Label of the token containing the key pair
+    pub token_label: String,
+    /// User PIN of the token
+    pub pin: String,
+    /// Label of the private and public key objects
+    pub key_label: String,
+}
+
+/// Signing box performing Ed25519 signing inside a PKCS#11 token (`CKM_EDDSA` mechanism).
+///
+/// Secret key never leaves the token.
+pub struct Pkcs11SigningBox {
+    // keeps the module loaded while the session is used
+    _pkcs11: Pkcs11,
+    session: Arc<Mutex<Session>>,
+    private_key: ObjectHandle,
+    public_key: Vec<u8>,
+}
+
+impl Pkcs11SigningBox {
+    pub fn open(config: &Pkcs11Config) -> ClientResult<Self> {
+        let pkcs11 = Pkcs11::new(&config.module_path).map_err(backend_error)?;
+        pkcs11.initialize(CInitializeArgs::OsThreads).map_err(backend_error)?;
+
+        let mut slot = None;
+        for candidate in pkcs11.get_slots_with_token().map_err(backend_error)? {
+            let info = pkcs11.get_token_info(candidate).map_err(backend_error)?;
+            if info.label().trim_end() == config.token_label {
+                slot = Some(candidate);
+                break;
+            }
+        }
+        let slot = slot.ok_or_else(|| {
+            backend_error(format!("token `{}` is not found", config.token_label))
+        })?;
+
+        let mut flags = SessionFlags::new();
+        flags.set_serial_session(true);
+        let session = pkcs11.open_session_no_callback(slot, flags).map_err(backend_error)?;
+        session.login(UserType::User, Some(&config.pin)).map_err(backend_error)?;
+
+        let find_key = |class: ObjectClass| -> ClientResult<ObjectHandle> {
+            let template = [
+                Attribute::Class(class),
+                Attribute::KeyType(KeyType::EC_EDWARDS),
+                Attribute::Label(config.key_label.as_bytes().to_vec()),
+            ];
+            session
+                .find_objects(&template)
+                .map_err(backend_error)?
+                .into_iter()
+                .next()
+                .ok_or_else(|| backend_error(format!("key `{}` is not found", config.key_label)))
+        };
+        let private_key = find_key(ObjectClass::PRIVATE_KEY)?;
+        let public_key_object = find_key(ObjectClass::PUBLIC_KEY)?;
+
+        let public_key = session
+            .get_attributes(public_key_object, &[AttributeType::EcPoint])
+            .map_err(backend_error)?
+            .into_iter()
+            .find_map(|attribute| match attribute {
+                Attribute::EcPoint(point) => Some(point),
+                _ => None,
+            })
+            .ok_or_else(|| backend_error("public key has no `CKA_EC_POINT` attribute"))?;
+
+        Ok(Self {
+            _pkcs11: pkcs11,
+            session: Arc::new(Mutex::new(session)),
+            private_key,
+            public_key: decode_ec_point(&public_key)?,
+        })
+    }
+}
+
+/// `CKA_EC_POINT` contains the public key either raw or wrapped into DER `OCTET STRING`
+fn decode_ec_point(point: &[u8]) -> ClientResult<Vec<u8>> {
+    match point.len() {
+        ED25519_KEY_LEN => Ok(point.to_vec()),
+        len if len == ED25519_KEY_LEN + 2 && point[0] == 0x04 && point[1] == ED25519_KEY_LEN as u8 => {
+            Ok(point[2..].to_vec())
+        }
+        _ => Err(backend_error("unsupported `CKA_EC_POINT` encoding")),
+    }
+}
+
+#[async_trait::async_trait]
+impl SigningBox for Pkcs11SigningBox {
+    async fn get_public_key(&self) -> ClientResult<Vec<u8>> {
+        Ok(self.public_key.clone())
+    }
+
+    async fn sign(&self, unsigned: &[u8]) -> ClientResult<Vec<u8>> {
+        // `C_Sign` blocks until the token responds, so it runs on the blocking thread pool
+        let session = self.session.clone();
+        let private_key = self.private_key;
+        let unsigned = unsigned.to_vec();
+        tokio::task::spawn_blocking(move || {
+            let session = session
+                .lock()
+                .map_err(|_| backend_error("session is poisoned"))?;
+            session
+                .sign(&Mechanism::Eddsa, private_key, &unsigned)
+                .map_err(backend_error)
+        })
+        .await
+        .map_err(backend_error)?
+    }
+}
@@ -0,0 +1,367 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+//! Threshold Ed25519 signing based on the two-round FROST protocol.
+//!
+//! Group key is split into shares so that any `threshold` participants
+//! can produce a regular Ed25519 signature verifiable with the group public key.
+//! No participant ever holds the whole secret key.
+
+use crate::crypto::boxes::SigningBox;
+use crate::crypto::{Error, KeyPair};
+use crate::error::{ClientError, ClientResult};
+use curve25519_dalek::constants::ED25519_BASEPOINT_TABLE;
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::Identity;
+use ed25519_dalek::Verifier;
+use rand::RngCore;
+use sha2::{Digest, Sha512};
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+const BACKEND: &str = "threshold";
+const BINDING_FACTOR_CONTEXT: &[u8] = b"FROST-ED25519-SHA512-rho";
+
+fn backend_error(err: impl std::fmt::Display) -> ClientError {
+    Error::signing_backend_failed(BACKEND, err)
+}
+
+fn random_scalar() -> Scalar {
+    let mut bytes = [0u8; 64];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    Scalar::from_bytes_mod_order_wide(&bytes)
+}
+
+fn hash_to_scalar(parts: &[&[u8]]) -> Scalar {
+    let mut hasher = Sha512::new();
+    for part in parts {
+        hasher.input(part);
+    }
+    let mut hash = [0u8; 64];
+    hash.copy_from_slice(&hasher.result());
+    Scalar::from_bytes_mod_order_wide(&hash)
+}
+
+fn decode_point(bytes: &[u8; 32]) -> ClientResult<EdwardsPoint> {
+    CompressedEdwardsY(*bytes)
+        .decompress()
+        .ok_or_else(|| backend_error("invalid curve point"))
+}
+
+fn decode_scalar(bytes: [u8; 32]) -> ClientResult<Scalar> {
+    Scalar::from_canonical_bytes(bytes).ok_or_else(|| backend_error("invalid scalar"))
+}
+
+/// Ed25519 secret scalar derived from the secret key the same way as in signing
+fn expand_secret_key(secret: &[u8]) -> Scalar {
+    let mut hasher = Sha512::new();
+    hasher.input(secret);
+    let hash = hasher.result();
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&hash[..32]);
+    key[0] &= 248;
+    key[31] &= 127;
+    key[31] |= 64;
+    Scalar::from_bytes_mod_order(key)
+}
+
+/// Secret key share of the threshold key
+#[derive(Clone)]
+pub struct ThresholdKeyShare {
+    /// Participant index starting from 1
+    pub index: u16,
+    /// Secret share
+    pub secret: [u8; 32],
+    /// Group public key
+    pub public_key: [u8; 32],
+}
+
+/// Splits Ed25519 key pair into `count` shares, any `threshold` of which can sign.
+///
+/// Shares are generated by a trusted dealer, so the key pair must be destroyed
+/// after the shares are distributed.
+pub fn split_signing_key(
+    keys: &KeyPair,
+    threshold: u16,
+    count: u16,
+) -> ClientResult<Vec<ThresholdKeyShare>> {
+    if threshold == 0 || threshold > count {
+        return Err(backend_error(format!(
+            "invalid threshold {} for {} shares",
+            threshold, count
+        )));
+    }
+    let keys = keys.decode()?;
+
+    let mut coefficients = vec![expand_secret_key(keys.secret.as_bytes())];
+    for _ in 1..threshold {
+        coefficients.push(random_scalar());
+    }
+
+    Ok((1..=count)
+        .map(|index| {
+            let x = Scalar::from(index as u64);
+            let secret = coefficients
+                .iter()
+                .rev()
+                .fold(Scalar::zero(), |value, coefficient| value * x + coefficient);
+            ThresholdKeyShare {
+                index,
+                secret: secret.to_bytes(),
+                public_key: keys.public.to_bytes(),
+            }
+        })
+        .collect())
+}
+
+/// Commitments to the signing nonces of the participant
+#[derive(Clone, Debug, PartialEq)]
+pub struct SigningCommitment {
+    /// Participant index
+    pub index: u16,
+    /// Hiding nonce commitment
+    pub hiding: [u8; 32],
+    /// Binding nonce commitment
+    pub binding: [u8; 32],
+}
+
+/// Participant of the threshold signing session.
+///
+/// Participant can run in another process or on another host,
+/// in this case implementation forwards the calls to it.
+#[async_trait::async_trait]
+pub trait ThresholdSigner {
+    /// First round: generates new signing nonces and returns their commitments
+    async fn commit(&self) -> ClientResult<SigningCommitment>;
+    /// Second round: returns signature share for the message.
+    /// `commitments` contains commitments of all participants of the session
+    async fn sign_share(
+        &self,
+        message: &[u8],
+        commitments: &[SigningCommitment],
+    ) -> ClientResult<[u8; 32]>;
+}
+
+fn check_commitments(commitments: &[SigningCommitment]) -> ClientResult<()> {
+    let mut indexes = HashSet::new();
+    for commitment in commitments {
+        if commitment.index == 0 || !indexes.insert(commitment.index) {
+            return Err(backend_error(format!(
+                "invalid participant index {}",
+                commitment.index
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn binding_factors(message: &[u8], commitments: &[SigningCommitment]) -> Vec<Scalar> {
+    let mut encoded = Vec::with_capacity(commitments.len() * 66);
+    for commitment in commitments {
+        encoded.extend_from_slice(&commitment.index.to_be_bytes());
+        encoded.extend_from_slice(&commitment.hiding);
+        encoded.extend_from_slice(&commitment.binding);
+    }
+    commitments
+        .iter()
+        .map(|commitment| {
+            hash_to_scalar(&[
+                BINDING_FACTOR_CONTEXT,
+                &commitment.index.to_be_bytes(),
+                message,
+                &encoded,
+            ])
+        })
+        .collect()
+}
+
+fn group_commitment(
+    commitments: &[SigningCommitment],
+    binding_factors: &[Scalar],
+) -> ClientResult<EdwardsPoint> {
+    let mut result = EdwardsPoint::identity();
+    for (commitment, factor) in commitments.iter().zip(binding_factors) {
+        result += decode_point(&commitment.hiding)? + decode_point(&commitment.binding)? * factor;
+    }
+    Ok(result)
+}
+
+fn challenge(group_commitment: &EdwardsPoint, public_key: &[u8; 32], message: &[u8]) -> Scalar {
+    hash_to_scalar(&[group_commitment.compress().as_bytes(), public_key, message])
+}
+
+fn lagrange_coefficient(index: u16, commitments: &[SigningCommitment]) -> Scalar {
+    let x = Scalar::from(index as u64);
+    let mut numerator = Scalar::one();
+    let mut denominator = Scalar::one();
+    for commitment in commitments.iter().filter(|commitment| commitment.index != index) {
+        let other = Scalar::from(commitment.index as u64);
+        numerator *= other;
+        denominator *= other - x;
+    }
+    numerator * denominator.invert()
+}
+
+struct SigningNonces {
+    hiding: Scalar,
+    binding: Scalar,
+    commitment: SigningCommitment,
+}
+
+/// Threshold signing participant holding the key share in the current process
+pub struct KeyShareSigner {
+    index: u16,
+    secret: Scalar,
+    public_key: [u8; 32],
+    nonces: Mutex<Option<SigningNonces>>,
+}
+
+impl KeyShareSigner {
+    pub fn new(share: &ThresholdKeyShare) -> ClientResult<Self> {
+        Ok(Self {
+            index: share.index,
+            secret: decode_scalar(share.secret)?,
+            public_key: share.public_key,
+            nonces: Mutex::new(None),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl ThresholdSigner for KeyShareSigner {
+    async fn commit(&self) -> ClientResult<SigningCommitment> {
+        let hiding = random_scalar();
+        let binding = random_scalar();
+        let commitment = SigningCommitment {
+            index: self.index,
+            hiding: (&hiding * &ED25519_BASEPOINT_TABLE).compress().to_bytes(),
+            binding: (&binding * &ED25519_BASEPOINT_TABLE).compress().to_bytes(),
+        };
+        *self.nonces.lock().map_err(|_| backend_error("nonces are poisoned"))? =
+            Some(SigningNonces { hiding, binding, commitment: commitment.clone() });
+        Ok(commitment)
+    }
+
+    async fn sign_share(
+        &self,
+        message: &[u8],
+        commitments: &[SigningCommitment],
+    ) -> ClientResult<[u8; 32]> {
+        // nonces are never reused, even if the session fails
+        let nonces = self.nonces
+            .lock()
+            .map_err(|_| backend_error("nonces are poisoned"))?
+            .take()
+            .ok_or_else(|| backend_error("signing share requested without commitment"))?;
+        check_commitments(commitments)?;
+        let position = commitments
+            .iter()
+            .position(|commitment| commitment == &nonces.commitment)
+            .ok_or_else(|| backend_error("session doesn't contain participant commitment"))?;
+
+        let factors = binding_factors(message, commitments);
+        let group_commitment = group_commitment(commitments, &factors)?;
+        let challenge = challenge(&group_commitment, &self.public_key, message);
+        let share = nonces.hiding
+            + nonces.binding * factors[position]
+            + lagrange_coefficient(self.index, commitments) * self.secret * challenge;
+
+        Ok(share.to_bytes())
+    }
+}
+
+/// Signing box producing Ed25519 signatures with `threshold` of the participants
+pub struct ThresholdSigningBox {
+    public_key: [u8; 32],
+    threshold: usize,
+    signers: Vec<Box<dyn ThresholdSigner + Send + Sync>>,
+    session: tokio::sync::Mutex<()>,
+}
+
+impl ThresholdSigningBox {
+    pub fn new(
+        public_key: [u8; 32],
+        threshold: u16,
+        signers: Vec<Box<dyn ThresholdSigner + Send + Sync>>,
+    ) -> ClientResult<Self> {
+        if threshold == 0 || signers.len() < threshold as usize {
+            return Err(backend_error(format!(
+                "invalid threshold {} for {} participants",
+                threshold,
+                signers.len()
+            )));
+        }
+        Ok(Self {
+            public_key,
+            threshold: threshold as usize,
+            signers,
+            session: tokio::sync::Mutex::new(()),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SigningBox for ThresholdSigningBox {
+    async fn get_public_key(&self) -> ClientResult<Vec<u8>> {
+        Ok(self.public_key.to_vec())
+    }
+
+    async fn sign(&self, unsigned: &[u8]) -> ClientResult<Vec<u8>> {
+        // participants keep nonces of a single session only
+        let _session = self.session.lock().await;
+
+        let mut participants = Vec::with_capacity(self.threshold);
+        let mut commitments = Vec::with_capacity(self.threshold);
+        for signer in &self.signers {
+            if commitments.len() == self.threshold {
+                break;
+            }
+            match signer.commit().await {
+                Ok(commitment) => {
+                    participants.push(signer);
+                    commitments.push(commitment);
+                }
+                Err(err) => log::warn!("Threshold signing participant is unavailable: {}", err),
+            }
+        }
+        if commitments.len() < self.threshold {
+            return Err(backend_error(format!(
+                "only {} of {} required participants are available",
+                commitments.len(),
+                self.threshold
+            )));
+        }
+        check_commitments(&commitments)?;
+
+        let factors = binding_factors(unsigned, &commitments);
+        let group_commitment = group_commitment(&commitments, &factors)?;
+        let mut response = Scalar::zero();
+        for signer in participants {
+            response += decode_scalar(signer.sign_share(unsigned, &commitments).await?)?;
+        }
+
+        let mut signature = [0u8; 64];
+        signature[..32].copy_from_slice(group_commitment.compress().as_bytes());
+        signature[32..].copy_from_slice(response.as_bytes());
+
+        // invalid share can not be detected by the participants, so check the result
+        let public_key = ed25519_dalek::PublicKey::from_bytes(&self.public_key)
+            .map_err(backend_error)?;
+        public_key
+            .verify(unsigned, &ed25519_dalek::Signature::new(signature))
+            .map_err(|_| backend_error("participants produced invalid signature"))?;
+
+        Ok(signature.to_vec())
+    }
+}
@@ -1,3 +1,4 @@
+use base58::{FromBase58, ToBase58};
 use crate::crypto::encscrypt::{ParamsOfScrypt, ResultOfScrypt};
 use crate::crypto::hash::{ParamsOfHash, ResultOfHash};
 use crate::crypto::hdkey::{
@@ -26,7 +27,10 @@ use crate::crypto::nacl::{
     ResultOfNaclSignDetached, ResultOfNaclSignOpen,
 };
 use crate::crypto::{ParamsOfChaCha20, ResultOfChaCha20};
-use crate::json_interface::crypto::{ParamsOfAppSigningBox, ResultOfAppSigningBox};
+use crate::json_interface::crypto::{
+    ParamsOfAppEncryptionBox, ParamsOfAppSigningBox, ResultOfAppEncryptionBox,
+    ResultOfAppSigningBox,
+};
 use crate::tests::TestClient;
 use super::*;
 
@@ -181,6 +185,52 @@ fn hash() {
         "16fd057308dd358d5a9b3ba2de766b2dfd5e308478fc1f7ba5988db2493852f5",
         result.hash
     );
+
+    let result: ResultOfHash = client
+        .request("crypto.keccak256", ParamsOfHash { data: "".into() })
+        .unwrap();
+    assert_eq!(
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+        result.hash
+    );
+
+    let result: ResultOfHash = client
+        .request(
+            "crypto.keccak256",
+            ParamsOfHash {
+                data: base64::encode("abc"),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+        result.hash
+    );
+
+    let result: ResultOfHash = client
+        .request("crypto.ripemd160", ParamsOfHash { data: "".into() })
+        .unwrap();
+    assert_eq!("9c1185a5c5e9fc54612808977ee8f548b2258d31", result.hash);
+
+    let result: ResultOfHash = client
+        .request(
+            "crypto.ripemd160",
+            ParamsOfHash {
+                data: base64::encode("message digest"),
+            },
+        )
+        .unwrap();
+    assert_eq!("5d0689ef49d2fae572b881b123a85ffa21595f36", result.hash);
+
+    let result: ResultOfHash = client
+        .request(
+            "crypto.ripemd160",
+            ParamsOfHash {
+                data: base64::encode("1234567890".repeat(8)),
+            },
+        )
+        .unwrap();
+    assert_eq!("9b752e45573d4b39f4dbd3323cab82bf63326bfb", result.hash);
 }
 
 #[test]
@@ -742,6 +792,372 @@ fn hdkey() {
     );
 }
 
+#[test]
+fn hdkey_xpub() {
+    TestClient::init_log();
+    let client = TestClient::new();
+
+    // BIP-0032 test vector 1, chain m/0H/1
+    let xprv = "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7";
+    let result: ResultOfHDKeyXPubFromXPrv = client
+        .request(
+            "crypto.hdkey_xpub_from_xprv",
+            ParamsOfHDKeyXPubFromXPrv { xprv: xprv.into() },
+        )
+        .unwrap();
+    assert_eq!(result.xpub, "xpub68Gmy5EdvgibQVfPdqkBBCHxA5htiqg55crXYuXoQRKfDBFA1WEjWgP6LHhwBZeNK1VTsfTFUHCdrfp1bgwQ9xv5ski8PX9rL2dZXvgGDnw");
+
+    let child: ResultOfHDKeyDeriveFromXPub = client
+        .request(
+            "crypto.hdkey_derive_from_xpub",
+            ParamsOfHDKeyDeriveFromXPub {
+                xpub: result.xpub.clone(),
+                child_index: 1,
+            },
+        )
+        .unwrap();
+    assert_eq!(child.xpub, "xpub6ASuArnXKPbfEwhqN6e3mwBcDTgzisQN1wXN9BJcM47sSikHjJf3UFHKkNAWbWMiGj7Wf5uMash7SyYq527Hqck2AxYysAA7xmALppuCkwQ");
+
+    // public derivation matches the private one
+    let child_xprv: ResultOfHDKeyDeriveFromXPrv = client
+        .request(
+            "crypto.hdkey_derive_from_xprv",
+            ParamsOfHDKeyDeriveFromXPrv {
+                xprv: xprv.into(),
+                child_index: 1,
+                hardened: false,
+            },
+        )
+        .unwrap();
+    let child_xpub: ResultOfHDKeyXPubFromXPrv = client
+        .request(
+            "crypto.hdkey_xpub_from_xprv",
+            ParamsOfHDKeyXPubFromXPrv {
+                xprv: child_xprv.xprv,
+            },
+        )
+        .unwrap();
+    assert_eq!(child_xpub.xpub, child.xpub);
+
+    let public: ResultOfHDKeyPublicFromXPub = client
+        .request(
+            "crypto.hdkey_public_from_xpub",
+            ParamsOfHDKeyPublicFromXPub { xpub: child.xpub },
+        )
+        .unwrap();
+    assert_eq!(
+        public.public,
+        "03501e454bf00751f24b1b489aa925215d66af2234e3891c3b21a52bedb3cd711c"
+    );
+
+    let error = client
+        .request::<_, ResultOfHDKeyDeriveFromXPub>(
+            "crypto.hdkey_derive_from_xpub",
+            ParamsOfHDKeyDeriveFromXPub {
+                xpub: result.xpub.clone(),
+                child_index: 0x80000000,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::Bip32InvalidDerivePath as u32);
+
+    // key with the maximum depth has no children
+    let mut deepest = result.xpub.from_base58().unwrap();
+    deepest[4] = 255;
+    let checksum = internal::sha256(&internal::sha256(&deepest[0..78]));
+    deepest[78..82].copy_from_slice(&checksum[0..4]);
+    let error = client
+        .request::<_, ResultOfHDKeyDeriveFromXPub>(
+            "crypto.hdkey_derive_from_xpub",
+            ParamsOfHDKeyDeriveFromXPub {
+                xpub: deepest.to_base58(),
+                child_index: 1,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::Bip32InvalidDerivePath as u32);
+}
+
+#[test]
+fn hdkey_slip10() {
+    TestClient::init_log();
+    let client = TestClient::new();
+
+    // SLIP-0010 test vector 1 for ed25519
+    let master = hdkey::Slip10Ed25519Key::from_seed(
+        &hex::decode("000102030405060708090a0b0c0d0e0f").unwrap(),
+    );
+    assert_eq!(
+        hex::encode(master.secret()),
+        "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7"
+    );
+    let child = master.derive_path(&"m/0'".to_string()).unwrap();
+    assert_eq!(
+        hex::encode(child.secret()),
+        "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+    );
+    let child = master.derive_path(&"m/0'/1'".to_string()).unwrap();
+    assert_eq!(
+        hex::encode(child.secret()),
+        "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2"
+    );
+
+    let phrase = "abuse boss fly battle rubber wasp afraid hamster guide essence vibrant tattoo";
+    let keys: KeyPair = client
+        .request(
+            "crypto.hdkey_slip10_keys_from_mnemonic",
+            ParamsOfHDKeySlip10KeysFromMnemonic {
+                phrase: phrase.into(),
+                dictionary: None,
+                word_count: None,
+                path: "m/44'/396'/0'/0'/0'".into(),
+            },
+        )
+        .unwrap();
+    let bip32_keys: KeyPair = client
+        .request(
+            "crypto.mnemonic_derive_sign_keys",
+            ParamsOfMnemonicDeriveSignKeys {
+                phrase: phrase.into(),
+                path: Some("m/44'/396'/0'/0'/0'".into()),
+                dictionary: None,
+                word_count: None,
+            },
+        )
+        .unwrap();
+    assert_ne!(keys.secret, bip32_keys.secret);
+    let signed: ResultOfSign = client
+        .request(
+            "crypto.sign",
+            ParamsOfSign {
+                unsigned: base64::encode("Test Message"),
+                keys: keys.clone(),
+            },
+        )
+        .unwrap();
+    let verified: ResultOfVerifySignature = client
+        .request(
+            "crypto.verify_signature",
+            ParamsOfVerifySignature {
+                signed: signed.signed,
+                public: keys.public,
+            },
+        )
+        .unwrap();
+    assert_eq!(verified.unsigned, base64::encode("Test Message"));
+
+    let error = client
+        .request::<_, KeyPair>(
+            "crypto.hdkey_slip10_keys_from_mnemonic",
+            ParamsOfHDKeySlip10KeysFromMnemonic {
+                phrase: phrase.into(),
+                dictionary: None,
+                word_count: None,
+                path: "m/44'/396'/0'/0/0".into(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::Bip32InvalidDerivePath as u32);
+}
+
+#[test]
+fn secp256k1() {
+    TestClient::init_log();
+    let client = TestClient::new();
+
+    let secret = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    let keys: KeyPair = client
+        .request(
+            "crypto.secp256k1_keypair_from_secret",
+            ParamsOfSecp256k1KeyPairFromSecret {
+                secret: secret.into(),
+                compressed: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        "024e3b81af9c2234cad09d679ce6035ed1392347ce64ce405f5dcd36228a25de6e",
+        keys.public
+    );
+    let uncompressed: KeyPair = client
+        .request(
+            "crypto.secp256k1_keypair_from_secret",
+            ParamsOfSecp256k1KeyPairFromSecret {
+                secret: secret.into(),
+                compressed: Some(false),
+            },
+        )
+        .unwrap();
+    assert_eq!(130, uncompressed.public.len());
+
+    // Ethereum address is the last 20 bytes of keccak256 of the uncompressed public key
+    let address: ResultOfHash = client
+        .request(
+            "crypto.keccak256",
+            ParamsOfHash {
+                data: base64_from_hex(&uncompressed.public[2..]),
+            },
+        )
+        .unwrap();
+    assert_eq!("2c7536e3605d9c16a7a3d7b1898e529396a65c23", &address.hash[24..]);
+
+    // personal_sign of "Some data"
+    let hash: ResultOfHash = client
+        .request(
+            "crypto.keccak256",
+            ParamsOfHash {
+                data: base64::encode("\x19Ethereum Signed Message:\n9Some data"),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        "1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655",
+        hash.hash
+    );
+    let signed: ResultOfSecp256k1Sign = client
+        .request(
+            "crypto.secp256k1_sign",
+            ParamsOfSecp256k1Sign {
+                hash: hash.hash.clone(),
+                secret: secret.into(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd\
+         6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a029",
+        signed.signature
+    );
+    assert_eq!(1, signed.recovery_id);
+
+    for public in &[&keys.public, &uncompressed.public] {
+        let verified: ResultOfSecp256k1Verify = client
+            .request(
+                "crypto.secp256k1_verify",
+                ParamsOfSecp256k1Verify {
+                    hash: hash.hash.clone(),
+                    signature: signed.signature.clone(),
+                    public: public.to_string(),
+                },
+            )
+            .unwrap();
+        assert!(verified.succeeded);
+    }
+
+    let recovered: ResultOfSecp256k1RecoverPublicKey = client
+        .request(
+            "crypto.secp256k1_recover_public_key",
+            ParamsOfSecp256k1RecoverPublicKey {
+                hash: hash.hash.clone(),
+                signature: signed.signature.clone(),
+                recovery_id: signed.recovery_id,
+                compressed: Some(false),
+            },
+        )
+        .unwrap();
+    assert_eq!(uncompressed.public, recovered.public);
+
+    let other: KeyPair = client
+        .request(
+            "crypto.secp256k1_generate_keypair",
+            ParamsOfSecp256k1GenerateKeyPair { compressed: None },
+        )
+        .unwrap();
+    assert_eq!(66, other.public.len());
+    let verified: ResultOfSecp256k1Verify = client
+        .request(
+            "crypto.secp256k1_verify",
+            ParamsOfSecp256k1Verify {
+                hash: hash.hash.clone(),
+                signature: signed.signature.clone(),
+                public: other.public.clone(),
+            },
+        )
+        .unwrap();
+    assert!(!verified.succeeded);
+
+    let error = client
+        .request::<_, ResultOfSecp256k1Sign>(
+            "crypto.secp256k1_sign",
+            ParamsOfSecp256k1Sign {
+                hash: hash.hash[2..].into(),
+                secret: secret.into(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidHashSize as u32);
+}
+
+#[test]
+fn secret_sharing() {
+    TestClient::init_log();
+    let client = TestClient::new();
+    let secret = "0c91e53128fa4d67589d63a6c44049c1068ec28a63069a55ca3de30c57f8b365";
+
+    let split = |dictionary: Option<u8>| -> Vec<String> {
+        client
+            .request::<_, ResultOfSecretSplit>(
+                "crypto.secret_split",
+                ParamsOfSecretSplit {
+                    secret: secret.into(),
+                    threshold: 3,
+                    share_count: 5,
+                    dictionary,
+                },
+            )
+            .unwrap()
+            .shares
+    };
+    let combine = |shares: Vec<&String>, dictionary: Option<u8>| {
+        client.request::<_, ResultOfSecretCombine>(
+            "crypto.secret_combine",
+            ParamsOfSecretCombine {
+                shares: shares.into_iter().cloned().collect(),
+                dictionary,
+            },
+        )
+    };
+
+    for dictionary in &[None, Some(0), Some(4)] {
+        let shares = split(*dictionary);
+        assert_eq!(shares.len(), 5);
+        let restored = combine(vec![&shares[0], &shares[2], &shares[4]], *dictionary).unwrap();
+        assert_eq!(restored.secret, secret);
+        let restored = combine(vec![&shares[3], &shares[1], &shares[4], &shares[0]], *dictionary)
+            .unwrap();
+        assert_eq!(restored.secret, secret);
+    }
+
+    let shares = split(None);
+    let error = combine(vec![&shares[0], &shares[1]], None).unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidSecretShare as u32);
+    let error = combine(vec![&shares[0], &shares[1], &shares[1]], None).unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidSecretShare as u32);
+
+    let other_shares = split(None);
+    let error = combine(vec![&shares[0], &shares[1], &other_shares[2]], None).unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidSecretShare as u32);
+
+    let mut words: Vec<&str> = shares[2].split(' ').collect();
+    words[1] = if words[1] == "abandon" { "ability" } else { "abandon" };
+    let mistyped = words.join(" ");
+    let error = combine(vec![&shares[0], &shares[1], &mistyped], None).unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidSecretShare as u32);
+
+    let error = client
+        .request::<_, ResultOfSecretSplit>(
+            "crypto.secret_split",
+            ParamsOfSecretSplit {
+                secret: secret.into(),
+                threshold: 4,
+                share_count: 3,
+                dictionary: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidSecretSharing as u32);
+}
+
 #[tokio::test(core_threads = 2)]
 async fn test_signing_box() {
     let client = std::sync::Arc::new(TestClient::new());
@@ -851,6 +1267,475 @@ async fn test_signing_box() {
     ).await.unwrap();
 }
 
+#[tokio::test(core_threads = 2)]
+async fn test_encryption_boxes() {
+    let client = TestClient::new();
+    let key = "01".repeat(32);
+    let data = base64::encode("Test Message");
+
+    let algorithms = vec![
+        EncryptionAlgorithm::ChaCha20 { key: key.clone() },
+        EncryptionAlgorithm::NaclSecretBox { key: key.clone() },
+        EncryptionAlgorithm::NaclBox {
+            their_public: "c4e2d9fe6a6baf8d1812b799856ef2a306291be7a7024837ad33a8530db79c6b".into(),
+            secret: "d9b9dc5033fb416134e5d2107fdbacab5aadb297cb82dbdcd137d663bac59f7f".into(),
+        },
+    ];
+
+    for algorithm in algorithms {
+        let encryption_box: RegisteredEncryptionBox = client
+            .request_async(
+                "crypto.create_encryption_box",
+                ParamsOfCreateEncryptionBox { algorithm: algorithm.clone() },
+            )
+            .await
+            .unwrap();
+
+        let info: ResultOfEncryptionBoxGetInfo = client
+            .request_async(
+                "crypto.encryption_box_get_info",
+                ParamsOfEncryptionBoxGetInfo {
+                    encryption_box: encryption_box.handle.clone(),
+                },
+            )
+            .await
+            .unwrap();
+        assert!(info.info.algorithm.is_some());
+        assert!(!serde_json::to_string(&info.info).unwrap().contains(&key));
+
+        let encrypt = || async {
+            client
+                .request_async::<_, ResultOfEncryptionBoxEncrypt>(
+                    "crypto.encryption_box_encrypt",
+                    ParamsOfEncryptionBoxEncrypt {
+                        encryption_box: encryption_box.handle.clone(),
+                        data: data.clone(),
+                    },
+                )
+                .await
+                .unwrap()
+                .data
+        };
+        let encrypted = encrypt().await;
+        assert_ne!(encrypted, data);
+        // every call uses a fresh nonce
+        assert_ne!(encrypt().await, encrypted);
+
+        // the box output is the nonce followed by the result of the corresponding crypto function
+        let encrypted_bytes = base64::decode(&encrypted).unwrap();
+        let nonce_size = match algorithm {
+            EncryptionAlgorithm::ChaCha20 { .. } => 12,
+            _ => 24,
+        };
+        let nonce = hex::encode(&encrypted_bytes[..nonce_size]);
+        let expected = match algorithm {
+            EncryptionAlgorithm::ChaCha20 { key } => client
+                .request::<_, ResultOfChaCha20>(
+                    "crypto.chacha20",
+                    ParamsOfChaCha20 { data: data.clone(), key, nonce },
+                )
+                .unwrap()
+                .data,
+            EncryptionAlgorithm::NaclSecretBox { key } => client
+                .request::<_, ResultOfNaclBox>(
+                    "crypto.nacl_secret_box",
+                    ParamsOfNaclSecretBox { decrypted: data.clone(), nonce, key },
+                )
+                .unwrap()
+                .encrypted,
+            EncryptionAlgorithm::NaclBox { their_public, secret } => client
+                .request::<_, ResultOfNaclBox>(
+                    "crypto.nacl_box",
+                    ParamsOfNaclBox { decrypted: data.clone(), nonce, their_public, secret },
+                )
+                .unwrap()
+                .encrypted,
+        };
+        assert_eq!(base64::encode(&encrypted_bytes[nonce_size..]), expected);
+
+        let decrypted: ResultOfEncryptionBoxDecrypt = client
+            .request_async(
+                "crypto.encryption_box_decrypt",
+                ParamsOfEncryptionBoxDecrypt {
+                    encryption_box: encryption_box.handle.clone(),
+                    data: encrypted,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(decrypted.data, data);
+
+        let error = client
+            .request_async::<_, ResultOfEncryptionBoxDecrypt>(
+                "crypto.encryption_box_decrypt",
+                ParamsOfEncryptionBoxDecrypt {
+                    encryption_box: encryption_box.handle.clone(),
+                    data: base64::encode(&encrypted_bytes[..nonce_size - 1]),
+                },
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidEncryptedData as u32);
+
+        let _: () = client
+            .request_async("crypto.remove_encryption_box", encryption_box.clone())
+            .await
+            .unwrap();
+        let error = client
+            .request_async::<_, ResultOfEncryptionBoxGetInfo>(
+                "crypto.encryption_box_get_info",
+                ParamsOfEncryptionBoxGetInfo {
+                    encryption_box: encryption_box.handle,
+                },
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(error.code, ErrorCode::EncryptionBoxNotRegistered as u32);
+    }
+}
+
+#[tokio::test(core_threads = 2)]
+async fn test_external_encryption_box() {
+    let client = std::sync::Arc::new(TestClient::new());
+    let client_copy = client.clone();
+
+    // external encryption box uses built-in box inside
+    let inner_box = client
+        .request_async::<_, RegisteredEncryptionBox>(
+            "crypto.create_encryption_box",
+            ParamsOfCreateEncryptionBox {
+                algorithm: EncryptionAlgorithm::NaclSecretBox { key: "01".repeat(32) },
+            },
+        )
+        .await
+        .unwrap()
+        .handle;
+    let inner_handle = inner_box.clone();
+
+    let callback = move |request: crate::client::ParamsOfAppRequest, _: u32| {
+        let client = client_copy.clone();
+        let encryption_box = inner_handle.clone();
+        tokio::spawn(async move {
+            let result = match serde_json::from_value(request.request_data).unwrap() {
+                ParamsOfAppEncryptionBox::GetInfo => ResultOfAppEncryptionBox::GetInfo {
+                    info: EncryptionBoxInfo {
+                        algorithm: Some("External".into()),
+                        ..Default::default()
+                    },
+                },
+                ParamsOfAppEncryptionBox::Encrypt { data } => ResultOfAppEncryptionBox::Encrypt {
+                    data: client
+                        .request_async::<_, ResultOfEncryptionBoxEncrypt>(
+                            "crypto.encryption_box_encrypt",
+                            ParamsOfEncryptionBoxEncrypt { encryption_box, data },
+                        )
+                        .await
+                        .unwrap()
+                        .data,
+                },
+                ParamsOfAppEncryptionBox::Decrypt { data } => ResultOfAppEncryptionBox::Decrypt {
+                    data: client
+                        .request_async::<_, ResultOfEncryptionBoxDecrypt>(
+                            "crypto.encryption_box_decrypt",
+                            ParamsOfEncryptionBoxDecrypt { encryption_box, data },
+                        )
+                        .await
+                        .unwrap()
+                        .data,
+                },
+            };
+            client.resolve_app_request(request.app_request_id, result).await;
+        });
+        futures::future::ready(())
+    };
+
+    let external_box: RegisteredEncryptionBox = client
+        .request_async_callback("crypto.register_encryption_box", (), callback)
+        .await
+        .unwrap();
+
+    let info: ResultOfEncryptionBoxGetInfo = client
+        .request_async(
+            "crypto.encryption_box_get_info",
+            ParamsOfEncryptionBoxGetInfo {
+                encryption_box: external_box.handle.clone(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(info.info.algorithm, Some("External".into()));
+
+    let data = base64::encode("Test Message");
+    let encrypted: ResultOfEncryptionBoxEncrypt = client
+        .request_async(
+            "crypto.encryption_box_encrypt",
+            ParamsOfEncryptionBoxEncrypt {
+                encryption_box: external_box.handle.clone(),
+                data: data.clone(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_ne!(encrypted.data, data);
+
+    // data encrypted by the external box is decrypted by the inner box and vice versa
+    let decrypted: ResultOfEncryptionBoxDecrypt = client
+        .request_async(
+            "crypto.encryption_box_decrypt",
+            ParamsOfEncryptionBoxDecrypt {
+                encryption_box: inner_box.clone(),
+                data: encrypted.data,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(decrypted.data, data);
+
+    let encrypted: ResultOfEncryptionBoxEncrypt = client
+        .request_async(
+            "crypto.encryption_box_encrypt",
+            ParamsOfEncryptionBoxEncrypt {
+                encryption_box: inner_box.clone(),
+                data: data.clone(),
+            },
+        )
+        .await
+        .unwrap();
+    let decrypted: ResultOfEncryptionBoxDecrypt = client
+        .request_async(
+            "crypto.encryption_box_decrypt",
+            ParamsOfEncryptionBoxDecrypt {
+                encryption_box: external_box.handle.clone(),
+                data: encrypted.data,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(decrypted.data, data);
+
+    for handle in vec![external_box.handle, inner_box] {
+        let _: () = client
+            .request_async("crypto.remove_encryption_box", RegisteredEncryptionBox { handle })
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test(core_threads = 2)]
+async fn test_threshold_signing_box() {
+    let client = TestClient::new();
+    let context = std::sync::Arc::new(crate::ClientContext::new(Default::default()).unwrap());
+    let keys = client.generate_sign_keys();
+
+    let shares = split_signing_key(&keys, 2, 3).unwrap();
+    let signers: Vec<Box<dyn ThresholdSigner + Send + Sync>> = vec![
+        Box::new(KeyShareSigner::new(&shares[2]).unwrap()),
+        Box::new(KeyShareSigner::new(&shares[0]).unwrap()),
+    ];
+    let signing_box = ThresholdSigningBox::new(shares[0].public_key, 2, signers).unwrap();
+    let handle = register_signing_box(context.clone(), signing_box)
+        .await
+        .unwrap()
+        .handle;
+
+    let pubkey = signing_box_get_public_key(
+        context.clone(),
+        RegisteredSigningBox { handle: handle.clone() },
+    )
+    .await
+    .unwrap()
+    .pubkey;
+    assert_eq!(pubkey, keys.public);
+
+    let unsigned = base64::encode("Test Message");
+    let signature = signing_box_sign(
+        context.clone(),
+        ParamsOfSigningBoxSign {
+            signing_box: handle,
+            unsigned: unsigned.clone(),
+        },
+    )
+    .await
+    .unwrap()
+    .signature;
+
+    let result: ResultOfNaclSignDetachedVerify = client
+        .request(
+            "crypto.nacl_sign_detached_verify",
+            ParamsOfNaclSignDetachedVerify {
+                unsigned,
+                signature,
+                public: keys.public,
+            },
+        )
+        .unwrap();
+    assert!(result.succeeded);
+
+    let signers: Vec<Box<dyn ThresholdSigner + Send + Sync>> =
+        vec![Box::new(KeyShareSigner::new(&shares[1]).unwrap())];
+    assert!(ThresholdSigningBox::new(shares[1].public_key, 2, signers).is_err());
+}
+
+#[cfg(unix)]
+#[tokio::test(core_threads = 2)]
+async fn test_agent_signing_box() {
+    use std::io::{BufRead, BufReader, Write};
+
+    let client = TestClient::new();
+    let context = std::sync::Arc::new(crate::ClientContext::new(Default::default()).unwrap());
+    let keys = client.generate_sign_keys();
+
+    let path = std::env::temp_dir().join(format!("ton_client_agent_{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let listener = std::os::unix::net::UnixListener::bind(&path).unwrap();
+    let agent_keys = keys.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut request = String::new();
+            BufReader::new(&stream).read_line(&mut request).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            let response = match request["method"].as_str().unwrap() {
+                "get_public_key" => json!({ "result": { "public_key": agent_keys.public } }),
+                "sign" => {
+                    let unsigned = base64::decode(request["unsigned"].as_str().unwrap()).unwrap();
+                    let keys = agent_keys.decode().unwrap();
+                    let signature = internal::sign_using_keys(&unsigned, &keys).unwrap().1;
+                    json!({ "result": { "signature": hex::encode(signature) } })
+                }
+                _ => json!({ "error": "unknown method" }),
+            };
+            writeln!(stream, "{}", response).unwrap();
+        }
+    });
+
+    let handle = register_signing_box(context.clone(), AgentSigningBox::new(&path))
+        .await
+        .unwrap()
+        .handle;
+
+    let pubkey = signing_box_get_public_key(
+        context.clone(),
+        RegisteredSigningBox { handle: handle.clone() },
+    )
+    .await
+    .unwrap()
+    .pubkey;
+    assert_eq!(pubkey, keys.public);
+
+    let unsigned = base64::encode("Test Message");
+    let signature = signing_box_sign(
+        context.clone(),
+        ParamsOfSigningBoxSign {
+            signing_box: handle,
+            unsigned: unsigned.clone(),
+        },
+    )
+    .await
+    .unwrap()
+    .signature;
+
+    let keys_sign: ResultOfSign = client
+        .request("crypto.sign", ParamsOfSign { unsigned, keys })
+        .unwrap();
+    assert_eq!(signature, keys_sign.signature);
+
+    let _ = std::fs::remove_file(&path);
+
+    // agent which never responds
+    let silent_path = std::env::temp_dir()
+        .join(format!("ton_client_silent_agent_{}.sock", std::process::id()));
+    let _ = std::fs::remove_file(&silent_path);
+    let listener = std::os::unix::net::UnixListener::bind(&silent_path).unwrap();
+    std::thread::spawn(move || {
+        let _streams: Vec<_> = listener.incoming().collect();
+    });
+    let handle = register_signing_box(
+        context.clone(),
+        AgentSigningBox::new(&silent_path).with_timeout(std::time::Duration::from_millis(100)),
+    )
+    .await
+    .unwrap()
+    .handle;
+    let error = signing_box_get_public_key(context.clone(), RegisteredSigningBox { handle })
+        .await
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::SigningBackendFailed as u32);
+
+    let _ = std::fs::remove_file(&silent_path);
+}
+
+/// Runs against SoftHSM or another PKCS#11 token with an Ed25519 key pair, e.g.:
+///
+/// ```sh
+/// softhsm2-util --init-token --free --label ton --pin 1234 --so-pin 1234
+/// pkcs11-tool --module /usr/lib/softhsm/libsofthsm2.so --token-label ton --login --pin 1234 \
+///     --keypairgen --key-type EC:edwards25519 --label ton-key
+/// PKCS11_MODULE_PATH=/usr/lib/softhsm/libsofthsm2.so cargo test --features pkcs11 test_pkcs11
+/// ```
+///
+/// Skipped if `PKCS11_MODULE_PATH` is not set. Token, PIN and key label can be
+/// overridden with `PKCS11_TOKEN_LABEL`, `PKCS11_PIN` and `PKCS11_KEY_LABEL`.
+#[cfg(feature = "pkcs11")]
+#[tokio::test(core_threads = 2)]
+async fn test_pkcs11_signing_box() {
+    let module_path = match std::env::var("PKCS11_MODULE_PATH") {
+        Ok(path) => path,
+        Err(_) => {
+            println!("PKCS11_MODULE_PATH is not set, test skipped");
+            return;
+        }
+    };
+    let env = |name: &str, default: &str| std::env::var(name).unwrap_or_else(|_| default.into());
+    let signing_box = Pkcs11SigningBox::open(&Pkcs11Config {
+        module_path,
+        token_label: env("PKCS11_TOKEN_LABEL", "ton"),
+        pin: env("PKCS11_PIN", "1234"),
+        key_label: env("PKCS11_KEY_LABEL", "ton-key"),
+    })
+    .unwrap();
+
+    let client = TestClient::new();
+    let context = std::sync::Arc::new(crate::ClientContext::new(Default::default()).unwrap());
+    let handle = register_signing_box(context.clone(), signing_box)
+        .await
+        .unwrap()
+        .handle;
+
+    let pubkey = signing_box_get_public_key(
+        context.clone(),
+        RegisteredSigningBox { handle: handle.clone() },
+    )
+    .await
+    .unwrap()
+    .pubkey;
+    assert_eq!(pubkey.len(), 64);
+
+    let unsigned = base64::encode("Test Message");
+    let signature = signing_box_sign(
+        context.clone(),
+        ParamsOfSigningBoxSign {
+            signing_box: handle,
+            unsigned: unsigned.clone(),
+        },
+    )
+    .await
+    .unwrap()
+    .signature;
+
+    let verified: ResultOfNaclSignDetachedVerify = client
+        .request(
+            "crypto.nacl_sign_detached_verify",
+            ParamsOfNaclSignDetachedVerify {
+                unsigned,
+                signature,
+                public: pubkey,
+            },
+        )
+        .unwrap();
+    assert!(verified.succeeded);
+}
+
 #[test]
 fn test_strip_secret() {
     assert_eq!(strip_secret(""), r#""""#);
@@ -879,3 +1764,253 @@ fn test_debug_keypair_secret_stripped() {
         }"
     )
 }
+
+#[tokio::test(core_threads = 2)]
+async fn test_keystore() {
+    let client = TestClient::new();
+    let password = base64::encode("Test Password");
+    let keys = client.generate_sign_keys();
+    let phrase =
+        "abuse boss fly battle rubber wasp afraid hamster guide essence vibrant tattoo".to_string();
+
+    let keystore = client
+        .request::<_, ResultOfKeystore>(
+            "crypto.keystore_create",
+            ParamsOfKeystoreCreate {
+                password: password.clone(),
+                kdf: Some(KeystoreKdfParams { log_n: 10, r: 8, p: 1 }),
+            },
+        )
+        .unwrap()
+        .keystore;
+
+    let import = |keystore: &String, password: &String, name: &str, secret: KeystoreSecret| {
+        client.request::<_, ResultOfKeystore>(
+            "crypto.keystore_import",
+            ParamsOfKeystoreImport {
+                keystore: keystore.clone(),
+                password: password.clone(),
+                name: name.into(),
+                secret,
+            },
+        )
+    };
+    let keys_secret = KeystoreSecret::KeyPair { keys: keys.clone() };
+    let keystore = import(&keystore, &password, "keys", keys_secret.clone())
+        .unwrap()
+        .keystore;
+    let keystore = import(
+        &keystore,
+        &password,
+        "seed",
+        KeystoreSecret::Mnemonic {
+            phrase: phrase.clone(),
+            path: None,
+            dictionary: None,
+            word_count: None,
+        },
+    )
+    .unwrap()
+    .keystore;
+    let keystore = import(
+        &keystore,
+        &password,
+        "xprv",
+        KeystoreSecret::XPrv {
+            xprv: "xprv9s21ZrQH143K25JhKqEwvJW7QAiVvkmi4WRenBZanA6kxHKtKAQQKwZG65kCyW5jWJ8NY9e3GkRoistUjjcpHNsGBUv94istDPXvqGNuWpC".into(),
+            path: None,
+        },
+    )
+    .unwrap()
+    .keystore;
+    assert!(!keystore.contains(&keys.secret));
+    assert!(!keystore.contains("abuse"));
+
+    let error = import(&keystore, &password, "keys", keys_secret.clone()).unwrap_err();
+    assert_eq!(error.code, ErrorCode::KeystoreEntryAlreadyExists as u32);
+    let error = import(
+        &keystore,
+        &base64::encode("Wrong Password"),
+        "other",
+        keys_secret,
+    )
+    .unwrap_err();
+    assert_eq!(error.code, ErrorCode::KeystoreInvalidPassword as u32);
+
+    let seed_keys: KeyPair = client
+        .request(
+            "crypto.mnemonic_derive_sign_keys",
+            ParamsOfMnemonicDeriveSignKeys {
+                phrase: phrase.clone(),
+                path: None,
+                dictionary: None,
+                word_count: None,
+            },
+        )
+        .unwrap();
+    let list: ResultOfKeystoreList = client
+        .request(
+            "crypto.keystore_list",
+            ParamsOfKeystoreList {
+                keystore: keystore.clone(),
+                password: Some(password.clone()),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        list.entries,
+        vec![
+            KeystoreEntryInfo {
+                name: "keys".into(),
+                entry_type: KeystoreEntryType::KeyPair,
+                public: keys.public.clone(),
+            },
+            KeystoreEntryInfo {
+                name: "seed".into(),
+                entry_type: KeystoreEntryType::Mnemonic,
+                public: seed_keys.public.clone(),
+            },
+            KeystoreEntryInfo {
+                name: "xprv".into(),
+                entry_type: KeystoreEntryType::XPrv,
+                public: "7b70008d0c40992283d488b1046739cf827afeabf647a5f07c4ad1e7e45a6f89".into(),
+            },
+        ]
+    );
+
+    let entry = |name: &str| ParamsOfKeystoreEntry {
+        keystore: keystore.clone(),
+        password: password.clone(),
+        name: name.into(),
+    };
+    let exported: ResultOfKeystoreExport =
+        client.request("crypto.keystore_export", entry("seed")).unwrap();
+    match exported.secret {
+        KeystoreSecret::Mnemonic {
+            phrase: exported_phrase,
+            path,
+            dictionary,
+            word_count,
+        } => {
+            assert_eq!(exported_phrase, phrase);
+            assert_eq!(path, Some(default_hdkey_derivation_path()));
+            assert_eq!(dictionary, Some(default_mnemonic_dictionary()));
+            assert_eq!(word_count, Some(default_mnemonic_word_count()));
+        }
+        _ => panic!("Mnemonic secret expected"),
+    }
+
+    let signing_box: RegisteredSigningBox = client
+        .request_async("crypto.keystore_unlock", entry("keys"))
+        .await
+        .unwrap();
+    let box_pubkey: ResultOfSigningBoxGetPublicKey = client
+        .request_async("crypto.signing_box_get_public_key", signing_box.clone())
+        .await
+        .unwrap();
+    assert_eq!(box_pubkey.pubkey, keys.public);
+    let unsigned = base64::encode("Test Message");
+    let box_sign: ResultOfSigningBoxSign = client
+        .request_async(
+            "crypto.signing_box_sign",
+            ParamsOfSigningBoxSign {
+                signing_box: signing_box.handle.clone(),
+                unsigned: unsigned.clone(),
+            },
+        )
+        .await
+        .unwrap();
+    let keys_sign: ResultOfSign = client
+        .request("crypto.sign", ParamsOfSign { unsigned, keys })
+        .unwrap();
+    assert_eq!(box_sign.signature, keys_sign.signature);
+
+    let keystore = client
+        .request::<_, ResultOfKeystore>("crypto.keystore_delete", entry("keys"))
+        .unwrap()
+        .keystore;
+    let error = client
+        .request::<_, ResultOfKeystoreExport>(
+            "crypto.keystore_export",
+            ParamsOfKeystoreEntry {
+                keystore: keystore.clone(),
+                password: password.clone(),
+                name: "keys".into(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::KeystoreEntryNotFound as u32);
+
+    let mut unsupported: serde_json::Value = serde_json::from_str(&keystore).unwrap();
+    unsupported["version"] = 2.into();
+    let error = client
+        .request::<_, ResultOfKeystoreList>(
+            "crypto.keystore_list",
+            ParamsOfKeystoreList {
+                keystore: unsupported.to_string(),
+                password: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidKeystore as u32);
+
+    let mut expensive: serde_json::Value = serde_json::from_str(&keystore).unwrap();
+    expensive["kdf"]["log_n"] = 30.into();
+    let error = client
+        .request::<_, ResultOfKeystoreList>(
+            "crypto.keystore_list",
+            ParamsOfKeystoreList {
+                keystore: expensive.to_string(),
+                password: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidKeystore as u32);
+    for kdf in vec![
+        KeystoreKdfParams { log_n: 21, r: 8, p: 1 },
+        KeystoreKdfParams { log_n: 10, r: 33, p: 1 },
+        KeystoreKdfParams { log_n: 10, r: 8, p: 17 },
+        KeystoreKdfParams { log_n: 20, r: 32, p: 1 },
+        KeystoreKdfParams { log_n: 10, r: 8, p: 16 },
+    ] {
+        let error = client
+            .request::<_, ResultOfKeystore>(
+                "crypto.keystore_create",
+                ParamsOfKeystoreCreate {
+                    password: password.clone(),
+                    kdf: Some(kdf),
+                },
+            )
+            .unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidKeystore as u32);
+    }
+
+    // entries "seed" and "xprv" with swapped names
+    let mut swapped: serde_json::Value = serde_json::from_str(&keystore).unwrap();
+    swapped["entries"][0]["name"] = "xprv".into();
+    swapped["entries"][1]["name"] = "seed".into();
+    let error = client
+        .request::<_, ResultOfKeystoreExport>(
+            "crypto.keystore_export",
+            ParamsOfKeystoreEntry {
+                keystore: swapped.to_string(),
+                password: password.clone(),
+                name: "seed".into(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidKeystore as u32);
+
+    let mut altered: serde_json::Value = serde_json::from_str(&keystore).unwrap();
+    altered["entries"][0]["public"] = "00".repeat(32).into();
+    let error = client
+        .request::<_, ResultOfKeystoreList>(
+            "crypto.keystore_list",
+            ParamsOfKeystoreList {
+                keystore: altered.to_string(),
+                password: Some(password.clone()),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidKeystore as u32);
+}
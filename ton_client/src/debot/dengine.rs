@@ -11,9 +11,10 @@ use super::routines;
 use super::run_output::RunOutput;
 use super::{JsonValue, TonClient, DInfo};
 use crate::abi::{
-    decode_message_body, encode_message, encode_message_body, Abi, CallSet, DeploySet,
+    decode_message_body, encode_message, encode_message_body, Abi, AbiHandle, CallSet, DeploySet,
     ErrorCode, ParamsOfDecodeMessageBody, ParamsOfEncodeMessage, ParamsOfEncodeMessageBody, Signer,
 };
+use crate::abi::registry::ParsedAbi;
 use crate::boc::internal::{deserialize_cell_from_base64};
 use crate::crypto::{remove_signing_box, RegisteredSigningBox, SigningBoxHandle};
 use crate::encoding::{decode_abi_number};
@@ -24,7 +25,7 @@ use crate::tvm::{run_tvm, ParamsOfRunTvm};
 use crate::{ClientConfig, ClientContext};
 use std::collections::VecDeque;
 use std::sync::Arc;
-use ton_abi::Contract;
+use ton_abi::param_type::ParamType;
 use super::dinterface::{BuiltinInterfaces, DebotInterfaceExecutor};
 use super::DEBOT_WC;
 use super::helpers::build_internal_message;
@@ -52,6 +53,17 @@ fn load_abi(abi: &str) -> Result<Abi, String> {
     ))
 }
 
+/// Registers debot ABI in the client context, so it is parsed only once
+/// and all the ABI functions take it by handle.
+fn register_abi(ton: &TonClient, abi: &str) -> Result<(Abi, Arc<ParsedAbi>), String> {
+    let parsed = Arc::new(
+        ParsedAbi::from_json(abi.to_owned()).map_err(|e| format!("failed to parse abi: {}", e))?,
+    );
+    let handle = ton.get_next_id();
+    ton.abis.insert(handle, parsed.clone());
+    Ok((Abi::Handle(AbiHandle(handle)), parsed))
+}
+
 // TODO: implement address validation
 pub fn load_ton_address(addr: &str) -> Result<String, String> {
     Ok(addr.to_owned())
@@ -65,8 +77,8 @@ const OPTION_TARGET_ADDR: u8 = 4;
 /// Downloads and stores debot, executes its actions and calls
 /// Debot Browser callbacks.
 pub struct DEngine {
-    raw_abi: String,
     abi: Abi,
+    parsed_abi: Arc<ParsedAbi>,
     addr: String,
     ton: TonClient,
     state: String,
@@ -96,13 +108,10 @@ impl DEngine {
         ton: TonClient,
         browser: Arc<dyn BrowserCallbacks + Send + Sync>,
     ) -> Self {
-        let abi = abi
-            .map(|s| load_abi(&s))
-            .unwrap_or(load_abi(DEBOT_ABI))
-            .unwrap();
+        let (abi, parsed_abi) = register_abi(&ton, abi.as_deref().unwrap_or(DEBOT_ABI)).unwrap();
         DEngine {
-            raw_abi: String::new(),
             abi,
+            parsed_abi,
             addr,
             ton: ton.clone(),
             state: String::new(),
@@ -188,8 +197,10 @@ impl DEngine {
         self.state = Self::load_state(self.ton.clone(), self.addr.clone()).await?;
         self.info = Self::fetch_info(self.ton.clone(), self.addr.clone(), self.state.clone()).await?;
         if let Some(dabi) = self.info.dabi.as_ref() {
-            self.raw_abi = dabi.clone();
-            self.abi = load_abi(&self.raw_abi)?;
+            let (abi, parsed_abi) = register_abi(&self.ton, dabi)?;
+            self.unregister_abi();
+            self.abi = abi;
+            self.parsed_abi = parsed_abi;
             self.builtin_interfaces.add(
                 Arc::new(MsgInterface::new(
                     self.ton.clone(),
@@ -199,7 +210,7 @@ impl DEngine {
                 ))
             );
             self.builtin_interfaces.add(
-                Arc::new(JsonInterface::new(&self.raw_abi))
+                Arc::new(JsonInterface::new(self.parsed_abi.clone()))
             );
         }
         self.update_options().await?;
@@ -262,9 +273,7 @@ impl DEngine {
         params: JsonValue,
     ) -> ClientResult<RunOutput> {
         debug!("send from {} id = {} params = {}", source, func_id, params);
-        let abi = Contract::load(self.raw_abi.as_bytes())
-            .map_err(|e| Error::invalid_debot_abi(e.to_string()))?;
-        let func_name = &abi.function_by_id(func_id, true)
+        let func_name = &self.parsed_abi.contract.function_by_id(func_id, true)
             .map_err(|e| Error::invalid_function_id(e) )?
             .name;
 
@@ -653,27 +662,20 @@ impl DEngine {
         let args: Option<JsonValue> = if act.misc != EMPTY_CELL {
             Some(json!({ "misc": act.misc }).into())
         } else {
-            let abi_json: serde_json::Value = if let Abi::Contract(ref abi_obj) = self.abi {
-                serde_json::from_str(&serde_json::to_string(&abi_obj).unwrap()).unwrap()
-            } else {
-                json!({})
-            };
-            let functions = abi_json["functions"].as_array().unwrap();
-            let func = functions
-                .iter()
-                .find(|f| f["name"].as_str().unwrap() == act.name)
-                .ok_or(format!("action not found"))?;
-            let arguments = func["inputs"].as_array().unwrap();
+            let func = self
+                .parsed_abi
+                .contract
+                .function(&act.name)
+                .map_err(|_| format!("action not found"))?;
             let mut args_json = json!({});
-            for arg in arguments {
-                let arg_name = arg["name"].as_str().unwrap();
+            for arg in &func.inputs {
                 let prompt = "".to_owned();
                 let mut value = String::new();
                 self.browser.input(&prompt, &mut value).await;
-                if arg["type"].as_str().unwrap() == "bytes" {
+                if arg.kind == ParamType::Bytes {
                     value = hex::encode(value.as_bytes());
                 }
-                args_json[arg_name] = json!(&value);
+                args_json[&arg.name] = json!(&value);
             }
             Some(args_json.into())
         };
@@ -788,6 +790,7 @@ impl DEngine {
             ParamsOfProcessMessage {
                 message_encode_params: call_params,
                 send_events: true,
+                send_state_events: None,
             },
             callback,
         )
@@ -901,4 +904,16 @@ impl DEngine {
             err.message
         }
     }
+
+    fn unregister_abi(&self) {
+        if let Abi::Handle(handle) = &self.abi {
+            self.ton.abis.remove(&handle.0);
+        }
+    }
+}
+
+impl Drop for DEngine {
+    fn drop(&mut self) {
+        self.unregister_abi();
+    }
 }
@@ -2,8 +2,10 @@ use super::dinterface::{
     decode_answer_id, get_string_arg, DebotInterface, InterfaceResult,
 };
 use crate::abi::Abi;
+use crate::abi::registry::ParsedAbi;
 use serde_json::Value;
-use ton_abi::{Contract, param_type::ParamType, Param};
+use std::sync::Arc;
+use ton_abi::{param_type::ParamType, Param};
 
 const ABI: &str = r#"
 {
@@ -31,12 +33,12 @@ const ABI: &str = r#"
 const BASE64_ID: &str = "442288826041d564ccedc579674f17c1b0a3452df799656a9167a41ab270ec19";
 
 pub struct JsonInterface {
-    debot_abi: String,
+    debot_abi: Arc<ParsedAbi>,
 }
 
 impl JsonInterface {
-    pub fn new(abi: &str) -> Self {
-        Self { debot_abi: abi.to_owned() }
+    pub(crate) fn new(abi: Arc<ParsedAbi>) -> Self {
+        Self { debot_abi: abi }
     }
 
     fn deserialize(&self, args: &Value) -> InterfaceResult {
@@ -55,9 +57,7 @@ impl JsonInterface {
     }
 
     fn deserialize_json(&self, json_obj: &mut Value, answer_id: u32) -> Result<(), String> {
-        let contract = Contract::load(self.debot_abi.as_bytes())
-            .map_err(|e| format!("{}", e))?;
-        let func = contract.function_by_id(answer_id, true)
+        let func = self.debot_abi.contract.function_by_id(answer_id, true)
             .map_err(|_| format!("function with id {} not found", answer_id))?;
         let obj = func.inputs.iter().find(|e| e.name == "obj").ok_or(format!("\"obj\" argument not found"))?;
         if let ParamType::Tuple(params) = &obj.kind {
@@ -14,7 +14,9 @@
 
  use crate::client::{AppObject, ClientContext, Error};
  use crate::error::ClientResult;
- use crate::crypto::{RegisteredSigningBox, SigningBox};
+ use crate::crypto::{
+     EncryptionBox, EncryptionBoxInfo, RegisteredEncryptionBox, RegisteredSigningBox, SigningBox,
+ };
 
 /// Signing box callbacks.
 #[derive(Serialize, Deserialize, Clone, Debug, ApiType, PartialEq)]
@@ -92,3 +94,101 @@ pub(crate) async fn register_signing_box(
 ) -> ClientResult<RegisteredSigningBox> {
     crate::crypto::register_signing_box(context, ExternalSigningBox::new(app_object)).await
 }
+
+/// Encryption box callbacks.
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, PartialEq)]
+#[serde(tag="type")]
+pub enum ParamsOfAppEncryptionBox {
+    /// Get encryption box info
+    GetInfo,
+    /// Encrypt data
+    Encrypt {
+        /// Data, encoded in Base64
+        data: String,
+    },
+    /// Decrypt data
+    Decrypt {
+        /// Data, encoded in Base64
+        data: String,
+    },
+}
+
+/// Returning values from encryption box callbacks.
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, PartialEq)]
+#[serde(tag="type")]
+pub enum ResultOfAppEncryptionBox {
+    /// Result of getting encryption box info
+    GetInfo {
+        info: EncryptionBoxInfo,
+    },
+    /// Result of encrypting data
+    Encrypt {
+        /// Encrypted data, encoded in Base64
+        data: String,
+    },
+    /// Result of decrypting data
+    Decrypt {
+        /// Decrypted data, encoded in Base64
+        data: String,
+    },
+}
+
+struct ExternalEncryptionBox {
+    app_object: AppObject<ParamsOfAppEncryptionBox, ResultOfAppEncryptionBox>,
+}
+
+impl ExternalEncryptionBox {
+    pub fn new(app_object: AppObject<ParamsOfAppEncryptionBox, ResultOfAppEncryptionBox>) -> Self {
+        Self { app_object }
+    }
+}
+
+#[async_trait::async_trait]
+impl EncryptionBox for ExternalEncryptionBox {
+    async fn get_info(&self) -> ClientResult<EncryptionBoxInfo> {
+        let response = self.app_object.call(ParamsOfAppEncryptionBox::GetInfo).await?;
+
+        match response {
+            ResultOfAppEncryptionBox::GetInfo { info } => Ok(info),
+            _ => Err(Error::unexpected_callback_response(
+                "EncryptionBoxGetInfo", &response))
+        }
+    }
+
+    async fn encrypt(&self, data: &[u8]) -> ClientResult<Vec<u8>> {
+        let response = self.app_object.call(ParamsOfAppEncryptionBox::Encrypt {
+            data: base64::encode(data)
+        }).await?;
+
+        match response {
+            ResultOfAppEncryptionBox::Encrypt { data } => {
+               crate::encoding::base64_decode(&data)
+            },
+            _ => Err(Error::unexpected_callback_response(
+                "EncryptionBoxEncrypt", &response))
+        }
+    }
+
+    async fn decrypt(&self, data: &[u8]) -> ClientResult<Vec<u8>> {
+        let response = self.app_object.call(ParamsOfAppEncryptionBox::Decrypt {
+            data: base64::encode(data)
+        }).await?;
+
+        match response {
+            ResultOfAppEncryptionBox::Decrypt { data } => {
+               crate::encoding::base64_decode(&data)
+            },
+            _ => Err(Error::unexpected_callback_response(
+                "EncryptionBoxDecrypt", &response))
+        }
+    }
+}
+
+/// Register an application implemented encryption box.
+#[api_function]
+pub(crate) async fn register_encryption_box(
+    context: std::sync::Arc<ClientContext>,
+    app_object: AppObject<ParamsOfAppEncryptionBox, ResultOfAppEncryptionBox>,
+) -> ClientResult<RegisteredEncryptionBox> {
+    crate::crypto::register_encryption_box(context, ExternalEncryptionBox::new(app_object)).await
+}
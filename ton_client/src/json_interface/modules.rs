@@ -57,6 +57,11 @@ fn register_crypto(handlers: &mut RuntimeHandlers) {
 
     module.register_error_code::<crate::crypto::ErrorCode>();
     module.register_type::<crate::crypto::SigningBoxHandle>();
+    module.register_type::<crate::crypto::EncryptionBoxHandle>();
+    module.register_type::<crate::crypto::KeystoreKdfParams>();
+    module.register_type::<crate::crypto::KeystoreEntryType>();
+    module.register_type::<crate::crypto::KeystoreSecret>();
+    module.register_type::<crate::crypto::KeystoreEntryInfo>();
 
     // Math
 
@@ -92,6 +97,14 @@ fn register_crypto(handlers: &mut RuntimeHandlers) {
 
     module.register_sync_fn(crate::crypto::sha256, crate::crypto::hash::sha256_api);
     module.register_sync_fn(crate::crypto::sha512, crate::crypto::hash::sha512_api);
+    module.register_sync_fn(
+        crate::crypto::keccak256,
+        crate::crypto::hash::keccak256_api,
+    );
+    module.register_sync_fn(
+        crate::crypto::ripemd160,
+        crate::crypto::hash::ripemd160_api,
+    );
 
     // Scrypt
 
@@ -187,6 +200,56 @@ fn register_crypto(handlers: &mut RuntimeHandlers) {
         crate::crypto::hdkey_public_from_xprv,
         crate::crypto::hdkey::hdkey_public_from_xprv_api,
     );
+    module.register_sync_fn(
+        crate::crypto::hdkey_xpub_from_xprv,
+        crate::crypto::hdkey::hdkey_xpub_from_xprv_api,
+    );
+    module.register_sync_fn(
+        crate::crypto::hdkey_derive_from_xpub,
+        crate::crypto::hdkey::hdkey_derive_from_xpub_api,
+    );
+    module.register_sync_fn(
+        crate::crypto::hdkey_public_from_xpub,
+        crate::crypto::hdkey::hdkey_public_from_xpub_api,
+    );
+    module.register_sync_fn(
+        crate::crypto::hdkey_slip10_keys_from_mnemonic,
+        crate::crypto::hdkey::hdkey_slip10_keys_from_mnemonic_api,
+    );
+
+    // secp256k1
+
+    module.register_sync_fn(
+        crate::crypto::secp256k1_keypair_from_secret,
+        crate::crypto::secp256k1_keys::secp256k1_keypair_from_secret_api,
+    );
+    module.register_sync_fn(
+        crate::crypto::secp256k1_generate_keypair,
+        crate::crypto::secp256k1_keys::secp256k1_generate_keypair_api,
+    );
+    module.register_sync_fn(
+        crate::crypto::secp256k1_sign,
+        crate::crypto::secp256k1_keys::secp256k1_sign_api,
+    );
+    module.register_sync_fn(
+        crate::crypto::secp256k1_verify,
+        crate::crypto::secp256k1_keys::secp256k1_verify_api,
+    );
+    module.register_sync_fn(
+        crate::crypto::secp256k1_recover_public_key,
+        crate::crypto::secp256k1_keys::secp256k1_recover_public_key_api,
+    );
+
+    // Secret sharing
+
+    module.register_sync_fn(
+        crate::crypto::secret_split,
+        crate::crypto::secret_sharing::secret_split_api,
+    );
+    module.register_sync_fn(
+        crate::crypto::secret_combine,
+        crate::crypto::secret_sharing::secret_combine_api,
+    );
 
     // Encryption
 
@@ -195,6 +258,33 @@ fn register_crypto(handlers: &mut RuntimeHandlers) {
         crate::crypto::encryption::chacha20_api,
     );
 
+    // Keystore
+
+    module.register_sync_fn(
+        crate::crypto::keystore_create,
+        crate::crypto::keystore::keystore_create_api,
+    );
+    module.register_sync_fn(
+        crate::crypto::keystore_import,
+        crate::crypto::keystore::keystore_import_api,
+    );
+    module.register_sync_fn(
+        crate::crypto::keystore_export,
+        crate::crypto::keystore::keystore_export_api,
+    );
+    module.register_async_fn(
+        crate::crypto::keystore_unlock,
+        crate::crypto::keystore::keystore_unlock_api,
+    );
+    module.register_sync_fn(
+        crate::crypto::keystore_list,
+        crate::crypto::keystore::keystore_list_api,
+    );
+    module.register_sync_fn(
+        crate::crypto::keystore_delete,
+        crate::crypto::keystore::keystore_delete_api,
+    );
+
     // Boxes
 
     module.register_async_fn_with_app_object_no_args(
@@ -217,6 +307,30 @@ fn register_crypto(handlers: &mut RuntimeHandlers) {
         crate::crypto::remove_signing_box,
         crate::crypto::boxes::remove_signing_box_api,
     );
+    module.register_async_fn_with_app_object_no_args(
+        super::crypto::register_encryption_box,
+        super::crypto::register_encryption_box_api,
+    );
+    module.register_async_fn(
+        crate::crypto::create_encryption_box,
+        crate::crypto::boxes::create_encryption_box_api,
+    );
+    module.register_sync_fn(
+        crate::crypto::remove_encryption_box,
+        crate::crypto::boxes::remove_encryption_box_api,
+    );
+    module.register_async_fn(
+        crate::crypto::encryption_box_get_info,
+        crate::crypto::boxes::encryption_box_get_info_api,
+    );
+    module.register_async_fn(
+        crate::crypto::encryption_box_encrypt,
+        crate::crypto::boxes::encryption_box_encrypt_api,
+    );
+    module.register_async_fn(
+        crate::crypto::encryption_box_decrypt,
+        crate::crypto::boxes::encryption_box_decrypt_api,
+    );
 
     module.register();
 }
@@ -245,6 +359,14 @@ fn register_abi(handlers: &mut RuntimeHandlers) {
     module.register_type::<crate::abi::AbiData>();
     module.register_type::<crate::abi::AbiFunction>();
     module.register_type::<crate::abi::AbiContract>();
+    module.register_type::<crate::abi::SigningEnvelopeEntry>();
+    module.register_type::<crate::abi::SigningEnvelope>();
+    module.register_type::<crate::abi::SignedEnvelopeMessage>();
+    module.register_type::<crate::abi::RegisteredAbi>();
+    module.register_type::<crate::abi::KnownAbi>();
+    module.register_type::<crate::abi::InputValidationError>();
+    module.register_type::<crate::abi::IdentifiedMessageBody>();
+    module.register_type::<crate::abi::MatchedMessageBody>();
 
     module.register_async_fn(
         crate::abi::encode_message_body,
@@ -278,6 +400,78 @@ fn register_abi(handlers: &mut RuntimeHandlers) {
         crate::abi::encode_account,
         crate::abi::encode_account::encode_account_api,
     );
+    module.register_async_fn(
+        crate::abi::register_abi,
+        crate::abi::registry::register_abi_api,
+    );
+    module.register_sync_fn(
+        crate::abi::unregister_abi,
+        crate::abi::registry::unregister_abi_api,
+    );
+    module.register_async_fn(
+        crate::abi::decode_account_data,
+        crate::abi::decode_data::decode_account_data_api,
+    );
+    module.register_async_fn(
+        crate::abi::update_initial_data,
+        crate::abi::init_data::update_initial_data_api,
+    );
+    module.register_async_fn(
+        crate::abi::decode_initial_data,
+        crate::abi::init_data::decode_initial_data_api,
+    );
+    module.register_async_fn(
+        crate::abi::get_function_schema,
+        crate::abi::schema::get_function_schema_api,
+    );
+    module.register_async_fn(
+        crate::abi::validate_input,
+        crate::abi::schema::validate_input_api,
+    );
+    module.register_async_fn(
+        crate::abi::get_function_id,
+        crate::abi::signature::get_function_id_api,
+    );
+    module.register_async_fn(
+        crate::abi::get_signature,
+        crate::abi::signature::get_signature_api,
+    );
+    module.register_async_fn(
+        crate::abi::identify_message_body,
+        crate::abi::signature::identify_message_body_api,
+    );
+    module.register_async_fn(
+        crate::abi::decode_message_multi,
+        crate::abi::decode_multi::decode_message_multi_api,
+    );
+    module.register_async_fn(
+        crate::abi::decode_transaction_multi,
+        crate::abi::decode_multi::decode_transaction_multi_api,
+    );
+    module.register_async_fn(
+        crate::abi::decode_transaction,
+        crate::abi::decode_transaction::decode_transaction_api,
+    );
+    module.register_async_fn(
+        crate::abi::decode_transaction_tree,
+        crate::abi::decode_transaction::decode_transaction_tree_api,
+    );
+    module.register_async_fn(
+        crate::abi::create_signing_envelope,
+        crate::abi::signing_envelope::create_signing_envelope_api,
+    );
+    module.register_async_fn(
+        crate::abi::sign_envelope,
+        crate::abi::signing_envelope::sign_envelope_api,
+    );
+    module.register_async_fn(
+        crate::abi::add_envelope_signature,
+        crate::abi::signing_envelope::add_envelope_signature_api,
+    );
+    module.register_async_fn(
+        crate::abi::finalize_envelope,
+        crate::abi::signing_envelope::finalize_envelope_api,
+    );
     module.register();
 }
 
@@ -307,6 +501,7 @@ fn register_boc(handlers: &mut RuntimeHandlers) {
         crate::boc::parse_shardstate,
         crate::boc::parse::parse_shardstate_api,
     );
+    module.register_async_fn(crate::boc::parse_tlb, crate::boc::tlb::parse_tlb_api);
     module.register_async_fn(
         crate::boc::get_blockchain_config,
         crate::boc::blockchain_config::get_blockchain_config_api,
@@ -333,6 +528,34 @@ fn register_boc(handlers: &mut RuntimeHandlers) {
         crate::boc::encode_boc,
         crate::boc::encode::encode_boc_api,
     );
+    module.register_async_fn(
+        crate::boc::query_cell,
+        crate::boc::reader::query_cell_api,
+    );
+    module.register_async_fn(
+        crate::boc::encode_message,
+        crate::boc::encode_parsed::encode_message_api,
+    );
+    module.register_async_fn(
+        crate::boc::encode_account,
+        crate::boc::encode_parsed::encode_account_api,
+    );
+    module.register_async_fn(
+        crate::boc::encode_state_init,
+        crate::boc::encode_parsed::encode_state_init_api,
+    );
+    module.register_async_fn(
+        crate::boc::create_merkle_proof,
+        crate::boc::merkle::create_merkle_proof_api,
+    );
+    module.register_async_fn(
+        crate::boc::verify_merkle_proof,
+        crate::boc::merkle::verify_merkle_proof_api,
+    );
+    module.register_async_fn(
+        crate::boc::apply_merkle_update,
+        crate::boc::merkle::apply_merkle_update_api,
+    );
     module.register();
 }
 
@@ -352,6 +575,7 @@ fn register_net(handlers: &mut RuntimeHandlers) {
     module.register_type::<crate::net::AggregationFn>();
     module.register_type::<crate::net::TransactionNode>();
     module.register_type::<crate::net::MessageNode>();
+    module.register_type::<crate::net::DecodedEvent>();
 
     module.register_async_fn(crate::net::query, crate::net::queries::query_api);
     module.register_async_fn(
@@ -378,6 +602,10 @@ fn register_net(handlers: &mut RuntimeHandlers) {
         super::net::subscribe_collection,
         super::net::subscribe_collection_api,
     );
+    module.register_async_fn_with_callback(
+        super::net::subscribe_events,
+        super::net::subscribe_events_api,
+    );
     module.register_async_fn_no_args(crate::net::suspend, crate::net::suspend_api);
     module.register_async_fn_no_args(crate::net::resume, crate::net::resume_api);
     module.register_async_fn(
@@ -413,6 +641,8 @@ fn register_processing(handlers: &mut RuntimeHandlers) {
     module.register_type::<crate::processing::ProcessingEvent>();
     module.register_type::<crate::processing::ResultOfProcessMessage>();
     module.register_type::<crate::processing::DecodedOutput>();
+    module.register_type::<crate::processing::ProcessingState>();
+    module.register_type::<crate::processing::ProcessedMessage>();
 
     module.register_async_fn_with_callback(
         super::processing::send_message,
@@ -426,6 +656,18 @@ fn register_processing(handlers: &mut RuntimeHandlers) {
         super::processing::process_message,
         super::processing::process_message_api,
     );
+    module.register_async_fn_with_callback(
+        super::processing::resume_processing,
+        super::processing::resume_processing_api,
+    );
+    module.register_async_fn_with_callback(
+        super::processing::process_messages,
+        super::processing::process_messages_api,
+    );
+    module.register_async_fn(
+        crate::processing::estimate_fees,
+        crate::processing::estimate_fees::estimate_fees_api,
+    );
     module.register();
 }
 
@@ -446,6 +688,10 @@ fn register_tvm(handlers: &mut RuntimeHandlers) {
     );
     module.register_async_fn(crate::tvm::run_tvm, crate::tvm::run_message::run_tvm_api);
     module.register_async_fn(crate::tvm::run_get, crate::tvm::run_get::run_get_api);
+    module.register_async_fn(
+        crate::tvm::run_message_chain,
+        crate::tvm::run_message_chain::run_message_chain_api,
+    );
     module.register();
 }
 
@@ -15,7 +15,10 @@
 use super::request::Request;
 use crate::client::ClientContext;
 use crate::error::ClientResult;
-use crate::net::{ParamsOfSubscribeCollection, ResultOfSubscribeCollection, ResultOfSubscription};
+use crate::net::{
+    DecodedEvent, ParamsOfSubscribeCollection, ParamsOfSubscribeEvents,
+    ResultOfSubscribeCollection, ResultOfSubscription,
+};
 
 /// Creates a subscription
 ///
@@ -78,3 +81,30 @@ pub(crate) async fn subscribe_collection(
 
     crate::net::subscribe_collection(context, params, callback).await
 }
+
+/// Subscribes to the contract events
+///
+/// Creates a subscription to the external outbound messages of the contract
+/// with the specified `address`. Each message is decoded with `abi` and
+/// the events with the specified `event_names` are reported to the callback
+/// with `responseType` == 100 together with the message and transaction IDs.
+/// If `event_names` is not specified, all the ABI events are reported.
+///
+/// Subscription errors are reported the same way as for `subscribe_collection`.
+/// Subscription must be closed with `unsubscribe`.
+#[api_function]
+pub(crate) async fn subscribe_events(
+    context: std::sync::Arc<ClientContext>,
+    params: ParamsOfSubscribeEvents,
+    callback: std::sync::Arc<Request>,
+) -> ClientResult<ResultOfSubscribeCollection> {
+    let callback = move |result: ClientResult<DecodedEvent>| {
+        match result {
+            Ok(event) => callback.response(event, crate::net::SubscriptionResponseType::Ok as u32),
+            Err(err) => callback.response(err, crate::net::SubscriptionResponseType::Error as u32),
+        }
+        futures::future::ready(())
+    };
+
+    crate::net::subscribe_events(context, params, callback).await
+}
@@ -16,8 +16,9 @@ use super::request::Request;
 use crate::client::ClientContext;
 use crate::error::ClientResult;
 use crate::processing::{
-    ParamsOfProcessMessage, ParamsOfSendMessage, ParamsOfWaitForTransaction, ProcessingEvent,
-    ProcessingResponseType, ResultOfProcessMessage, ResultOfSendMessage,
+    ParamsOfProcessMessage, ParamsOfProcessMessages, ParamsOfResumeProcessing,
+    ParamsOfSendMessage, ParamsOfWaitForTransaction, ProcessingEvent, ProcessingResponseType,
+    ResultOfProcessMessage, ResultOfProcessMessages, ResultOfSendMessage,
 };
 use std::sync::Arc;
 
@@ -52,6 +53,55 @@ pub(crate) async fn process_message(
     crate::processing::process_message(context, params, callback).await
 }
 
+/// Resumes the message processing interrupted by the app termination.
+///
+/// Continues the processing from the state received with the last
+/// `ProcessingStateChanged` event of `process_message`. The already sent
+/// message is not sent again: the processing waits for its transaction
+/// starting from the saved shard block. If the message expires, a new
+/// message is created with the next try index, as `process_message` does.
+///
+/// Use it instead of calling `process_message` again for the
+/// interrupted processing, to avoid sending the same call twice.
+#[api_function]
+pub(crate) async fn resume_processing(
+    context: Arc<ClientContext>,
+    params: ParamsOfResumeProcessing,
+    request: std::sync::Arc<Request>,
+) -> ClientResult<ResultOfProcessMessage> {
+    let callback = move |event: ProcessingEvent| {
+        request.response(event, ProcessingResponseType::ProcessingEvent as u32);
+        futures::future::ready(())
+    };
+    crate::processing::resume_processing(context, params, callback).await
+}
+
+/// Sends many messages to the network and monitors their processing at once.
+///
+/// Creates ABI-compatible messages, sends them to the network and waits for
+/// the result transactions. The shard blocks are walked only once for all the
+/// messages of the same shard, so the processing of a large batch doesn't
+/// overload the endpoint with the separate block subscriptions.
+///
+/// Returns results in the order of the messages. Failure of a message
+/// doesn't affect the others: its error is returned in the `error` field
+/// of the corresponding result.
+///
+/// Unlike `process_message`, the expired messages aren't recreated and resent,
+/// `MessageExpired` error is returned for them instead.
+#[api_function]
+pub(crate) async fn process_messages(
+    context: Arc<ClientContext>,
+    params: ParamsOfProcessMessages,
+    request: std::sync::Arc<Request>,
+) -> ClientResult<ResultOfProcessMessages> {
+    let callback = move |event: ProcessingEvent| {
+        request.response(event, ProcessingResponseType::ProcessingEvent as u32);
+        futures::future::ready(())
+    };
+    crate::processing::process_messages(context, params, callback).await
+}
+
 /// Sends message to the network
 ///
 /// Sends message to the network and returns the last generated shard block of the destination account
@@ -0,0 +1,272 @@
+/*
+ * Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+ *
+ * Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+ * this file except in compliance with the License.
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific TON DEV software governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! Minimal GraphQL parser supporting the documents produced by the `net` module:
+//! operations with variables, aliases, arguments and nested selection sets.
+//! Fragments and directives are not supported.
+
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum OperationKind {
+    Query,
+    Mutation,
+    Subscription,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Field {
+    pub alias: Option<String>,
+    pub name: String,
+    /// Argument values with the variables substituted
+    pub arguments: Map<String, Value>,
+    pub selection: Vec<Field>,
+}
+
+impl Field {
+    /// Name of the field in the response
+    pub fn key(&self) -> &str {
+        self.alias.as_ref().unwrap_or(&self.name)
+    }
+
+    pub fn argument(&self, name: &str) -> &Value {
+        self.arguments.get(name).unwrap_or(&Value::Null)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Operation {
+    pub kind: OperationKind,
+    pub fields: Vec<Field>,
+}
+
+pub(crate) fn parse(query: &str, variables: &Value) -> Result<Operation, String> {
+    Parser {
+        chars: query.chars().collect(),
+        pos: 0,
+        variables,
+    }
+    .document()
+}
+
+struct Parser<'a> {
+    chars: Vec<char>,
+    pos: usize,
+    variables: &'a Value,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_ignored(&mut self) {
+        while let Some(c) = self.chars.get(self.pos) {
+            match c {
+                ' ' | '\t' | '\n' | '\r' | ',' | '\u{feff}' => self.pos += 1,
+                '#' => {
+                    while self.chars.get(self.pos).map(|c| *c != '\n').unwrap_or(false) {
+                        self.pos += 1;
+                    }
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_ignored();
+        self.chars.get(self.pos).cloned()
+    }
+
+    fn error<T>(&self, message: &str) -> Result<T, String> {
+        Err(format!("Syntax error at {}: {}", self.pos, message))
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.error(&format!("expected `{}`", expected))
+        }
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        self.skip_ignored();
+        let start = self.pos;
+        while let Some(c) = self.chars.get(self.pos) {
+            if c.is_ascii_alphanumeric() || *c == '_' {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        if start == self.pos || self.chars[start].is_ascii_digit() {
+            return self.error("expected name");
+        }
+        Ok(self.chars[start..self.pos].iter().collect())
+    }
+
+    fn document(mut self) -> Result<Operation, String> {
+        let kind = if self.peek() == Some('{') {
+            OperationKind::Query
+        } else {
+            let kind = match self.name()?.as_str() {
+                "query" => OperationKind::Query,
+                "mutation" => OperationKind::Mutation,
+                "subscription" => OperationKind::Subscription,
+                other => return self.error(&format!("unknown operation `{}`", other)),
+            };
+            if self.peek().map(|c| c.is_ascii_alphabetic() || c == '_').unwrap_or(false) {
+                self.name()?;
+            }
+            if self.peek() == Some('(') {
+                self.skip_variable_definitions()?;
+            }
+            kind
+        };
+        let fields = self.selection_set()?;
+        if self.peek().is_some() {
+            return self.error("only one operation is supported");
+        }
+        Ok(Operation { kind, fields })
+    }
+
+    // variable types are not checked, values are taken from the `variables` as is
+    fn skip_variable_definitions(&mut self) -> Result<(), String> {
+        self.expect('(')?;
+        let mut depth = 1;
+        while depth > 0 {
+            match self.chars.get(self.pos) {
+                Some('(') => depth += 1,
+                Some(')') => depth -= 1,
+                Some(_) => {}
+                None => return self.error("unterminated variable definitions"),
+            }
+            self.pos += 1;
+        }
+        Ok(())
+    }
+
+    fn selection_set(&mut self) -> Result<Vec<Field>, String> {
+        self.expect('{')?;
+        let mut fields = Vec::new();
+        loop {
+            match self.peek() {
+                Some('}') => {
+                    self.pos += 1;
+                    return Ok(fields);
+                }
+                Some('.') => return self.error("fragments are not supported"),
+                Some(_) => fields.push(self.field()?),
+                None => return self.error("unterminated selection set"),
+            }
+        }
+    }
+
+    fn field(&mut self) -> Result<Field, String> {
+        let mut alias = None;
+        let mut name = self.name()?;
+        if self.peek() == Some(':') {
+            self.pos += 1;
+            alias = Some(name);
+            name = self.name()?;
+        }
+        let mut arguments = Map::new();
+        if self.peek() == Some('(') {
+            self.pos += 1;
+            while self.peek() != Some(')') {
+                let name = self.name()?;
+                self.expect(':')?;
+                arguments.insert(name, self.value()?);
+            }
+            self.pos += 1;
+        }
+        let selection = if self.peek() == Some('{') {
+            self.selection_set()?
+        } else {
+            Vec::new()
+        };
+        Ok(Field { alias, name, arguments, selection })
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        match self.peek() {
+            Some('$') => {
+                self.pos += 1;
+                let name = self.name()?;
+                Ok(self.variables[name.as_str()].clone())
+            }
+            Some('"') => self.string(),
+            Some('[') => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                while self.peek() != Some(']') {
+                    if self.peek().is_none() {
+                        return self.error("unterminated list");
+                    }
+                    items.push(self.value()?);
+                }
+                self.pos += 1;
+                Ok(Value::Array(items))
+            }
+            Some('{') => {
+                self.pos += 1;
+                let mut object = Map::new();
+                while self.peek() != Some('}') {
+                    let name = self.name()?;
+                    self.expect(':')?;
+                    object.insert(name, self.value()?);
+                }
+                self.pos += 1;
+                Ok(Value::Object(object))
+            }
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            Some(_) => Ok(match self.name()?.as_str() {
+                "true" => Value::Bool(true),
+                "false" => Value::Bool(false),
+                "null" => Value::Null,
+                // enum values are represented with their names
+                other => Value::String(other.to_owned()),
+            }),
+            None => self.error("expected value"),
+        }
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let start = self.pos;
+        while let Some(c) = self.chars.get(self.pos) {
+            if c.is_ascii_digit() || ['-', '+', '.', 'e', 'E'].contains(c) {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        let literal: String = self.chars[start..self.pos].iter().collect();
+        serde_json::from_str(&literal).or_else(|_| self.error("invalid number"))
+    }
+
+    fn string(&mut self) -> Result<Value, String> {
+        let start = self.pos;
+        self.pos += 1;
+        loop {
+            match self.chars.get(self.pos) {
+                Some('\\') => self.pos += 2,
+                Some('"') => break,
+                Some(_) => self.pos += 1,
+                None => return self.error("unterminated string"),
+            }
+        }
+        self.pos += 1;
+        let literal: String = self.chars[start..self.pos].iter().collect();
+        serde_json::from_str(&literal).or_else(|_| self.error("invalid string"))
+    }
+}
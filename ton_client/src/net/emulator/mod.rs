@@ -0,0 +1,674 @@
+/*
+ * Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+ *
+ * Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+ * this file except in compliance with the License.
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific TON DEV software governing permissions and
+ * limitations under the License.
+ *
+ */
+
+//! In-process blockchain emulator for offline testing.
+//!
+//! Emulator implements the subset of GraphQL API used by the `net` module:
+//! collection queries with `wait_for` timeouts, aggregations, subscriptions
+//! via the websocket link and `postRequests` mutation. Posted messages are executed
+//! with the transaction executor, produced internal messages are delivered to
+//! the destination accounts within the same synthetic block.
+//!
+//! Emulator is addressed with `emulator://<name>` endpoint, so the client works with it
+//! the same way as with a real node:
+//!
+//! ```ignore
+//! let emulator = BlockchainEmulator::new("test", None).await?;
+//! emulator.add_account(&giver_boc).await?;
+//! let client = ClientContext::new(ClientConfig {
+//!     network: NetworkConfig {
+//!         endpoints: Some(vec![emulator.endpoint()]),
+//!         ..Default::default()
+//!     },
+//!     ..Default::default()
+//! })?;
+//! ```
+//!
+//! Like TON OS SE, emulator has no masterchain blocks and the single shard
+//! of the basechain, so only basechain accounts are supported.
+
+mod graphql;
+mod store;
+#[cfg(test)]
+mod tests;
+
+use crate::boc::internal::{
+    deserialize_cell_from_boc, deserialize_object_from_boc, deserialize_object_from_cell,
+    serialize_cell_to_base64, serialize_object_to_base64, serialize_object_to_cell,
+};
+use crate::boc::ParamsOfParse;
+use crate::client::{ClientEnv, FetchResult, WebSocket};
+use crate::error::ClientResult;
+use crate::net::endpoint::EMULATOR_URL_SCHEME;
+use crate::net::{
+    Error, ACCOUNTS_COLLECTION, BLOCKS_COLLECTION, MESSAGES_COLLECTION, TRANSACTIONS_COLLECTION,
+};
+use crate::tvm::run_message::call_executor;
+use crate::tvm::types::{blockchain_config_from_boc, ResolvedExecutionOptions};
+use crate::{ClientConfig, ClientContext};
+use futures::{SinkExt, StreamExt};
+use graphql::{Field, OperationKind};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+use store::Store;
+use tokio::sync::{watch, Mutex};
+use ton_block::{Account, CurrencyCollection, Grams, Message, MsgAddressInt, Serializable};
+use ton_executor::BlockchainConfig;
+
+const SERVER_VERSION: &str = "0.39.0";
+const WORKCHAIN: i32 = 0;
+const SHARD: &str = "8000000000000000";
+/// Empty block is produced if somebody waits for a block longer than this interval
+const BLOCK_INTERVAL_MS: u64 = 1000;
+/// Limits the number of transactions produced by a single posted message
+const MAX_MESSAGE_CHAIN_LENGTH: usize = 1000;
+
+lazy_static! {
+    static ref EMULATORS: std::sync::Mutex<HashMap<String, Weak<Blockchain>>> =
+        std::sync::Mutex::new(HashMap::new());
+}
+
+/// Returns emulator addressed with the URL if any
+pub(crate) fn find_emulator(url: &str) -> Option<Arc<Blockchain>> {
+    let name = url.strip_prefix(EMULATOR_URL_SCHEME)?;
+    let name = name.split(|c| c == '/' || c == '?').next().unwrap_or_default();
+    EMULATORS.lock().ok()?.get(name)?.upgrade()
+}
+
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (b'+', _) => {
+                decoded.push(b' ');
+                i += 1;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn graphql_errors(message: impl std::fmt::Display) -> Value {
+    json!({ "errors": [{ "message": message.to_string() }] })
+}
+
+/// Maps the name of the aggregation query to the collection name,
+/// e.g. `aggregateBlockSignatures` to `blocks_signatures`
+fn aggregation_collection(query_name: &str) -> Option<String> {
+    let doc_type = query_name.strip_prefix("aggregate")?;
+    let mut words = Vec::new();
+    for c in doc_type.chars() {
+        if c.is_ascii_uppercase() {
+            words.push(c.to_ascii_lowercase().to_string());
+        } else {
+            words.last_mut()?.push(c);
+        }
+    }
+    // only the first word of the collection name is plural, e.g. `blocks_signatures`
+    if let Some(first) = words.first_mut() {
+        if words.len() > 1 {
+            first.push('s');
+        }
+    }
+    Some(words.join("_"))
+}
+
+struct Chain {
+    store: Store,
+    last_block_id: String,
+    last_block_seq_no: u32,
+    last_lt: u64,
+}
+
+pub(crate) struct Blockchain {
+    name: String,
+    context: Arc<ClientContext>,
+    blockchain_config: Arc<BlockchainConfig>,
+    chain: Mutex<Chain>,
+    changed: watch::Sender<u64>,
+    changes: watch::Receiver<u64>,
+}
+
+impl Blockchain {
+    fn now(&self) -> u32 {
+        (self.context.env.now_ms() / 1000) as u32
+    }
+
+    fn notify(&self, chain: &Chain) {
+        let _ = self.changed.broadcast(chain.store.seq());
+    }
+
+    fn block_id(&self, seq_no: u32) -> String {
+        hex::encode(Sha256::digest(format!("{}:{}:{}", self.name, WORKCHAIN, seq_no).as_bytes()))
+    }
+
+    fn produce_block(&self, chain: &mut Chain, in_msg_descr: Vec<Value>) -> String {
+        let seq_no = chain.last_block_seq_no + 1;
+        let id = self.block_id(seq_no);
+        chain.store.upsert(BLOCKS_COLLECTION, json!({
+            "id": id,
+            "status": 2,
+            "seq_no": seq_no,
+            "gen_utime": self.now(),
+            "workchain_id": WORKCHAIN,
+            "shard": SHARD,
+            "after_merge": false,
+            "after_split": false,
+            "before_split": false,
+            "key_block": false,
+            "prev_ref": {
+                "root_hash": chain.last_block_id,
+                "seq_no": chain.last_block_seq_no,
+            },
+            "tr_count": in_msg_descr.len(),
+            "in_msg_descr": in_msg_descr,
+        }));
+        chain.last_block_id = id.clone();
+        chain.last_block_seq_no = seq_no;
+        id
+    }
+
+    async fn parse(&self, collection: &str, boc: String) -> ClientResult<Value> {
+        let params = ParamsOfParse { boc };
+        let context = self.context.clone();
+        Ok(match collection {
+            MESSAGES_COLLECTION => crate::boc::parse_message(context, params).await?,
+            TRANSACTIONS_COLLECTION => crate::boc::parse_transaction(context, params).await?,
+            _ => crate::boc::parse_account(context, params).await?,
+        }
+        .parsed)
+    }
+
+    async fn account_cell(&self, chain: &Chain, address: &MsgAddressInt) -> ClientResult<ton_types::Cell> {
+        match chain.store.get(ACCOUNTS_COLLECTION, &address.to_string()) {
+            Some(account) => {
+                let boc = account["boc"].as_str().unwrap_or_default();
+                Ok(deserialize_cell_from_boc(&self.context, boc, "account").await?.1)
+            }
+            None => serialize_object_to_cell(&Account::default(), "account"),
+        }
+    }
+
+    async fn store_account(&self, chain: &mut Chain, address: &MsgAddressInt, account: ton_types::Cell) -> ClientResult<()> {
+        if deserialize_object_from_cell::<Account>(account.clone(), "account")?.is_none() {
+            chain.store.remove(ACCOUNTS_COLLECTION, &address.to_string());
+        } else {
+            let parsed = self.parse(ACCOUNTS_COLLECTION, serialize_cell_to_base64(&account, "account")?).await?;
+            chain.store.upsert(ACCOUNTS_COLLECTION, parsed);
+        }
+        Ok(())
+    }
+
+    /// Executes the message and all the internal messages produced by it
+    async fn process_message(&self, boc: &str) -> ClientResult<()> {
+        let message = deserialize_object_from_boc::<Message>(&self.context, boc, "message")
+            .await?
+            .object;
+        match message.dst_ref() {
+            Some(address) if address.get_workchain_id() == WORKCHAIN => {}
+            _ => return Err(Error::emulator_error("only messages to the basechain accounts are supported")),
+        }
+
+        let mut chain = self.chain.lock().await;
+        let mut in_msg_descr = Vec::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(message);
+        while let Some(message) = queue.pop_front() {
+            if in_msg_descr.len() >= MAX_MESSAGE_CHAIN_LENGTH {
+                log::warn!("Emulator message chain is too long, remaining messages are not delivered");
+                break;
+            }
+            let address = match message.dst_ref() {
+                Some(address) if address.get_workchain_id() == WORKCHAIN => address.clone(),
+                _ => continue,
+            };
+            let message_boc = serialize_object_to_base64(&message, "message")?;
+            let account = self.account_cell(&chain, &address).await?;
+            let options = ResolvedExecutionOptions {
+                blockchain_config: self.blockchain_config.clone(),
+                block_time: self.now(),
+                block_lt: chain.last_lt,
+                transaction_lt: chain.last_lt + 1,
+            };
+            let account_copy = account.clone();
+            let address_copy = address.clone();
+            let contract_info = move || async move {
+                let account = deserialize_object_from_cell::<Account>(account_copy, "account")?;
+                let balance = account.balance().map(|balance| balance.grams.0).unwrap_or_default();
+                let balance = u64::try_from(balance).map_err(|_| {
+                    Error::emulator_error(format!("balance {} does not fit into 64 bits", balance))
+                })?;
+                Ok((address_copy, balance))
+            };
+            // rejected messages are dropped like the validators do
+            let (transaction, account) = match call_executor(account, message, options, contract_info).await {
+                Ok(result) => result,
+                Err(err) => {
+                    log::debug!("Emulator rejected message: {}", err.message);
+                    continue;
+                }
+            };
+            chain.last_lt = transaction.logical_time() + transaction.outmsg_cnt as u64 + 1;
+
+            let in_message = self.parse(MESSAGES_COLLECTION, message_boc).await?;
+            let message_id = in_message["id"].clone();
+            chain.store.upsert(MESSAGES_COLLECTION, in_message);
+            for i in 0..transaction.outmsg_cnt {
+                let out_message = transaction
+                    .get_out_msg(i)
+                    .map_err(|err| crate::tvm::Error::can_not_read_transaction(err))?
+                    .ok_or_else(|| crate::tvm::Error::can_not_read_transaction("message missing"))?;
+                let parsed = self
+                    .parse(MESSAGES_COLLECTION, serialize_object_to_base64(&out_message, "message")?)
+                    .await?;
+                chain.store.upsert(MESSAGES_COLLECTION, parsed);
+                if out_message.is_internal() {
+                    queue.push_back(out_message);
+                }
+            }
+
+            let mut parsed = self
+                .parse(TRANSACTIONS_COLLECTION, serialize_object_to_base64(&transaction, "transaction")?)
+                .await?;
+            parsed["block_id"] = self.block_id(chain.last_block_seq_no + 1).into();
+            in_msg_descr.push(json!({
+                "msg_id": message_id,
+                "transaction_id": parsed["id"],
+            }));
+            chain.store.upsert(TRANSACTIONS_COLLECTION, parsed);
+            self.store_account(&mut chain, &address, account).await?;
+        }
+
+        self.produce_block(&mut chain, in_msg_descr);
+        self.notify(&chain);
+        Ok(())
+    }
+
+    async fn query_collection(&self, collection: &str, field: &Field) -> Result<Value, String> {
+        let filter = field.argument("filter");
+        let order = field.argument("orderBy");
+        let limit = field.argument("limit").as_u64().map(|limit| limit as usize);
+        let timeout = field.argument("timeout").as_f64().unwrap_or_default() as u64;
+
+        let deadline = self.context.env.now_ms() + timeout;
+        let mut changes = self.changes.clone();
+        loop {
+            {
+                let chain = self.chain.lock().await;
+                let documents = chain.store.query(collection, filter, order, limit, 0);
+                if !documents.is_empty() || timeout == 0 {
+                    return Ok(Value::Array(
+                        documents
+                            .into_iter()
+                            .map(|document| chain.store.project(collection, document, &field.selection))
+                            .collect(),
+                    ));
+                }
+            }
+            let now = self.context.env.now_ms();
+            if now >= deadline {
+                return Ok(Value::Array(Vec::new()));
+            }
+            let mut wait = deadline - now;
+            // blocks are produced only when somebody waits for them
+            if collection == BLOCKS_COLLECTION {
+                wait = wait.min(BLOCK_INTERVAL_MS);
+            }
+            let changed = tokio::time::timeout(Duration::from_millis(wait), changes.recv()).await;
+            if changed.is_err() && collection == BLOCKS_COLLECTION {
+                let mut chain = self.chain.lock().await;
+                self.produce_block(&mut chain, Vec::new());
+                self.notify(&chain);
+            }
+        }
+    }
+
+    async fn resolve_query_field(&self, field: &Field) -> Result<Value, String> {
+        match field.name.as_str() {
+            "info" => {
+                let chain = self.chain.lock().await;
+                let info = json!({
+                    "version": SERVER_VERSION,
+                    "time": self.context.env.now_ms(),
+                    "latency": 0,
+                    "endpoints": [format!("{}{}", EMULATOR_URL_SCHEME, self.name)],
+                });
+                Ok(chain.store.project("", &info, &field.selection))
+            }
+            "counterparties" => Err("counterparties query is not supported by the emulator".to_owned()),
+            name => match aggregation_collection(name) {
+                Some(collection) => self.chain.lock().await.store.aggregate(
+                    &collection,
+                    field.argument("filter"),
+                    field.argument("fields"),
+                ),
+                None => self.query_collection(name, field).await,
+            },
+        }
+    }
+
+    async fn post_requests(&self, field: &Field) -> Result<Value, String> {
+        let requests = field.argument("requests").as_array().cloned().unwrap_or_default();
+        let mut ids = Vec::new();
+        for request in requests {
+            let body = request["body"].as_str().ok_or("request has no body")?;
+            self.process_message(body).await.map_err(|err| err.message)?;
+            ids.push(request["id"].clone());
+        }
+        Ok(Value::Array(ids))
+    }
+
+    async fn execute(&self, query: &str, variables: &Value) -> Value {
+        let operation = match graphql::parse(query, variables) {
+            Ok(operation) => operation,
+            Err(err) => return graphql_errors(err),
+        };
+        let mut data = serde_json::Map::new();
+        for field in &operation.fields {
+            let result = match (&operation.kind, field.name.as_str()) {
+                (OperationKind::Query, _) => self.resolve_query_field(field).await,
+                (OperationKind::Mutation, "postRequests") => self.post_requests(field).await,
+                (OperationKind::Mutation, name) => Err(format!("Unknown mutation {}", name)),
+                (OperationKind::Subscription, _) => {
+                    Err("Subscriptions are supported via websocket only".to_owned())
+                }
+            };
+            match result {
+                Ok(value) => {
+                    data.insert(field.key().to_owned(), value);
+                }
+                Err(err) => return graphql_errors(err),
+            }
+        }
+        json!({ "data": data })
+    }
+
+    pub async fn fetch(&self, url: &str, body: Option<String>) -> ClientResult<FetchResult> {
+        let (query, variables) = match body {
+            Some(body) => {
+                let request: Value = serde_json::from_str(&body)
+                    .map_err(|err| Error::emulator_error(format!("invalid request: {}", err)))?;
+                (request["query"].as_str().unwrap_or_default().to_owned(), request["variables"].clone())
+            }
+            None => {
+                let query = url
+                    .split('?')
+                    .nth(1)
+                    .unwrap_or_default()
+                    .split('&')
+                    .find_map(|param| param.strip_prefix("query="))
+                    .unwrap_or_default();
+                (percent_decode(query), Value::Null)
+            }
+        };
+        Ok(FetchResult {
+            status: 200,
+            headers: HashMap::new(),
+            body: self.execute(&query, &variables).await.to_string(),
+            url: url.to_owned(),
+            remote_address: None,
+        })
+    }
+
+    async fn run_subscription(
+        self: Arc<Self>,
+        id: Value,
+        payload: Value,
+        mut sender: futures::channel::mpsc::Sender<ClientResult<String>>,
+    ) {
+        let field = match graphql::parse(payload["query"].as_str().unwrap_or_default(), &payload["variables"]) {
+            Ok(operation) if operation.kind == OperationKind::Subscription && operation.fields.len() == 1 => {
+                operation.fields[0].clone()
+            }
+            Ok(_) => {
+                let error = json!({ "message": "Single subscription field is expected" });
+                let _ = sender.send(Ok(json!({ "type": "error", "id": id, "payload": error }).to_string())).await;
+                return;
+            }
+            Err(err) => {
+                let error = json!({ "message": err });
+                let _ = sender.send(Ok(json!({ "type": "error", "id": id, "payload": error }).to_string())).await;
+                return;
+            }
+        };
+
+        let mut changes = self.changes.clone();
+        let mut since = self.chain.lock().await.store.seq();
+        while changes.recv().await.is_some() {
+            let messages: Vec<String> = {
+                let chain = self.chain.lock().await;
+                let messages = chain.store
+                    .query(&field.name, field.argument("filter"), &Value::Null, None, since)
+                    .into_iter()
+                    .map(|document| {
+                        let mut data = serde_json::Map::new();
+                        data.insert(
+                            field.key().to_owned(),
+                            chain.store.project(&field.name, document, &field.selection),
+                        );
+                        json!({ "type": "data", "id": id, "payload": { "data": data } }).to_string()
+                    })
+                    .collect();
+                since = chain.store.seq();
+                messages
+            };
+            for message in messages {
+                if sender.send(Ok(message)).await.is_err() {
+                    return;
+                }
+            }
+        }
+    }
+
+    async fn serve_websocket(
+        self: Arc<Self>,
+        mut receiver: futures::channel::mpsc::Receiver<String>,
+        mut sender: futures::channel::mpsc::Sender<ClientResult<String>>,
+    ) {
+        let mut operations = HashMap::new();
+        while let Some(message) = receiver.next().await {
+            let message: Value = serde_json::from_str(&message).unwrap_or_default();
+            let id = message["id"].clone();
+            let response = match message["type"].as_str().unwrap_or_default() {
+                "connection_init" => Some(json!({ "type": "connection_ack" })),
+                "start" => {
+                    let (subscription, abort) = futures::future::abortable(self.clone().run_subscription(
+                        id.clone(),
+                        message["payload"].clone(),
+                        sender.clone(),
+                    ));
+                    tokio::spawn(subscription);
+                    operations.insert(id.to_string(), abort);
+                    None
+                }
+                "stop" => {
+                    if let Some(abort) = operations.remove(&id.to_string()) {
+                        abort.abort();
+                    }
+                    Some(json!({ "type": "complete", "id": id }))
+                }
+                "connection_terminate" => break,
+                _ => None,
+            };
+            if let Some(response) = response {
+                if sender.send(Ok(response.to_string())).await.is_err() {
+                    break;
+                }
+            }
+        }
+        for abort in operations.values() {
+            abort.abort();
+        }
+    }
+
+    pub fn websocket_connect(self: &Arc<Self>, env: &ClientEnv) -> WebSocket {
+        let (client_sender, server_receiver) = futures::channel::mpsc::channel::<String>(10);
+        let (server_sender, client_receiver) =
+            futures::channel::mpsc::channel::<ClientResult<String>>(100);
+        env.spawn(self.clone().serve_websocket(server_receiver, server_sender));
+        WebSocket {
+            receiver: Box::pin(client_receiver),
+            sender: Box::pin(
+                client_sender.sink_map_err(|err| crate::client::Error::websocket_send_error(err)),
+            ),
+        }
+    }
+}
+
+/// In-process blockchain emulator.
+///
+/// Emulator is available for the clients until it is dropped.
+pub struct BlockchainEmulator {
+    blockchain: Arc<Blockchain>,
+}
+
+impl BlockchainEmulator {
+    /// Creates emulator available with `emulator://<name>` endpoint.
+    ///
+    /// `blockchain_config` is a config params BOC encoded with base64,
+    /// default config is used if it is not provided.
+    pub async fn new(name: &str, blockchain_config: Option<String>) -> ClientResult<Self> {
+        let context = Arc::new(ClientContext::new(ClientConfig::default())?);
+        let blockchain_config = match blockchain_config {
+            Some(config) => blockchain_config_from_boc(&context, &config).await?,
+            None => BlockchainConfig::default(),
+        };
+        let (changed, changes) = watch::channel(0);
+        let blockchain = Arc::new(Blockchain {
+            name: name.to_owned(),
+            context,
+            blockchain_config: Arc::new(blockchain_config),
+            chain: Mutex::new(Chain {
+                store: Store::default(),
+                last_block_id: String::new(),
+                last_block_seq_no: 0,
+                last_lt: 1_000_000,
+            }),
+            changed,
+            changes,
+        });
+        {
+            let mut chain = blockchain.chain.lock().await;
+            blockchain.produce_block(&mut chain, Vec::new());
+        }
+
+        let mut emulators = EMULATORS
+            .lock()
+            .map_err(|_| Error::emulator_error("emulators registry is poisoned"))?;
+        if emulators.get(name).and_then(|emulator| emulator.upgrade()).is_some() {
+            return Err(Error::emulator_error(format!("emulator `{}` already exists", name)));
+        }
+        emulators.insert(name.to_owned(), Arc::downgrade(&blockchain));
+
+        Ok(Self { blockchain })
+    }
+
+    /// Endpoint to be used in `NetworkConfig::endpoints`
+    pub fn endpoint(&self) -> String {
+        format!("{}{}", EMULATOR_URL_SCHEME, self.blockchain.name)
+    }
+
+    /// Puts the account state into the emulator, replacing existing state of the account.
+    /// Returns the account address.
+    pub async fn add_account(&self, boc: &str) -> ClientResult<String> {
+        let blockchain = &self.blockchain;
+        let (_, cell) = deserialize_cell_from_boc(&blockchain.context, boc, "account").await?;
+        let address = deserialize_object_from_cell::<Account>(cell.clone(), "account")?
+            .get_addr()
+            .cloned()
+            .ok_or_else(|| Error::emulator_error("account has no address"))?;
+        if address.get_workchain_id() != WORKCHAIN {
+            return Err(Error::emulator_error("only basechain accounts are supported"));
+        }
+        let mut chain = blockchain.chain.lock().await;
+        blockchain.store_account(&mut chain, &address, cell).await?;
+        blockchain.notify(&chain);
+        Ok(address.to_string())
+    }
+
+    /// Adds `value` nanotokens to the account balance without any transaction.
+    /// Creates uninitialized account if the account doesn't exist.
+    pub async fn topup(&self, address: &str, value: u64) -> ClientResult<()> {
+        let blockchain = &self.blockchain;
+        let address = crate::encoding::account_decode(address)?;
+        if address.get_workchain_id() != WORKCHAIN {
+            return Err(Error::emulator_error("only basechain accounts are supported"));
+        }
+        let mut chain = blockchain.chain.lock().await;
+        let cell = blockchain.account_cell(&chain, &address).await?;
+        let mut account = deserialize_object_from_cell::<Account>(cell, "account")?;
+        match account.balance().cloned() {
+            Some(mut balance) => {
+                let grams = balance
+                    .grams
+                    .0
+                    .checked_add(value as u128)
+                    .ok_or_else(|| Error::emulator_error("account balance overflow"))?;
+                balance.grams = Grams(grams);
+                account.set_balance(balance);
+            }
+            None => {
+                account = Account::uninit(
+                    address.clone(),
+                    chain.last_lt,
+                    blockchain.now(),
+                    CurrencyCollection::with_grams(value),
+                );
+            }
+        }
+        let cell = account
+            .serialize()
+            .map_err(|err| Error::emulator_error(format!("can not serialize account: {}", err)))?;
+        blockchain.store_account(&mut chain, &address, cell).await?;
+        blockchain.notify(&chain);
+        Ok(())
+    }
+
+    /// Executes the message the same way as it is posted by the client
+    pub async fn send_message(&self, boc: &str) -> ClientResult<()> {
+        self.blockchain.process_message(boc).await
+    }
+}
+
+impl Drop for BlockchainEmulator {
+    fn drop(&mut self) {
+        if let Ok(mut emulators) = EMULATORS.lock() {
+            let registered = emulators
+                .get(&self.blockchain.name)
+                .map(|emulator| emulator.ptr_eq(&Arc::downgrade(&self.blockchain)))
+                .unwrap_or(false);
+            if registered {
+                emulators.remove(&self.blockchain.name);
+            }
+        }
+    }
+}
@@ -0,0 +1,348 @@
+/*
+ * Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+ *
+ * Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+ * this file except in compliance with the License.
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific TON DEV software governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::graphql::Field;
+use crate::net::{
+    ACCOUNTS_COLLECTION, BLOCKS_COLLECTION, MESSAGES_COLLECTION, TRANSACTIONS_COLLECTION,
+};
+use num_bigint::BigInt;
+use num_traits::Num;
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+const FILTER_OPERATORS: [&str; 8] = ["eq", "ne", "gt", "gte", "lt", "lte", "in", "notIn"];
+
+struct Document {
+    /// Sequence number of the last document change
+    seq: u64,
+    value: Value,
+}
+
+#[derive(Default)]
+struct Collection {
+    documents: Vec<Document>,
+    index: HashMap<String, usize>,
+}
+
+/// In-memory storage of the documents in the same form as the GraphQL API returns them
+#[derive(Default)]
+pub(crate) struct Store {
+    collections: HashMap<String, Collection>,
+    seq: u64,
+}
+
+impl Store {
+    /// Sequence number of the last change
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// Inserts the document or replaces the document with the same `id`
+    pub fn upsert(&mut self, collection: &str, value: Value) {
+        self.seq += 1;
+        let document = Document { seq: self.seq, value };
+        let collection = self.collections.entry(collection.to_owned()).or_default();
+        let id = document.value["id"].as_str().unwrap_or_default().to_owned();
+        match collection.index.get(&id) {
+            Some(index) => collection.documents[*index] = document,
+            None => {
+                collection.index.insert(id, collection.documents.len());
+                collection.documents.push(document);
+            }
+        }
+    }
+
+    pub fn remove(&mut self, collection: &str, id: &str) {
+        if let Some(collection) = self.collections.get_mut(collection) {
+            if let Some(index) = collection.index.remove(id) {
+                collection.documents.remove(index);
+                for position in collection.index.values_mut() {
+                    if *position > index {
+                        *position -= 1;
+                    }
+                }
+                self.seq += 1;
+            }
+        }
+    }
+
+    pub fn get(&self, collection: &str, id: &str) -> Option<&Value> {
+        let collection = self.collections.get(collection)?;
+        collection.index.get(id).map(|index| &collection.documents[*index].value)
+    }
+
+    /// Returns documents matching the filter and changed after `since` sequence number
+    pub fn query(
+        &self,
+        collection: &str,
+        filter: &Value,
+        order: &Value,
+        limit: Option<usize>,
+        since: u64,
+    ) -> Vec<&Value> {
+        let mut documents: Vec<&Value> = match self.collections.get(collection) {
+            Some(collection) => collection
+                .documents
+                .iter()
+                .filter(|document| document.seq > since && matches(&document.value, filter))
+                .map(|document| &document.value)
+                .collect(),
+            None => Vec::new(),
+        };
+        for order_by in order.as_array().cloned().unwrap_or_default().iter().rev() {
+            let path = order_by["path"].as_str().unwrap_or_default();
+            let descending = order_by["direction"] == "DESC";
+            // sort is stable, so sorting by the keys in the reverse order gives lexicographic order
+            documents.sort_by(|a, b| {
+                let ordering = compare(get_path(a, path), get_path(b, path)).unwrap_or(Ordering::Equal);
+                if descending { ordering.reverse() } else { ordering }
+            });
+        }
+        if let Some(limit) = limit {
+            documents.truncate(limit);
+        }
+        documents
+    }
+
+    /// Calculates aggregation functions over the documents matching the filter
+    pub fn aggregate(&self, collection: &str, filter: &Value, fields: &Value) -> Result<Value, String> {
+        let documents = self.query(collection, filter, &Value::Null, None, 0);
+        let fields = match fields.as_array() {
+            Some(fields) if !fields.is_empty() => fields.clone(),
+            _ => vec![json!({ "field": "", "fn": "COUNT" })],
+        };
+        let mut results = Vec::new();
+        for field in fields {
+            let path = field["field"].as_str().unwrap_or_default();
+            let values: Vec<BigInt> = documents
+                .iter()
+                .filter_map(|document| to_number(get_path(document, path)))
+                .collect();
+            let result = match field["fn"].as_str().unwrap_or("COUNT") {
+                "COUNT" => Some(BigInt::from(documents.len())),
+                "MIN" => values.iter().min().cloned(),
+                "MAX" => values.iter().max().cloned(),
+                "SUM" => Some(values.iter().sum()),
+                "AVERAGE" if !values.is_empty() => {
+                    Some(values.iter().sum::<BigInt>() / BigInt::from(values.len()))
+                }
+                "AVERAGE" => None,
+                other => return Err(format!("Unknown aggregation function {}", other)),
+            };
+            results.push(result.map(|value| Value::String(value.to_string())).unwrap_or_default());
+        }
+        Ok(Value::Array(results))
+    }
+
+    /// Builds the response object according to the selection set
+    pub fn project(&self, collection: &str, document: &Value, selection: &[Field]) -> Value {
+        if selection.is_empty() {
+            return document.clone();
+        }
+        if let Value::Array(items) = document {
+            return Value::Array(
+                items.iter().map(|item| self.project(collection, item, selection)).collect(),
+            );
+        }
+        if document.is_null() {
+            return Value::Null;
+        }
+        let mut result = serde_json::Map::new();
+        for field in selection {
+            let value = match self.join(collection, document, &field.name) {
+                Some((joined, collection)) => self.project(collection, &joined, &field.selection),
+                None => self.project("", &document[field.name.as_str()], &field.selection),
+            };
+            result.insert(field.key().to_owned(), format_value(value, field.argument("format")));
+        }
+        Value::Object(result)
+    }
+
+    // resolves the fields joining other collections
+    fn join(&self, collection: &str, document: &Value, field: &str) -> Option<(Value, &'static str)> {
+        let id = document["id"].as_str().unwrap_or_default();
+        let find_transaction = |filter: &dyn Fn(&Value) -> bool| {
+            self.collections
+                .get(TRANSACTIONS_COLLECTION)
+                .and_then(|transactions| {
+                    transactions.documents.iter().find(|document| filter(&document.value))
+                })
+                .map(|document| document.value.clone())
+                .unwrap_or_default()
+        };
+        let joined = match (collection, field) {
+            (TRANSACTIONS_COLLECTION, "in_message") => (
+                self.get(MESSAGES_COLLECTION, document["in_msg"].as_str()?)
+                    .cloned()
+                    .unwrap_or_default(),
+                MESSAGES_COLLECTION,
+            ),
+            (TRANSACTIONS_COLLECTION, "out_messages") => (
+                Value::Array(
+                    document["out_msgs"]
+                        .as_array()?
+                        .iter()
+                        .map(|id| {
+                            self.get(MESSAGES_COLLECTION, id.as_str().unwrap_or_default())
+                                .cloned()
+                                .unwrap_or_default()
+                        })
+                        .collect(),
+                ),
+                MESSAGES_COLLECTION,
+            ),
+            (TRANSACTIONS_COLLECTION, "account") => (
+                self.get(ACCOUNTS_COLLECTION, document["account_addr"].as_str()?)
+                    .cloned()
+                    .unwrap_or_default(),
+                ACCOUNTS_COLLECTION,
+            ),
+            (TRANSACTIONS_COLLECTION, "block") => (
+                self.get(BLOCKS_COLLECTION, document["block_id"].as_str()?)
+                    .cloned()
+                    .unwrap_or_default(),
+                BLOCKS_COLLECTION,
+            ),
+            (MESSAGES_COLLECTION, "src_transaction") => (
+                find_transaction(&|transaction| {
+                    transaction["out_msgs"]
+                        .as_array()
+                        .map(|out_msgs| out_msgs.iter().any(|out_msg| *out_msg == id))
+                        .unwrap_or(false)
+                }),
+                TRANSACTIONS_COLLECTION,
+            ),
+            (MESSAGES_COLLECTION, "dst_transaction") => (
+                find_transaction(&|transaction| transaction["in_msg"] == id),
+                TRANSACTIONS_COLLECTION,
+            ),
+            _ => return None,
+        };
+        Some(joined)
+    }
+}
+
+fn get_path<'a>(value: &'a Value, path: &str) -> &'a Value {
+    path.split('.')
+        .filter(|name| !name.is_empty())
+        .fold(value, |value, name| &value[name])
+}
+
+/// Converts big number representations used in the API into a number:
+/// JSON numbers, `0x` prefixed hex strings and decimal strings
+fn to_number(value: &Value) -> Option<BigInt> {
+    match value {
+        Value::Number(number) => BigInt::from_str_radix(&number.to_string(), 10).ok(),
+        Value::String(string) => {
+            let (negative, string) = match string.strip_prefix('-') {
+                Some(string) => (true, string),
+                None => (false, string.as_str()),
+            };
+            let number = match string.strip_prefix("0x") {
+                Some(hex) => BigInt::from_str_radix(hex, 16).ok()?,
+                None if !string.is_empty() && string.bytes().all(|c| c.is_ascii_digit()) => {
+                    BigInt::from_str_radix(string, 10).ok()?
+                }
+                None => return None,
+            };
+            Some(if negative { -number } else { number })
+        }
+        _ => None,
+    }
+}
+
+fn compare(a: &Value, b: &Value) -> Option<Ordering> {
+    if let (Some(a), Some(b)) = (to_number(a), to_number(b)) {
+        return Some(a.cmp(&b));
+    }
+    match (a, b) {
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        (Value::Null, Value::Null) => Some(Ordering::Equal),
+        (Value::Null, _) => Some(Ordering::Less),
+        (_, Value::Null) => Some(Ordering::Greater),
+        _ if a == b => Some(Ordering::Equal),
+        _ => None,
+    }
+}
+
+fn matches_operator(value: &Value, operator: &str, operand: &Value) -> bool {
+    let ordering = compare(value, operand);
+    match operator {
+        "eq" => ordering == Some(Ordering::Equal),
+        "ne" => ordering != Some(Ordering::Equal),
+        "gt" => ordering == Some(Ordering::Greater),
+        "gte" => ordering.map(|ordering| ordering != Ordering::Less).unwrap_or(false),
+        "lt" => ordering == Some(Ordering::Less),
+        "lte" => ordering.map(|ordering| ordering != Ordering::Greater).unwrap_or(false),
+        "in" => operand
+            .as_array()
+            .map(|items| items.iter().any(|item| compare(value, item) == Some(Ordering::Equal)))
+            .unwrap_or(false),
+        "notIn" => operand
+            .as_array()
+            .map(|items| items.iter().all(|item| compare(value, item) != Some(Ordering::Equal)))
+            .unwrap_or(true),
+        _ => false,
+    }
+}
+
+/// Checks the document against the GraphQL API filter
+pub(crate) fn matches(document: &Value, filter: &Value) -> bool {
+    let filter = match filter.as_object() {
+        Some(filter) => filter,
+        None => return true,
+    };
+    let mut result = true;
+    for (name, condition) in filter {
+        if name == "OR" {
+            continue;
+        }
+        let value = &document[name.as_str()];
+        let condition_matches = match condition.as_object() {
+            Some(condition)
+                if condition.keys().all(|key| FILTER_OPERATORS.contains(&key.as_str())) =>
+            {
+                condition
+                    .iter()
+                    .all(|(operator, operand)| matches_operator(value, operator, operand))
+            }
+            Some(condition) if condition.contains_key("any") || condition.contains_key("all") => {
+                let items = value.as_array().cloned().unwrap_or_default();
+                condition.get("any").map(|filter| items.iter().any(|item| matches(item, filter))).unwrap_or(true)
+                    && condition.get("all").map(|filter| items.iter().all(|item| matches(item, filter))).unwrap_or(true)
+            }
+            Some(_) => !value.is_null() && matches(value, condition),
+            None => false,
+        };
+        if !condition_matches {
+            result = false;
+            break;
+        }
+    }
+    match filter.get("OR") {
+        Some(or) => result || matches(document, or),
+        None => result,
+    }
+}
+
+fn format_value(value: Value, format: &Value) -> Value {
+    match (format.as_str(), &value) {
+        (Some("DEC"), Value::String(_)) => to_number(&value)
+            .map(|number| Value::String(number.to_str_radix(10)))
+            .unwrap_or(value),
+        _ => value,
+    }
+}
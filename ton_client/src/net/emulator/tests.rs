@@ -0,0 +1,148 @@
+use super::*;
+use crate::abi::{CallSet, DeploySet, ParamsOfEncodeMessage, Signer};
+use crate::net::{ParamsOfQueryCollection, ResultOfQueryCollection};
+use crate::processing::{ParamsOfProcessMessage, ProcessingEvent, ResultOfProcessMessage};
+use crate::tests::{TestClient, HELLO};
+
+#[test]
+fn graphql_parsing() {
+    let operation = graphql::parse(
+        "query accounts($p1: AccountFilter, $p2: [QueryOrderBy]) {\n\
+         q1: accounts(filter: $p1, orderBy: $p2, limit: 1) { id balance(format: DEC) }\n\
+         }",
+        &json!({
+            "p1": { "id": { "eq": "0:1" } },
+            "p2": [{ "path": "id", "direction": "ASC" }],
+        }),
+    )
+    .unwrap();
+    assert_eq!(operation.kind, OperationKind::Query);
+    assert_eq!(operation.fields.len(), 1);
+    let field = &operation.fields[0];
+    assert_eq!(field.key(), "q1");
+    assert_eq!(field.name, "accounts");
+    assert_eq!(field.argument("filter"), &json!({ "id": { "eq": "0:1" } }));
+    assert_eq!(field.argument("limit"), &json!(1));
+    assert_eq!(field.selection.len(), 2);
+    assert_eq!(field.selection[1].argument("format"), &json!("DEC"));
+
+    assert!(graphql::parse("{ info { ...Info } }", &Value::Null).is_err());
+}
+
+#[test]
+fn store_filters() {
+    let document = json!({
+        "id": "1",
+        "balance": "0x100",
+        "out_msgs": ["a", "b"],
+        "prev_ref": { "root_hash": "abc" },
+    });
+    assert!(store::matches(&document, &json!({ "balance": { "gt": "255" } })));
+    assert!(!store::matches(&document, &json!({ "balance": { "lt": 256 } })));
+    assert!(store::matches(&document, &json!({ "out_msgs": { "any": { "eq": "b" } } })));
+    assert!(store::matches(&document, &json!({ "prev_ref": { "root_hash": { "eq": "abc" } } })));
+    assert!(store::matches(
+        &document,
+        &json!({ "id": { "eq": "2" }, "OR": { "id": { "in": ["1", "3"] } } })
+    ));
+    assert!(!store::matches(&document, &json!({ "id": { "notIn": ["1"] } })));
+}
+
+#[tokio::test(core_threads = 2)]
+async fn emulator_process_message() {
+    let emulator = BlockchainEmulator::new("emulator_process_message", None)
+        .await
+        .unwrap();
+    assert!(BlockchainEmulator::new("emulator_process_message", None).await.is_err());
+
+    let client = TestClient::new_with_config(json!({
+        "network": {
+            "endpoints": [emulator.endpoint()],
+        }
+    }));
+    let keys = client.generate_sign_keys();
+    let (abi, tvc) = TestClient::package(HELLO, None);
+    let deploy_params = ParamsOfEncodeMessage {
+        abi: abi.clone(),
+        address: None,
+        deploy_set: Some(DeploySet {
+            tvc,
+            ..Default::default()
+        }),
+        call_set: CallSet::some_with_function("constructor"),
+        signer: Signer::Keys { keys: keys.clone() },
+        processing_try_index: None,
+    };
+    let address = client
+        .encode_message(deploy_params.clone())
+        .await
+        .unwrap()
+        .address;
+    emulator.topup(&address, 1_000_000_000).await.unwrap();
+
+    let callback = |_: ProcessingEvent, _: u32| async {};
+    let result: ResultOfProcessMessage = client
+        .net_process_message(
+            ParamsOfProcessMessage {
+                message_encode_params: deploy_params,
+                send_events: false,
+                send_state_events: None,
+            },
+            callback,
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.transaction["account_addr"], address);
+
+    let result: ResultOfProcessMessage = client
+        .net_process_message(
+            ParamsOfProcessMessage {
+                message_encode_params: ParamsOfEncodeMessage {
+                    abi: abi.clone(),
+                    address: Some(address.clone()),
+                    deploy_set: None,
+                    call_set: CallSet::some_with_function("touch"),
+                    signer: Signer::Keys { keys: keys.clone() },
+                    processing_try_index: None,
+                },
+                send_events: false,
+                send_state_events: None,
+            },
+            callback,
+        )
+        .await
+        .unwrap();
+    assert!(result.fees.total_account_fees > 0);
+
+    let accounts: ResultOfQueryCollection = client
+        .request_async(
+            "net.query_collection",
+            ParamsOfQueryCollection {
+                collection: "accounts".to_owned(),
+                filter: Some(json!({ "id": { "eq": address } })),
+                result: "id acc_type balance(format: DEC)".to_owned(),
+                limit: None,
+                order: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(accounts.result.len(), 1);
+    assert_eq!(accounts.result[0]["acc_type"], 1);
+
+    let transactions: ResultOfQueryCollection = client
+        .request_async(
+            "net.query_collection",
+            ParamsOfQueryCollection {
+                collection: "transactions".to_owned(),
+                filter: Some(json!({ "account_addr": { "eq": address } })),
+                result: "id in_message { msg_type }".to_owned(),
+                limit: None,
+                order: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(transactions.result.len(), 2);
+    assert_eq!(transactions.result[0]["in_message"]["msg_type"], 1);
+}
@@ -20,6 +20,9 @@ use std::sync::atomic::{AtomicI64, AtomicU32, AtomicU64, Ordering};
 
 const V_0_39_0: u32 = 39000;
 
+/// Endpoints with this scheme are served by the in-process blockchain emulator
+pub(crate) const EMULATOR_URL_SCHEME: &str = "emulator://";
+
 pub(crate) struct Endpoint {
     pub query_url: String,
     pub subscription_url: String,
@@ -55,7 +58,10 @@ impl Endpoint {
     }
 
     fn expand_address(base_url: &str) -> String {
-        let base_url = if base_url.starts_with("http://") || base_url.starts_with("https://") {
+        let base_url = if base_url.starts_with("http://")
+            || base_url.starts_with("https://")
+            || base_url.starts_with(EMULATOR_URL_SCHEME)
+        {
             base_url.to_owned()
         } else {
             format!("https://{}", base_url)
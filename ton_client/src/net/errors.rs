@@ -18,6 +18,7 @@ pub enum ErrorCode {
     NoEndpointsProvided = 612,
     GraphqlWebsocketInitError = 613,
     NetworkModuleResumed = 614,
+    EmulatorError = 615,
 }
 
 pub struct Error;
@@ -143,4 +144,11 @@ impl Error {
             "Network module has been resumed".to_owned(),
         )
     }
+
+    pub fn emulator_error<E: Display>(err: E) -> ClientError {
+        error(
+            ErrorCode::EmulatorError,
+            format!("Blockchain emulator error: {}", err),
+        )
+    }
 }
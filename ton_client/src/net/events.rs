@@ -0,0 +1,144 @@
+/*
+* Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+*
+* Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+* this file except in compliance with the License.
+*
+* Unless required by applicable law or agreed to in writing, software
+* distributed under the License is distributed on an "AS IS" BASIS,
+* WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+* See the License for the specific TON DEV software governing permissions and
+* limitations under the License.
+*/
+
+use crate::abi::registry::ParsedAbi;
+use crate::abi::{decode_message::decode_body, Abi, MessageBodyType};
+use crate::boc::internal::deserialize_object_from_boc;
+use crate::client::ClientContext;
+use crate::encoding::account_decode;
+use crate::error::ClientResult;
+use crate::net::{
+    subscribe_collection, ParamsOfSubscribeCollection, ResultOfSubscribeCollection,
+    MESSAGES_COLLECTION,
+};
+use futures::Future;
+use serde_json::Value;
+use std::sync::Arc;
+use ton_block::Message;
+
+const EVENT_MESSAGE_FIELDS: &str = "id boc created_lt created_at src_transaction { id }";
+const EXT_OUT_MSG_TYPE: u8 = 2;
+
+#[derive(Serialize, Deserialize, ApiType, Default, Clone)]
+pub struct ParamsOfSubscribeEvents {
+    /// Address of the contract emitting events
+    pub address: String,
+    /// Contract ABI used to decode events
+    pub abi: Abi,
+    /// Names of the events to subscribe to. All ABI events are reported if not specified
+    pub event_names: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, Clone, Debug, PartialEq)]
+pub struct DecodedEvent {
+    /// Event name
+    pub name: String,
+    /// Event parameters
+    pub value: Option<Value>,
+    /// Event message ID
+    pub message_id: String,
+    /// ID of the transaction emitted the event
+    pub transaction_id: Option<String>,
+    /// Logical time of the event message creation
+    pub created_lt: String,
+    /// Creation time of the event message
+    pub created_at: u32,
+}
+
+async fn decode_event(
+    context: &ClientContext,
+    abi: &ParsedAbi,
+    event_names: &[String],
+    message: Value,
+) -> ClientResult<Option<DecodedEvent>> {
+    let boc = message["boc"].as_str().unwrap_or_default();
+    let body = match deserialize_object_from_boc::<Message>(context, boc, "message")
+        .await?
+        .object
+        .body()
+    {
+        Some(body) => body,
+        None => return Ok(None),
+    };
+    // messages of other ABIs or non-event messages are skipped
+    let decoded = match decode_body(&abi.contract, body, false) {
+        Ok(decoded) if decoded.body_type == MessageBodyType::Event => decoded,
+        _ => return Ok(None),
+    };
+    if !event_names.is_empty() && !event_names.contains(&decoded.name) {
+        return Ok(None);
+    }
+    Ok(Some(DecodedEvent {
+        name: decoded.name,
+        value: decoded.value,
+        message_id: message["id"].as_str().unwrap_or_default().to_owned(),
+        transaction_id: message["src_transaction"]["id"].as_str().map(str::to_owned),
+        created_lt: message["created_lt"].as_str().unwrap_or_default().to_owned(),
+        created_at: message["created_at"].as_u64().unwrap_or_default() as u32,
+    }))
+}
+
+/// Subscribes to the contract events.
+///
+/// Creates subscription to the external outbound messages of the contract,
+/// decodes each message with the ABI and reports events with the specified names.
+/// Subscription must be closed with `unsubscribe`.
+pub async fn subscribe_events<F: Future<Output = ()> + Send>(
+    context: Arc<ClientContext>,
+    params: ParamsOfSubscribeEvents,
+    callback: impl Fn(ClientResult<DecodedEvent>) -> F + Send + Sync + 'static,
+) -> ClientResult<ResultOfSubscribeCollection> {
+    let abi = params.abi.resolve(&context)?;
+    let event_names = params.event_names.unwrap_or_default();
+    for name in &event_names {
+        if abi.contract.events().get(name).is_none() {
+            return Err(crate::abi::Error::invalid_abi(format!(
+                "event `{}` is not found in the ABI",
+                name
+            )));
+        }
+    }
+    let address = account_decode(&params.address)?.to_string();
+
+    let callback = Arc::new(callback);
+    let event_context = context.clone();
+    subscribe_collection(
+        context,
+        ParamsOfSubscribeCollection {
+            collection: MESSAGES_COLLECTION.to_owned(),
+            filter: Some(json!({
+                "src": { "eq": address },
+                "msg_type": { "eq": EXT_OUT_MSG_TYPE },
+            })),
+            result: EVENT_MESSAGE_FIELDS.to_owned(),
+        },
+        move |result| {
+            let context = event_context.clone();
+            let abi = abi.clone();
+            let event_names = event_names.clone();
+            let callback = callback.clone();
+            async move {
+                let event = match result {
+                    Ok(result) => decode_event(&context, &abi, &event_names, result.result).await,
+                    Err(err) => Err(err),
+                };
+                match event {
+                    Ok(Some(event)) => callback(Ok(event)).await,
+                    Ok(None) => {}
+                    Err(err) => callback(Err(err)).await,
+                }
+            }
+        },
+    )
+    .await
+}
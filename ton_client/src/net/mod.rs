@@ -12,8 +12,13 @@
 */
 
 pub use batch::{batch_query, ParamsOfBatchQuery, ResultOfBatchQuery};
+#[cfg(not(feature = "wasm"))]
+pub use emulator::BlockchainEmulator;
+#[cfg(not(feature = "wasm"))]
+pub(crate) use emulator::find_emulator;
 pub(crate) use endpoint::Endpoint;
 pub use errors::{Error, ErrorCode};
+pub use events::{subscribe_events, DecodedEvent, ParamsOfSubscribeEvents};
 pub use queries::{
     aggregate_collection, query, query_collection, query_counterparties, wait_for_collection,
     ParamsOfQuery, ParamsOfWaitForCollection, ResultOfAggregateCollection, ResultOfQuery,
@@ -42,8 +47,11 @@ use crate::client::ClientContext;
 use crate::error::ClientResult;
 
 pub(crate) mod batch;
+#[cfg(not(feature = "wasm"))]
+mod emulator;
 mod endpoint;
 mod errors;
+pub(crate) mod events;
 mod gql;
 pub(crate) mod queries;
 mod server_link;
@@ -349,6 +349,7 @@ async fn subscribe_for_transactions_with_addresses() {
             ParamsOfProcessMessage {
                 message_encode_params: deploy_params,
                 send_events: false,
+                send_state_events: None,
             },
             TestClient::default_callback,
         )
@@ -431,6 +432,7 @@ async fn subscribe_for_transactions_with_addresses() {
                     call_set: CallSet::some_with_function("touch"),
                 },
                 send_events: false,
+                send_state_events: None,
             },
             TestClient::default_callback,
         )
@@ -1124,3 +1126,90 @@ async fn order_by_fallback() {
     }
     assert!(result.is_err());
 }
+
+#[tokio::test(core_threads = 2)]
+async fn subscribe_events() {
+    let emulator = BlockchainEmulator::new("subscribe_events", None).await.unwrap();
+    let client = TestClient::new_with_config(json!({
+        "network": {
+            "endpoints": [emulator.endpoint()],
+        }
+    }));
+    let keys = client.generate_sign_keys();
+    let (abi, tvc) = TestClient::package(EVENTS, Some(2));
+    let deploy_params = ParamsOfEncodeMessage {
+        abi: abi.clone(),
+        address: None,
+        deploy_set: DeploySet::some_with_tvc(tvc),
+        call_set: Some(CallSet {
+            function_name: "constructor".into(),
+            header: Some(crate::abi::FunctionHeader {
+                pubkey: Some(keys.public.clone()),
+                ..Default::default()
+            }),
+            input: None,
+        }),
+        signer: Signer::Keys { keys: keys.clone() },
+        processing_try_index: None,
+    };
+    let address = client.encode_message(deploy_params.clone()).await.unwrap().address;
+    emulator.topup(&address, 1_000_000_000).await.unwrap();
+    client
+        .net_process_message(
+            ParamsOfProcessMessage {
+                message_encode_params: deploy_params,
+                send_events: false,
+                send_state_events: None,
+            },
+            TestClient::default_callback,
+        )
+        .await
+        .unwrap();
+
+    let events = Arc::new(Mutex::new(vec![]));
+    let events_copy = events.clone();
+    let callback = move |result: DecodedEvent, response_type: SubscriptionResponseType| {
+        assert!(matches!(response_type, SubscriptionResponseType::Ok));
+        let events = events_copy.clone();
+        async move {
+            events.lock().await.push(result);
+        }
+    };
+    let handle: ResultOfSubscribeCollection = client
+        .request_async_callback(
+            "net.subscribe_events",
+            ParamsOfSubscribeEvents {
+                address: address.clone(),
+                abi: abi.clone(),
+                event_names: Some(vec!["EventThrown".to_owned()]),
+            },
+            callback,
+        )
+        .await
+        .unwrap();
+
+    let result = client
+        .net_process_function(
+            address.clone(),
+            abi.clone(),
+            "returnValue",
+            json!({ "id": "0x1" }),
+            Signer::Keys { keys },
+        )
+        .await
+        .unwrap();
+
+    // give some time for subscription to receive all data
+    tokio::time::delay_for(std::time::Duration::from_millis(500)).await;
+
+    let events = events.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].name, "EventThrown");
+    assert_eq!(events[0].value, Some(json!({ "id": crate::utils::conversion::abi_uint(1, 256) })));
+    assert_eq!(
+        events[0].transaction_id,
+        result.transaction["id"].as_str().map(str::to_owned)
+    );
+
+    let _: () = client.request_async("net.unsubscribe", handle).await.unwrap();
+}
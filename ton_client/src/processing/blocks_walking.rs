@@ -14,9 +14,10 @@
 use super::Error;
 use crate::client::ClientContext;
 use crate::error::ClientResult;
-use crate::net::{OrderBy, ParamsOfQueryCollection, ParamsOfWaitForCollection, SortDirection, BLOCKS_COLLECTION, Endpoint};
+use crate::net::{OrderBy, ParamsOfQueryCollection, ParamsOfWaitForCollection, SortDirection, BLOCKS_COLLECTION, Endpoint, ServerLink};
+use std::collections::HashMap;
 use std::sync::Arc;
-use ton_block::MsgAddressInt;
+use ton_block::{AccountIdPrefixFull, MsgAddressInt, ShardIdent};
 use ton_block::MASTERCHAIN_ID;
 
 pub const BLOCK_FIELDS: &str = r#"
@@ -36,7 +37,19 @@ pub(crate) async fn find_last_shard_block(
     address: &MsgAddressInt,
     endpoint: Option<Endpoint>,
 ) -> ClientResult<ton_sdk::BlockId> {
-    let workchain = address.get_workchain_id();
+    let mut blocks =
+        find_last_shard_blocks(context, std::slice::from_ref(address), endpoint).await?;
+    Ok(blocks.remove(0))
+}
+
+/// Finds the last shard blocks for several accounts at once.
+///
+/// The last masterchain block is fetched only once for all the accounts.
+pub(crate) async fn find_last_shard_blocks(
+    context: &Arc<ClientContext>,
+    addresses: &[MsgAddressInt],
+    endpoint: Option<Endpoint>,
+) -> ClientResult<Vec<ton_sdk::BlockId>> {
     let server_link = context.get_server_link()?;
 
     // if account resides in masterchain, then starting point is last masterchain block
@@ -57,68 +70,32 @@ pub(crate) async fn find_last_shard_block(
         .await?;
     debug!("Last block {}", blocks[0]["id"]);
 
-    if MASTERCHAIN_ID == workchain {
-        // if account resides in masterchain, then starting point is last masterchain block
-        blocks[0]["id"]
-            .as_str()
-            .map(|val| val.to_owned().into())
-            .ok_or(Error::block_not_found(
-                "No masterchain block found".to_owned(),
-            ))
-    } else {
-        // if account is from other chains, then starting point is last account's shard block
-        // To obtain it we take masterchain block to get shards configuration and select matching shard
-        if blocks[0].is_null() {
-            // TON OS SE case - no masterchain, no sharding. Check that only one shard
-            let blocks = server_link.query_collection(ParamsOfQueryCollection {
-                    collection: BLOCKS_COLLECTION.to_string(),
-                    filter: Some(json!({
-                    "workchain_id": { "eq": workchain },
-                    })),
-                    result: "after_merge shard".to_string(),
-                    order: Some(vec![OrderBy {
-                        path: "seq_no".to_owned(),
-                        direction: SortDirection::DESC,
-                    }]),
-                    limit: Some(1),
-                }, endpoint.clone())
-                .await?;
-
-            if blocks[0].is_null() {
-                return Err(Error::block_not_found(format!(
-                    "No blocks for workchain {} found",
-                    workchain
-                )));
-            }
-            // if workchain is sharded, then it is not TON OS SE and masterchain blocks missing is error
-            if blocks[0]["after_merge"] == true || blocks[0]["shard"] != "8000000000000000" {
-                return Err(Error::block_not_found(
-                    "No masterchain block found".to_owned(),
-                ));
-            }
-
-            // Take last block by seq_no
-            let blocks = server_link.query_collection(ParamsOfQueryCollection {
-                    collection: BLOCKS_COLLECTION.to_string(),
-                    filter: Some(json!({
-                    "workchain_id": { "eq": workchain },
-                    "shard": { "eq": "8000000000000000" },
-                    })),
-                    result: "id".to_string(),
-                    order: Some(vec![OrderBy {
-                        path: "seq_no".to_owned(),
-                        direction: SortDirection::DESC,
-                    }]),
-                    limit: Some(1),
-                }, endpoint)
-                .await?;
+    let mut se_blocks = HashMap::new();
+    let mut result = Vec::with_capacity(addresses.len());
+    for address in addresses {
+        let workchain = address.get_workchain_id();
+        let block_id = if MASTERCHAIN_ID == workchain {
+            // if account resides in masterchain, then starting point is last masterchain block
             blocks[0]["id"]
                 .as_str()
                 .map(|val| val.to_owned().into())
                 .ok_or(Error::block_not_found(
-                    "No starting TON OS SE block found".to_owned(),
-                ))
+                    "No masterchain block found".to_owned(),
+                ))?
+        } else if blocks[0].is_null() {
+            // TON OS SE case - no masterchain, no sharding
+            match se_blocks.get(&workchain) {
+                Some(block_id) => block_id.clone(),
+                None => {
+                    let block_id =
+                        find_last_se_block(server_link, workchain, endpoint.clone()).await?;
+                    se_blocks.insert(workchain, block_id.clone());
+                    block_id
+                }
+            }
         } else {
+            // if account is from other chains, then starting point is last account's shard block
+            // To obtain it we take masterchain block to get shards configuration and select matching shard
             let shards =
                 blocks[0]["master"]["shard_hashes"]
                     .as_array()
@@ -140,9 +117,67 @@ pub(crate) async fn find_last_shard_block(
             shard_block["descr"]["root_hash"]
                 .as_str()
                 .map(|val| val.to_owned().into())
-                .ok_or(Error::invalid_data("No `root_hash` field in shard descr"))
-        }
+                .ok_or(Error::invalid_data("No `root_hash` field in shard descr"))?
+        };
+        result.push(block_id);
+    }
+    Ok(result)
+}
+
+async fn find_last_se_block(
+    server_link: &ServerLink,
+    workchain: i32,
+    endpoint: Option<Endpoint>,
+) -> ClientResult<ton_sdk::BlockId> {
+    // Check that only one shard
+    let blocks = server_link.query_collection(ParamsOfQueryCollection {
+            collection: BLOCKS_COLLECTION.to_string(),
+            filter: Some(json!({
+            "workchain_id": { "eq": workchain },
+            })),
+            result: "after_merge shard".to_string(),
+            order: Some(vec![OrderBy {
+                path: "seq_no".to_owned(),
+                direction: SortDirection::DESC,
+            }]),
+            limit: Some(1),
+        }, endpoint.clone())
+        .await?;
+
+    if blocks[0].is_null() {
+        return Err(Error::block_not_found(format!(
+            "No blocks for workchain {} found",
+            workchain
+        )));
+    }
+    // if workchain is sharded, then it is not TON OS SE and masterchain blocks missing is error
+    if blocks[0]["after_merge"] == true || blocks[0]["shard"] != "8000000000000000" {
+        return Err(Error::block_not_found(
+            "No masterchain block found".to_owned(),
+        ));
     }
+
+    // Take last block by seq_no
+    let blocks = server_link.query_collection(ParamsOfQueryCollection {
+            collection: BLOCKS_COLLECTION.to_string(),
+            filter: Some(json!({
+            "workchain_id": { "eq": workchain },
+            "shard": { "eq": "8000000000000000" },
+            })),
+            result: "id".to_string(),
+            order: Some(vec![OrderBy {
+                path: "seq_no".to_owned(),
+                direction: SortDirection::DESC,
+            }]),
+            limit: Some(1),
+        }, endpoint)
+        .await?;
+    blocks[0]["id"]
+        .as_str()
+        .map(|val| val.to_owned().into())
+        .ok_or(Error::block_not_found(
+            "No starting TON OS SE block found".to_owned(),
+        ))
 }
 
 pub async fn wait_next_block(
@@ -207,3 +242,15 @@ fn check_shard_match(
     ton_sdk::Contract::check_shard_match(shard_descr, address)
         .map_err(|err| Error::can_not_check_block_shard(err))
 }
+
+/// Checks that the account belongs to the shard of the block
+pub(crate) fn block_contains_account(
+    block: &ton_sdk::Block,
+    address: &MsgAddressInt,
+) -> ClientResult<bool> {
+    let shard = ShardIdent::with_tagged_prefix(block.shard_descr.workchain_id, block.shard_descr.shard)
+        .map_err(|err| Error::can_not_check_block_shard(err))?;
+    let prefix =
+        AccountIdPrefixFull::prefix(address).map_err(|err| Error::can_not_check_block_shard(err))?;
+    Ok(shard.contains_full_prefix(&prefix))
+}
@@ -0,0 +1,134 @@
+/*
+ * Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+ *
+ * Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+ * this file except in compliance with the License.
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific TON DEV software governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::fetching::fetch_account;
+use super::Error;
+use crate::abi::{encode_message, ParamsOfEncodeMessage};
+use crate::boc::internal::deserialize_object_from_boc;
+use crate::client::ClientContext;
+use crate::encoding::account_decode;
+use crate::error::ClientResult;
+use crate::tvm::{
+    run_executor, AccountForExecutor, ExecutionOptions, ParamsOfRunExecutor, TransactionFees,
+};
+use std::convert::TryFrom;
+use std::sync::Arc;
+use ton_block::Message;
+
+#[derive(Serialize, Deserialize, ApiType, Clone, Default)]
+pub struct ParamsOfEstimateFees {
+    /// Message encode parameters.
+    pub message_encode_params: ParamsOfEncodeMessage,
+
+    /// Account BOC. Encoded as base64.
+    ///
+    /// If not specified, the account is fetched from the network.
+    /// If the account doesn't exist and the message contains deploy data,
+    /// the fees are estimated for the uninitialized account.
+    pub account: Option<String>,
+
+    /// Execution options, e.g. blockchain config BOC.
+    pub execution_options: Option<ExecutionOptions>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Debug, PartialEq, Clone, Default)]
+pub struct ResultOfEstimateFees {
+    /// Destination address of the message.
+    pub address: String,
+
+    /// Estimated transaction fees.
+    pub fees: TransactionFees,
+
+    /// Forward fees of the internal messages produced by the transaction,
+    /// in the order of the transaction out messages. `0` for the external
+    /// outbound messages.
+    pub out_messages_fwd_fees: Vec<u64>,
+}
+
+async fn resolve_account(
+    context: &Arc<ClientContext>,
+    params: &ParamsOfEstimateFees,
+    address: &str,
+) -> ClientResult<AccountForExecutor> {
+    let boc = match &params.account {
+        Some(boc) => boc.clone(),
+        None => {
+            let address = account_decode(address)?;
+            match fetch_account(context.clone(), &address, "boc").await {
+                Ok(account) => account["boc"].as_str().unwrap_or_default().to_owned(),
+                Err(err)
+                    if err.code == crate::tvm::ErrorCode::AccountMissing as u32
+                        && params.message_encode_params.deploy_set.is_some() =>
+                {
+                    return Ok(AccountForExecutor::Uninit)
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    };
+    Ok(AccountForExecutor::Account {
+        boc,
+        unlimited_balance: Some(true),
+    })
+}
+
+/// Estimates fees of the message processing without sending it.
+///
+/// Encodes the message, runs it on the local transaction executor
+/// with the unlimited account balance and returns the fees the account
+/// would pay. Works both for the calls of the deployed contracts and
+/// for the deploy messages to the uninitialized or not existing accounts.
+///
+/// Unlike `tvm.run_executor`, the account is fetched from the network
+/// if it isn't specified, so the function is ready to be used for the fee
+/// preview before the message is sent.
+#[api_function]
+pub async fn estimate_fees(
+    context: Arc<ClientContext>,
+    params: ParamsOfEstimateFees,
+) -> ClientResult<ResultOfEstimateFees> {
+    let encoded = encode_message(context.clone(), params.message_encode_params.clone()).await?;
+    let account = resolve_account(&context, &params, &encoded.address).await?;
+
+    let result = run_executor(
+        context.clone(),
+        ParamsOfRunExecutor {
+            message: encoded.message,
+            account,
+            execution_options: params.execution_options,
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    let mut out_messages_fwd_fees = Vec::new();
+    for message in &result.out_messages {
+        let message: Message = deserialize_object_from_boc(&context, message, "message")
+            .await?
+            .object;
+        let fwd_fee = message
+            .int_header()
+            .map(|header| header.fwd_fee.0)
+            .unwrap_or_default();
+        out_messages_fwd_fees.push(u64::try_from(fwd_fee).map_err(|_| {
+            Error::invalid_message_boc(format!("forward fee {} does not fit into 64 bits", fwd_fee))
+        })?);
+    }
+
+    Ok(ResultOfEstimateFees {
+        address: encoded.address,
+        fees: result.fees,
+        out_messages_fwd_fees,
+    })
+}
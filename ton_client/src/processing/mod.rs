@@ -17,16 +17,26 @@ mod tests;
 
 pub(crate) mod blocks_walking;
 mod errors;
+pub(crate) mod estimate_fees;
 mod fetching;
 mod internal;
 pub(crate) mod parsing;
 pub(crate) mod process_message;
+pub(crate) mod process_messages;
 pub(crate) mod send_message;
 mod types;
 pub(crate) mod wait_for_transaction;
 
 pub use errors::{Error, ErrorCode};
-pub use process_message::{process_message, ParamsOfProcessMessage};
+pub use estimate_fees::{estimate_fees, ParamsOfEstimateFees, ResultOfEstimateFees};
+pub use process_message::{
+    process_message, resume_processing, ParamsOfProcessMessage, ParamsOfResumeProcessing,
+};
+pub use process_messages::{
+    process_messages, ParamsOfProcessMessages, ProcessedMessage, ResultOfProcessMessages,
+};
 pub use send_message::{send_message, ParamsOfSendMessage, ResultOfSendMessage};
-pub use types::{DecodedOutput, ProcessingEvent, ProcessingResponseType, ResultOfProcessMessage};
+pub use types::{
+    DecodedOutput, ProcessingEvent, ProcessingResponseType, ProcessingState, ResultOfProcessMessage,
+};
 pub use wait_for_transaction::{wait_for_transaction, ParamsOfWaitForTransaction};
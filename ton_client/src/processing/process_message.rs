@@ -1,10 +1,11 @@
-use crate::abi::ParamsOfEncodeMessage;
+use crate::abi::{Abi, ParamsOfEncodeMessage, Signer};
 use crate::client::ClientContext;
 use crate::error::{AddNetworkUrl, ClientResult};
-use crate::processing::internal::can_retry_expired_message;
+use crate::processing::internal::{can_retry_expired_message, get_message_expiration_time};
+use crate::processing::send_message::send_message_internal;
 use crate::processing::{
-    send_message, wait_for_transaction, ErrorCode, ParamsOfSendMessage, ParamsOfWaitForTransaction,
-    ProcessingEvent, ResultOfProcessMessage, ResultOfSendMessage,
+    wait_for_transaction, ErrorCode, ParamsOfSendMessage, ParamsOfWaitForTransaction,
+    ProcessingEvent, ProcessingState, ResultOfProcessMessage, ResultOfSendMessage,
 };
 use crate::tvm::StdContractError;
 use std::sync::Arc;
@@ -16,6 +17,31 @@ pub struct ParamsOfProcessMessage {
 
     /// Flag for requesting events sending
     pub send_events: bool,
+
+    /// Flag for requesting `ProcessingStateChanged` events.
+    ///
+    /// Events are sent only if `send_events` is also set. Default is `false`.
+    pub send_state_events: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, Debug)]
+pub struct ParamsOfResumeProcessing {
+    /// Processing state received with the last `ProcessingStateChanged` event.
+    pub state: ProcessingState,
+
+    /// Signer of the message. Must be the same as the one used to start the processing.
+    ///
+    /// Required to create a new message if the current one expires, since the
+    /// processing state doesn't contain the signer.
+    pub signer: Signer,
+
+    /// Flag for requesting events sending
+    pub send_events: bool,
+
+    /// Flag for requesting `ProcessingStateChanged` events.
+    ///
+    /// Events are sent only if `send_events` is also set. Default is `false`.
+    pub send_state_events: Option<bool>,
 }
 
 pub async fn process_message<F: futures::Future<Output = ()> + Send>(
@@ -23,42 +49,120 @@ pub async fn process_message<F: futures::Future<Output = ()> + Send>(
     params: ParamsOfProcessMessage,
     callback: impl Fn(ProcessingEvent) -> F + Send + Sync + 'static,
 ) -> ClientResult<ResultOfProcessMessage> {
-    let abi = params.message_encode_params.abi.clone();
+    process(
+        context,
+        params.message_encode_params,
+        params.send_events,
+        params.send_state_events.unwrap_or(false),
+        None,
+        callback,
+    )
+    .await
+}
+
+pub async fn resume_processing<F: futures::Future<Output = ()> + Send>(
+    context: Arc<ClientContext>,
+    params: ParamsOfResumeProcessing,
+    callback: impl Fn(ProcessingEvent) -> F + Send + Sync + 'static,
+) -> ClientResult<ResultOfProcessMessage> {
+    let message_encode_params = ParamsOfEncodeMessage {
+        signer: params.signer,
+        ..params.state.message_encode_params.clone()
+    };
+    process(
+        context,
+        message_encode_params,
+        params.send_events,
+        params.send_state_events.unwrap_or(false),
+        Some(params.state),
+        callback,
+    )
+    .await
+}
+
+async fn process<F: futures::Future<Output = ()> + Send>(
+    context: Arc<ClientContext>,
+    message_encode_params: ParamsOfEncodeMessage,
+    send_events: bool,
+    send_state_events: bool,
+    mut resumed: Option<ProcessingState>,
+    callback: impl Fn(ProcessingEvent) -> F + Send + Sync + 'static,
+) -> ClientResult<ResultOfProcessMessage> {
+    let abi = message_encode_params.abi.clone();
+    // signer is not stored in the state, the app passes it to `resume_processing`.
+    // ABI handle is replaced with the ABI itself, since handles don't survive client restart
+    let state_encode_params = ParamsOfEncodeMessage {
+        abi: Abi::Json(abi.json(&context)?),
+        signer: Signer::None,
+        ..message_encode_params.clone()
+    };
+    let send_state_events = send_events && send_state_events;
 
-    let mut try_index = 0;
+    let mut try_index = resumed.as_ref().map(|state| state.try_index).unwrap_or(0);
     loop {
-        // Encode message
-        let mut encode_params = params.message_encode_params.clone();
-        encode_params.processing_try_index = Some(try_index);
-        let message = crate::abi::encode_message(context.clone(), encode_params)
-            .await?
-            .message;
+        let (message, shard_block_id, sending_endpoints) = if let Some(state) = resumed.take() {
+            // Message is already sent, continue with waiting
+            (state.message, state.shard_block_id, state.sending_endpoints)
+        } else {
+            // Encode message
+            let mut encode_params = message_encode_params.clone();
+            encode_params.processing_try_index = Some(try_index);
+            let encoded = crate::abi::encode_message(context.clone(), encode_params).await?;
+            let state = ProcessingState {
+                message_encode_params: state_encode_params.clone(),
+                try_index,
+                message_expiration_time: get_message_expiration_time(
+                    context.clone(),
+                    Some(&abi),
+                    &encoded.message,
+                )
+                .await?,
+                message: encoded.message.clone(),
+                message_id: encoded.message_id,
+                shard_block_id: String::new(),
+                sending_endpoints: None,
+            };
 
-        // Send
-        let ResultOfSendMessage {
-            shard_block_id,
-            sending_endpoints,
-        } = send_message(
-            context.clone(),
-            ParamsOfSendMessage {
-                message: message.clone(),
-                abi: Some(abi.clone()),
-                send_events: params.send_events,
-            },
-            &callback,
-        )
-        .await
-        .add_network_url_from_context(&context)
-        .await?;
+            // Send
+            let ResultOfSendMessage {
+                shard_block_id,
+                sending_endpoints,
+            } = send_message_internal(
+                context.clone(),
+                ParamsOfSendMessage {
+                    message: encoded.message.clone(),
+                    abi: Some(abi.clone()),
+                    send_events,
+                },
+                &callback,
+                None,
+                if send_state_events { Some(state.clone()) } else { None },
+            )
+            .await
+            .add_network_url_from_context(&context)
+            .await?;
+
+            if send_state_events {
+                callback(ProcessingEvent::ProcessingStateChanged {
+                    state: ProcessingState {
+                        shard_block_id: shard_block_id.clone(),
+                        sending_endpoints: Some(sending_endpoints.clone()),
+                        ..state
+                    },
+                })
+                .await;
+            }
+            (encoded.message, shard_block_id, Some(sending_endpoints))
+        };
 
         let wait_for = wait_for_transaction(
             context.clone(),
             ParamsOfWaitForTransaction {
                 message: message.clone(),
-                send_events: params.send_events,
+                send_events,
                 abi: Some(abi.clone()),
                 shard_block_id: shard_block_id.clone(),
-                sending_endpoints: Some(sending_endpoints),
+                sending_endpoints,
             },
             &callback,
         )
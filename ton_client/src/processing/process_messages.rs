@@ -0,0 +1,345 @@
+/*
+ * Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+ *
+ * Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+ * this file except in compliance with the License.
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific TON DEV software governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use crate::abi::ParamsOfEncodeMessage;
+use crate::client::ClientContext;
+use crate::encoding::account_decode;
+use crate::error::{AddNetworkUrl, ClientError, ClientResult};
+use crate::processing::blocks_walking::{block_contains_account, find_last_shard_blocks};
+use crate::processing::fetching::fetch_next_shard_block;
+use crate::processing::send_message::send_message_internal;
+use crate::processing::wait_for_transaction::WaitingMessage;
+use crate::processing::{
+    Error, ParamsOfSendMessage, ParamsOfWaitForTransaction, ProcessingEvent,
+    ResultOfProcessMessage,
+};
+use futures::stream::{self, FuturesUnordered};
+use futures::StreamExt;
+use std::collections::HashMap;
+use std::sync::Arc;
+use ton_sdk::Block;
+
+#[derive(Serialize, Deserialize, ApiType, Default, Debug)]
+pub struct ParamsOfProcessMessages {
+    /// Encode parameters of the messages to process.
+    pub messages: Vec<ParamsOfEncodeMessage>,
+
+    /// Flag for requesting events sending
+    pub send_events: bool,
+
+    /// Maximum number of messages being sent at the same time.
+    ///
+    /// Default is 10.
+    pub max_parallel_sends: Option<u32>,
+}
+
+const DEFAULT_MAX_PARALLEL_SENDS: u32 = 10;
+
+#[derive(Serialize, Deserialize, ApiType, Default, Debug, PartialEq, Clone)]
+pub struct ProcessedMessage {
+    /// Message processing result. `null` if the processing has failed.
+    pub result: Option<ResultOfProcessMessage>,
+
+    /// Message processing error. `null` if the processing has succeeded.
+    pub error: Option<ClientError>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, Debug, PartialEq, Clone)]
+pub struct ResultOfProcessMessages {
+    /// Processing results in the order of the `messages` parameter.
+    pub messages: Vec<ProcessedMessage>,
+}
+
+impl ProcessedMessage {
+    fn failed(error: ClientError) -> Self {
+        Self {
+            result: None,
+            error: Some(error),
+        }
+    }
+}
+
+impl From<ClientResult<ResultOfProcessMessage>> for ProcessedMessage {
+    fn from(result: ClientResult<ResultOfProcessMessage>) -> Self {
+        match result {
+            Ok(result) => Self {
+                result: Some(result),
+                error: None,
+            },
+            Err(error) => Self {
+                result: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+type WalkingResult = Vec<(usize, ClientResult<ResultOfProcessMessage>)>;
+
+/// Messages waiting for their transactions in the blocks following `shard_block_id`
+struct ShardWalker {
+    shard_block_id: String,
+    messages: Vec<(usize, WaitingMessage)>,
+}
+
+pub async fn process_messages<F: futures::Future<Output = ()> + Send>(
+    context: Arc<ClientContext>,
+    params: ParamsOfProcessMessages,
+    callback: impl Fn(ProcessingEvent) -> F + Send + Sync + 'static,
+) -> ClientResult<ResultOfProcessMessages> {
+    let mut results: Vec<Option<ProcessedMessage>> = vec![None; params.messages.len()];
+
+    // Encode messages
+    let mut encoded = Vec::new();
+    for (index, encode_params) in params.messages.iter().enumerate() {
+        let mut encode_params = encode_params.clone();
+        encode_params.processing_try_index = Some(0);
+        let result = crate::abi::encode_message(context.clone(), encode_params)
+            .await
+            .and_then(|result| {
+                Ok((account_decode(&result.address)?, result.message, result.message_id))
+            });
+        match result {
+            Ok((address, message, message_id)) => {
+                encoded.push((index, address, message, message_id))
+            }
+            Err(err) => results[index] = Some(ProcessedMessage::failed(err)),
+        }
+    }
+
+    // Fetch the starting shard blocks for all the messages at once
+    if params.send_events {
+        callback(ProcessingEvent::WillFetchFirstBlock {}).await;
+    }
+    let addresses: Vec<_> = encoded.iter().map(|(_, address, ..)| address.clone()).collect();
+    let shard_blocks = match find_last_shard_blocks(&context, &addresses, None).await {
+        Ok(shard_blocks) => shard_blocks,
+        Err(err) => {
+            if params.send_events {
+                callback(ProcessingEvent::FetchFirstBlockFailed { error: err.clone() }).await;
+            }
+            for (index, _, _, message_id) in encoded {
+                results[index] = Some(ProcessedMessage::failed(Error::fetch_first_block_failed(
+                    &err,
+                    &message_id,
+                )));
+            }
+            return Ok(ResultOfProcessMessages {
+                messages: results.into_iter().map(Option::unwrap_or_default).collect(),
+            });
+        }
+    };
+
+    // Send
+    let send_events = params.send_events;
+    let sending = encoded
+        .into_iter()
+        .zip(shard_blocks)
+        .map(|((index, _, message, _), shard_block_id)| {
+            let context = context.clone();
+            let abi = params.messages[index].abi.clone();
+            let callback = &callback;
+            async move {
+                let sent: ClientResult<(String, WaitingMessage)> = async {
+                    let sent = send_message_internal(
+                        context.clone(),
+                        ParamsOfSendMessage {
+                            message: message.clone(),
+                            abi: Some(abi.clone()),
+                            send_events,
+                        },
+                        callback,
+                        Some(shard_block_id.to_string()),
+                        None,
+                    )
+                    .await
+                    .add_network_url_from_context(&context)
+                    .await?;
+                    let waiting = WaitingMessage::new(
+                        &context,
+                        &message,
+                        Some(abi),
+                        Some(sent.sending_endpoints),
+                    )
+                    .await?;
+                    Ok((sent.shard_block_id, waiting))
+                }
+                .await;
+                (index, sent)
+            }
+        });
+    let max_parallel_sends = params
+        .max_parallel_sends
+        .unwrap_or(DEFAULT_MAX_PARALLEL_SENDS)
+        .max(1) as usize;
+    let sent: Vec<_> = stream::iter(sending)
+        .buffer_unordered(max_parallel_sends)
+        .collect()
+        .await;
+    let mut walkers = HashMap::<String, ShardWalker>::new();
+    for (index, sent) in sent {
+        match sent {
+            Ok((shard_block_id, waiting)) => walkers
+                .entry(shard_block_id.clone())
+                .or_insert_with(|| ShardWalker {
+                    shard_block_id,
+                    messages: Vec::new(),
+                })
+                .messages
+                .push((index, waiting)),
+            Err(err) => results[index] = Some(ProcessedMessage::failed(err)),
+        }
+    }
+
+    // Wait for transactions walking each shard block only once for all its messages.
+    // Messages sent at different blocks of the same shard are walked separately
+    // until they reach the same block, then the walkers are merged
+    let mut pending: Vec<ShardWalker> = walkers.into_iter().map(|(_, walker)| walker).collect();
+    let mut walking = FuturesUnordered::new();
+    // messages joined the walkers which are fetching the next block, by the walker block
+    let mut joined = HashMap::<String, Vec<(usize, WaitingMessage)>>::new();
+    let mut finished = Vec::new();
+    loop {
+        for walker in pending.drain(..) {
+            match joined.get_mut(&walker.shard_block_id) {
+                Some(messages) => messages.extend(walker.messages),
+                None => {
+                    joined.insert(walker.shard_block_id.clone(), Vec::new());
+                    walking.push(fetch_next_block(&context, walker, send_events, &callback));
+                }
+            }
+        }
+        let (mut walker, block) = match walking.next().await {
+            Some(fetched) => fetched,
+            None => break,
+        };
+        if let Some(messages) = joined.remove(&walker.shard_block_id) {
+            walker.messages.extend(messages);
+        }
+        let (walked, next) = check_next_block(&context, walker, block).await;
+        finished.extend(walked);
+        pending.extend(next);
+    }
+    for (index, result) in finished {
+        results[index] = Some(result.into());
+    }
+
+    Ok(ResultOfProcessMessages {
+        messages: results.into_iter().map(Option::unwrap_or_default).collect(),
+    })
+}
+
+/// Fetches the block following the walker block in the shard of the walker messages.
+async fn fetch_next_block<F: futures::Future<Output = ()> + Send>(
+    context: &Arc<ClientContext>,
+    walker: ShardWalker,
+    send_events: bool,
+    callback: &(impl Fn(ProcessingEvent) -> F + Send + Sync),
+) -> (ShardWalker, ClientResult<Block>) {
+    let messages = &walker.messages;
+    let shard_block_id = &walker.shard_block_id;
+    let (_, first) = &messages[0];
+    let max_block_time = messages
+        .iter()
+        .map(|(_, message)| message.max_block_time)
+        .max()
+        .unwrap_or_default();
+    let processing_timeout = context.config.network.message_processing_timeout;
+    let now = context.env.now_ms();
+    let fetch_block_timeout =
+        (std::cmp::max(max_block_time, now) - now) as u32 + processing_timeout;
+
+    let block = fetch_next_shard_block(
+        context,
+        &ParamsOfWaitForTransaction {
+            abi: None,
+            message: first.message.clone(),
+            shard_block_id: shard_block_id.clone(),
+            send_events,
+            sending_endpoints: None,
+        },
+        &first.address,
+        shard_block_id,
+        &first.message_id,
+        fetch_block_timeout,
+        callback,
+    )
+    .await
+    .add_network_url_from_context(context)
+    .await;
+    (walker, block)
+}
+
+/// Checks all the waiting messages of the walker in the fetched block.
+///
+/// Returns the finished messages and the walkers to continue with. If the shard
+/// is split, the messages from the other shard continue walking from the same
+/// block in a separate walker.
+async fn check_next_block(
+    context: &Arc<ClientContext>,
+    walker: ShardWalker,
+    block: ClientResult<Block>,
+) -> (WalkingResult, Vec<ShardWalker>) {
+    let ShardWalker {
+        shard_block_id,
+        messages,
+    } = walker;
+    let block = match block {
+        Ok(block) => block,
+        Err(err) => {
+            // the error is reported for every message with its own id
+            let finished = messages
+                .into_iter()
+                .map(|(index, message)| {
+                    let mut err = err.clone();
+                    err.data["message_id"] = message.message_id.into();
+                    (index, Err(err))
+                })
+                .collect();
+            return (finished, Vec::new());
+        }
+    };
+
+    let mut next = Vec::new();
+    let mut messages = messages;
+    if block.after_split {
+        let (current, other): (Vec<_>, Vec<_>) = messages.into_iter().partition(|(_, message)| {
+            block_contains_account(&block, &message.address).unwrap_or(true)
+        });
+        if !other.is_empty() {
+            next.push(ShardWalker {
+                shard_block_id: shard_block_id.clone(),
+                messages: other,
+            });
+        }
+        messages = current;
+    }
+
+    let mut finished = Vec::new();
+    let mut waiting = Vec::new();
+    for (index, message) in messages {
+        match message.check_block(context, &block, &shard_block_id).await {
+            Ok(Some(result)) => finished.push((index, Ok(result))),
+            Ok(None) => waiting.push((index, message)),
+            Err(err) => finished.push((index, Err(err))),
+        }
+    }
+    if !waiting.is_empty() {
+        next.push(ShardWalker {
+            shard_block_id: block.id.to_string(),
+            messages: waiting,
+        });
+    }
+    (finished, next)
+}
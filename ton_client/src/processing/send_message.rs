@@ -20,7 +20,7 @@ use crate::encoding::{base64_decode, hex_decode};
 use crate::error::{AddNetworkUrl, ClientResult};
 use crate::net::Endpoint;
 use crate::processing::internal::get_message_expiration_time;
-use crate::processing::types::ProcessingEvent;
+use crate::processing::types::{ProcessingEvent, ProcessingState};
 use crate::processing::Error;
 use std::sync::Arc;
 use ton_block::{Message, MsgAddressInt};
@@ -106,7 +106,7 @@ impl SendingMessage {
         })
     }
 
-    async fn prepare_to_send<F: futures::Future<Output = ()> + Send>(
+    async fn fetch_first_block<F: futures::Future<Output = ()> + Send>(
         &self,
         context: &Arc<ClientContext>,
         callback: &Option<impl Fn(ProcessingEvent) -> F + Send + Sync>,
@@ -114,15 +114,37 @@ impl SendingMessage {
         if let Some(callback) = callback {
             callback(ProcessingEvent::WillFetchFirstBlock {}).await;
         }
-        let shard_block_id = match find_last_shard_block(&context, &self.dst, None).await {
-            Ok(block) => block.to_string(),
+        match find_last_shard_block(&context, &self.dst, None).await {
+            Ok(block) => Ok(block.to_string()),
             Err(err) => {
                 if let Some(callback) = &callback {
                     callback(ProcessingEvent::FetchFirstBlockFailed { error: err.clone() }).await;
                 }
-                return Err(Error::fetch_first_block_failed(err, &self.id));
+                Err(Error::fetch_first_block_failed(err, &self.id))
             }
+        }
+    }
+
+    async fn prepare_to_send<F: futures::Future<Output = ()> + Send>(
+        &self,
+        context: &Arc<ClientContext>,
+        callback: &Option<impl Fn(ProcessingEvent) -> F + Send + Sync>,
+        shard_block_id: Option<String>,
+        state: Option<ProcessingState>,
+    ) -> ClientResult<String> {
+        let shard_block_id = match shard_block_id {
+            Some(shard_block_id) => shard_block_id,
+            None => self.fetch_first_block(context, callback).await?,
         };
+        if let (Some(callback), Some(state)) = (&callback, state) {
+            callback(ProcessingEvent::ProcessingStateChanged {
+                state: ProcessingState {
+                    shard_block_id: shard_block_id.clone(),
+                    ..state
+                },
+            })
+            .await;
+        }
         if let Some(callback) = &callback {
             callback(ProcessingEvent::WillSend {
                 shard_block_id: shard_block_id.clone(),
@@ -201,6 +223,25 @@ pub async fn send_message<F: futures::Future<Output = ()> + Send>(
     context: Arc<ClientContext>,
     params: ParamsOfSendMessage,
     callback: impl Fn(ProcessingEvent) -> F + Send + Sync + Clone,
+) -> ClientResult<ResultOfSendMessage> {
+    send_message_internal(context, params, callback, None, None).await
+}
+
+/// Sends message to the network.
+///
+/// `shard_block_id` is the already fetched last shard block of the destination
+/// account, e.g. fetched for a batch of messages at once. It is fetched
+/// if not specified.
+///
+/// The processing `state` with the shard block is emitted right before the
+/// message is sent, so the processing can be resumed even if the app is
+/// killed during sending.
+pub(crate) async fn send_message_internal<F: futures::Future<Output = ()> + Send>(
+    context: Arc<ClientContext>,
+    params: ParamsOfSendMessage,
+    callback: impl Fn(ProcessingEvent) -> F + Send + Sync + Clone,
+    shard_block_id: Option<String>,
+    state: Option<ProcessingState>,
 ) -> ClientResult<ResultOfSendMessage> {
     let message = SendingMessage::new(&context, &params.message, params.abi.as_ref()).await?;
 
@@ -210,7 +251,9 @@ pub async fn send_message<F: futures::Future<Output = ()> + Send>(
         None
     };
 
-    let shard_block_id = message.prepare_to_send(&context, &callback).await?;
+    let shard_block_id = message
+        .prepare_to_send(&context, &callback, shard_block_id, state)
+        .await?;
     let result = message.send(&context).await;
     if let Some(callback) = &callback {
         callback(match &result {
@@ -1,12 +1,13 @@
 use crate::abi::{
-    CallSet, DecodedMessageBody, DeploySet, FunctionHeader, MessageBodyType, ParamsOfEncodeMessage,
-    Signer,
+    Abi, CallSet, DecodedMessageBody, DeploySet, FunctionHeader, MessageBodyType,
+    ParamsOfEncodeMessage, ParamsOfRegisterAbi, RegisteredAbi, Signer,
 };
 use crate::json_interface::modules::ProcessingModule;
 use crate::processing::types::DecodedOutput;
 use crate::processing::{
-    ErrorCode, ParamsOfProcessMessage, ParamsOfSendMessage, ParamsOfWaitForTransaction,
-    ProcessingEvent, ProcessingResponseType,
+    ErrorCode, ParamsOfEstimateFees, ParamsOfProcessMessage, ParamsOfProcessMessages,
+    ParamsOfResumeProcessing, ParamsOfSendMessage, ParamsOfWaitForTransaction, ProcessingEvent,
+    ProcessingResponseType, ResultOfEstimateFees, ResultOfProcessMessage, ResultOfProcessMessages,
 };
 use crate::tests::{TestClient, EVENTS, HELLO};
 use crate::tvm::ErrorCode as TvmErrorCode;
@@ -24,6 +25,7 @@ fn processing_event_name(e: Option<&ProcessingEvent>) -> &str {
             ProcessingEvent::WillFetchFirstBlock { .. } => "WillFetchFirstBlock",
             ProcessingEvent::WillFetchNextBlock { .. } => "WillFetchNextBlock",
             ProcessingEvent::WillSend { .. } => "WillSend",
+            ProcessingEvent::ProcessingStateChanged { .. } => "ProcessingStateChanged",
         }
     } else {
         ""
@@ -190,6 +192,7 @@ async fn test_process_message() {
             ParamsOfProcessMessage {
                 message_encode_params: encode_params,
                 send_events: true,
+                send_state_events: None,
             },
             callback,
         )
@@ -242,6 +245,7 @@ async fn test_process_message() {
                     processing_try_index: None,
                 },
                 send_events: true,
+                send_state_events: None,
             },
             callback,
         )
@@ -347,6 +351,7 @@ async fn test_error_resolving() {
             ParamsOfProcessMessage {
                 message_encode_params: deploy_params.clone(),
                 send_events: false,
+                send_state_events: None,
             },
             TestClient::default_callback,
         )
@@ -374,6 +379,7 @@ async fn test_error_resolving() {
             ParamsOfProcessMessage {
                 message_encode_params: deploy_params.clone(),
                 send_events: false,
+                send_state_events: None,
             },
             TestClient::default_callback,
         )
@@ -407,6 +413,7 @@ async fn test_error_resolving() {
             ParamsOfProcessMessage {
                 message_encode_params: run_params.clone(),
                 send_events: false,
+                send_state_events: None,
             },
             TestClient::default_callback,
         )
@@ -430,6 +437,7 @@ async fn test_error_resolving() {
             ParamsOfProcessMessage {
                 message_encode_params: deploy_params.clone(),
                 send_events: false,
+                send_state_events: None,
             },
             TestClient::default_callback,
         )
@@ -443,6 +451,7 @@ async fn test_error_resolving() {
             ParamsOfProcessMessage {
                 message_encode_params: run_params.clone(),
                 send_events: false,
+                send_state_events: None,
             },
             TestClient::default_callback,
         )
@@ -512,6 +521,7 @@ async fn test_retries() {
                             signer: Signer::Keys { keys },
                         },
                         send_events: false,
+                        send_state_events: None,
                     },
                     TestClient::default_callback,
                 )
@@ -522,3 +532,256 @@ async fn test_retries() {
         result.unwrap().unwrap();
     }
 }
+
+#[tokio::test(core_threads = 2)]
+async fn test_estimate_fees() {
+    let emulator = crate::net::BlockchainEmulator::new("processing_estimate_fees", None)
+        .await
+        .unwrap();
+    let client = TestClient::new_with_config(json!({
+        "network": {
+            "endpoints": [emulator.endpoint()],
+        }
+    }));
+    let keys = client.generate_sign_keys();
+    let (abi, tvc) = TestClient::package(EVENTS, Some(2));
+    let deploy_params = ParamsOfEncodeMessage {
+        abi: abi.clone(),
+        address: None,
+        deploy_set: DeploySet::some_with_tvc(tvc),
+        call_set: Some(CallSet {
+            function_name: "constructor".into(),
+            header: Some(FunctionHeader {
+                pubkey: Some(keys.public.clone()),
+                ..Default::default()
+            }),
+            input: None,
+        }),
+        signer: Signer::Keys { keys: keys.clone() },
+        processing_try_index: None,
+    };
+
+    // account doesn't exist yet, so the deploy is estimated on the uninitialized account
+    let estimated: ResultOfEstimateFees = client
+        .request_async(
+            "processing.estimate_fees",
+            ParamsOfEstimateFees {
+                message_encode_params: deploy_params.clone(),
+                account: None,
+                execution_options: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert!(estimated.fees.gas_fee > 0);
+    assert!(estimated.fees.total_account_fees > 0);
+
+    emulator.topup(&estimated.address, 1_000_000_000).await.unwrap();
+    client
+        .net_process_message(
+            ParamsOfProcessMessage {
+                message_encode_params: deploy_params,
+                send_events: false,
+                send_state_events: None,
+            },
+            TestClient::default_callback,
+        )
+        .await
+        .unwrap();
+
+    let call_params = ParamsOfEncodeMessage {
+        abi: abi.clone(),
+        address: Some(estimated.address.clone()),
+        deploy_set: None,
+        call_set: CallSet::some_with_function_and_input("returnValue", json!({ "id": "0x1" })),
+        signer: Signer::Keys { keys },
+        processing_try_index: None,
+    };
+    let estimated: ResultOfEstimateFees = client
+        .request_async(
+            "processing.estimate_fees",
+            ParamsOfEstimateFees {
+                message_encode_params: call_params.clone(),
+                account: None,
+                execution_options: None,
+            },
+        )
+        .await
+        .unwrap();
+    // event and return value are external outbound messages with no forward fees
+    assert_eq!(estimated.out_messages_fwd_fees, vec![0, 0]);
+
+    let processed = client
+        .net_process_message(
+            ParamsOfProcessMessage {
+                message_encode_params: call_params,
+                send_events: false,
+                send_state_events: None,
+            },
+            TestClient::default_callback,
+        )
+        .await
+        .unwrap();
+    assert_eq!(estimated.fees.gas_fee, processed.fees.gas_fee);
+    assert_eq!(estimated.fees.out_msgs_fwd_fee, processed.fees.out_msgs_fwd_fee);
+}
+
+#[tokio::test(core_threads = 2)]
+async fn test_resume_processing() {
+    let emulator = crate::net::BlockchainEmulator::new("processing_resume_processing", None)
+        .await
+        .unwrap();
+    let client = TestClient::new_with_config(json!({
+        "network": {
+            "endpoints": [emulator.endpoint()],
+        }
+    }));
+    let keys = client.generate_sign_keys();
+    let (abi, tvc) = TestClient::package(EVENTS, Some(2));
+    let registered: RegisteredAbi = client
+        .request("abi.register_abi", ParamsOfRegisterAbi { abi })
+        .unwrap();
+    let deploy_params = ParamsOfEncodeMessage {
+        abi: Abi::Handle(registered.handle.clone()),
+        address: None,
+        deploy_set: DeploySet::some_with_tvc(tvc),
+        call_set: Some(CallSet {
+            function_name: "constructor".into(),
+            header: Some(FunctionHeader {
+                pubkey: Some(keys.public.clone()),
+                ..Default::default()
+            }),
+            input: None,
+        }),
+        signer: Signer::Keys { keys: keys.clone() },
+        processing_try_index: None,
+    };
+    let address = client.encode_message(deploy_params.clone()).await.unwrap().address;
+    emulator.topup(&address, 1_000_000_000).await.unwrap();
+
+    let events = std::sync::Arc::new(tokio::sync::Mutex::new(vec![]));
+    let events_copy = events.clone();
+    let callback = move |event: ProcessingEvent, _: ProcessingResponseType| {
+        let events = events_copy.clone();
+        async move {
+            events.lock().await.push(event);
+        }
+    };
+    let processed = client
+        .net_process_message(
+            ParamsOfProcessMessage {
+                message_encode_params: deploy_params,
+                send_events: true,
+                send_state_events: Some(true),
+            },
+            callback,
+        )
+        .await
+        .unwrap();
+
+    let events = events.lock().await.clone();
+    assert_events(
+        events.clone(),
+        vec![
+            "WillFetchFirstBlock",
+            "ProcessingStateChanged",
+            "WillSend",
+            "DidSend",
+            "ProcessingStateChanged",
+            "WillFetchNextBlock*",
+        ],
+    );
+    let states: Vec<_> = events
+        .into_iter()
+        .filter_map(|event| match event {
+            ProcessingEvent::ProcessingStateChanged { state } => Some(state),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(states[0].sending_endpoints, None);
+    assert_eq!(states[0].shard_block_id, states[1].shard_block_id);
+    assert_eq!(states[1].message_id, processed.transaction["in_msg"]);
+    // signer is not stored in the state, ABI handle is replaced with the ABI
+    for state in &states {
+        assert!(matches!(state.message_encode_params.signer, Signer::None));
+        assert!(matches!(state.message_encode_params.abi, Abi::Json(_)));
+    }
+    // the state doesn't depend on the registered ABI, e.g. after the client restart
+    let _: () = client.request("abi.unregister_abi", registered).unwrap();
+
+    // resuming with the state saved before sending finds the same transaction
+    // instead of sending a new message
+    let resumed: ResultOfProcessMessage = client
+        .request_async_callback(
+            "processing.resume_processing",
+            ParamsOfResumeProcessing {
+                state: states[0].clone(),
+                signer: Signer::Keys { keys },
+                send_events: false,
+                send_state_events: None,
+            },
+            TestClient::default_callback,
+        )
+        .await
+        .unwrap();
+    assert_eq!(resumed.transaction["id"], processed.transaction["id"]);
+}
+
+#[tokio::test(core_threads = 2)]
+async fn test_process_messages() {
+    let emulator = crate::net::BlockchainEmulator::new("processing_process_messages", None)
+        .await
+        .unwrap();
+    let client = TestClient::new_with_config(json!({
+        "network": {
+            "endpoints": [emulator.endpoint()],
+        }
+    }));
+    let (abi, tvc) = TestClient::package(HELLO, None);
+    let mut messages = Vec::new();
+    for _ in 0..3 {
+        let keys = client.generate_sign_keys();
+        let deploy_params = ParamsOfEncodeMessage {
+            abi: abi.clone(),
+            address: None,
+            deploy_set: DeploySet::some_with_tvc(tvc.clone()),
+            call_set: CallSet::some_with_function("constructor"),
+            signer: Signer::Keys { keys },
+            processing_try_index: None,
+        };
+        let address = client.encode_message(deploy_params.clone()).await.unwrap().address;
+        emulator.topup(&address, 1_000_000_000).await.unwrap();
+        messages.push(deploy_params);
+    }
+    let mut invalid = messages[0].clone();
+    invalid.call_set = CallSet::some_with_function("unknown");
+    messages.insert(1, invalid);
+
+    let result: ResultOfProcessMessages = client
+        .request_async_callback(
+            "processing.process_messages",
+            ParamsOfProcessMessages {
+                messages: messages.clone(),
+                send_events: false,
+                max_parallel_sends: Some(2),
+            },
+            TestClient::default_callback,
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.messages.len(), 4);
+    assert!(result.messages[1].result.is_none());
+    assert!(result.messages[1].error.is_some());
+    for (index, processed) in result.messages.iter().enumerate() {
+        if index == 1 {
+            continue;
+        }
+        assert!(processed.error.is_none());
+        let address = client
+            .encode_message(messages[index].clone())
+            .await
+            .unwrap()
+            .address;
+        assert_eq!(processed.result.as_ref().unwrap().transaction["account_addr"], address);
+    }
+}
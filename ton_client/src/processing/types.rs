@@ -1,4 +1,4 @@
-use crate::abi::DecodedMessageBody;
+use crate::abi::{DecodedMessageBody, ParamsOfEncodeMessage};
 use crate::error::ClientError;
 use serde_json::Value;
 use ton_sdk::TransactionFees;
@@ -116,4 +116,48 @@ pub enum ProcessingEvent {
         message: String,
         error: ClientError,
     },
+
+    /// Notifies the app that the processing state has changed.
+    ///
+    /// Event occurs before the message is sent to the network and
+    /// after the sending is complete, if `send_state_events` is requested. The app can persist the state
+    /// and continue the processing later with `resume_processing`
+    /// if it was interrupted.
+    ProcessingStateChanged { state: ProcessingState },
+}
+
+/// Serializable state of the message processing.
+///
+/// Contains everything required to continue the processing
+/// of the already sent message without sending a new one.
+///
+/// The signer is not included in the state: `message_encode_params.signer`
+/// is always `None`, and the signer is passed to `resume_processing` separately.
+#[derive(Serialize, Deserialize, ApiType, Default, Debug, Clone)]
+pub struct ProcessingState {
+    /// Message encode parameters without the signer. Used to create a new message
+    /// if the current one expires. ABI is always passed as JSON, even if
+    /// the processing was started with the ABI handle.
+    pub message_encode_params: ParamsOfEncodeMessage,
+
+    /// Index of the current processing try.
+    pub try_index: u8,
+
+    /// Message BOC of the current try.
+    pub message: String,
+
+    /// Message id of the current try.
+    pub message_id: String,
+
+    /// Message expiration time in ms, if the message ABI has the `expire` header.
+    pub message_expiration_time: Option<u64>,
+
+    /// The last generated shard block of the message destination account before
+    /// the message was sent.
+    pub shard_block_id: String,
+
+    /// The list of endpoints to which the message was sent.
+    ///
+    /// `null` if the state is emitted before sending.
+    pub sending_endpoints: Option<Vec<String>>,
 }
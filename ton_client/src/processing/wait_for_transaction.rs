@@ -7,6 +7,8 @@ use crate::processing::internal::{get_message_expiration_time, resolve_error};
 use crate::processing::{fetching, internal, Error};
 use crate::processing::{ProcessingEvent, ResultOfProcessMessage};
 use std::sync::Arc;
+use ton_block::MsgAddressInt;
+use ton_sdk::Block;
 
 //--------------------------------------------------------------------------- wait_for_transaction
 
@@ -45,109 +47,167 @@ pub async fn wait_for_transaction<F: futures::Future<Output = ()> + Send>(
     let net = context.get_server_link()?;
 
     // Prepare to wait
-    let message =
-        deserialize_object_from_boc::<ton_block::Message>(&context, &params.message, "message")
-            .await?;
-    let message_id = message.cell.repr_hash().to_hex_string();
-    let address = message
-        .object
-        .dst_ref().cloned()
-        .ok_or(Error::message_has_not_destination_address())?;
-    let message_expiration_time =
-        get_message_expiration_time(context.clone(), params.abi.as_ref(), &params.message).await?;
+    let waiting = WaitingMessage::new(
+        &context,
+        &params.message,
+        params.abi.clone(),
+        params.sending_endpoints.clone(),
+    )
+    .await?;
     let processing_timeout = net.config().message_processing_timeout;
-    let max_block_time =
-        message_expiration_time.unwrap_or(context.env.now_ms() + processing_timeout as u64);
-    log::debug!(
-        "message_expiration_time {}",
-        message_expiration_time.unwrap_or_default() / 1000
-    );
     let mut shard_block_id = params.shard_block_id.clone();
 
     // Block walking loop
     loop {
         let now = context.env.now_ms();
         let fetch_block_timeout =
-            (std::cmp::max(max_block_time, now) - now) as u32 + processing_timeout;
+            (std::cmp::max(waiting.max_block_time, now) - now) as u32 + processing_timeout;
         log::debug!("fetch_block_timeout {}", fetch_block_timeout);
 
         let block = fetching::fetch_next_shard_block(
             &context,
             &params,
-            &address,
+            &waiting.address,
             &shard_block_id,
-            &message_id,
+            &waiting.message_id,
             fetch_block_timeout,
             &callback,
         )
         .await
         .add_network_url_from_context(&context)
         .await?;
-        let transaction_ids = internal::find_transactions(&block, &message_id, &shard_block_id)?;
+        if let Some(result) = waiting.check_block(&context, &block, &shard_block_id).await? {
+            return Ok(result);
+        }
+
+        // We have successfully walked through the block.
+        // So store it as the last checked.
+        shard_block_id = block.id.to_string();
+    }
+}
+
+/// External inbound message waiting for its transaction
+pub(crate) struct WaitingMessage {
+    pub message: String,
+    pub message_id: String,
+    pub address: MsgAddressInt,
+    pub abi: Option<Abi>,
+    pub expiration_time: Option<u64>,
+    pub max_block_time: u64,
+    pub sending_endpoints: Option<Vec<String>>,
+}
+
+impl WaitingMessage {
+    pub async fn new(
+        context: &Arc<ClientContext>,
+        message: &str,
+        abi: Option<Abi>,
+        sending_endpoints: Option<Vec<String>>,
+    ) -> ClientResult<Self> {
+        let deserialized =
+            deserialize_object_from_boc::<ton_block::Message>(&context, message, "message")
+                .await?;
+        let message_id = deserialized.cell.repr_hash().to_hex_string();
+        let address = deserialized
+            .object
+            .dst_ref().cloned()
+            .ok_or(Error::message_has_not_destination_address())?;
+        let expiration_time =
+            get_message_expiration_time(context.clone(), abi.as_ref(), message).await?;
+        let processing_timeout = context.get_server_link()?.config().message_processing_timeout;
+        let max_block_time =
+            expiration_time.unwrap_or(context.env.now_ms() + processing_timeout as u64);
+        log::debug!(
+            "message_expiration_time {}",
+            expiration_time.unwrap_or_default() / 1000
+        );
+        Ok(Self {
+            message: message.to_string(),
+            message_id,
+            address,
+            abi,
+            expiration_time,
+            max_block_time,
+            sending_endpoints,
+        })
+    }
+
+    /// Looks for the message transaction in the `block` following `shard_block_id`.
+    ///
+    /// Returns `None` if the walking should be continued with the next block,
+    /// and the error if the message is expired.
+    pub async fn check_block(
+        &self,
+        context: &Arc<ClientContext>,
+        block: &Block,
+        shard_block_id: &String,
+    ) -> ClientResult<Option<ResultOfProcessMessage>> {
+        let transaction_ids = internal::find_transactions(block, &self.message_id, shard_block_id)?;
         let mut last_error = None;
         for transaction_id in transaction_ids {
             // Transaction has been found.
             // Let's fetch other stuff.
             let result = fetching::fetch_transaction_result(
-                &context,
-                &shard_block_id,
-                &message_id,
+                context,
+                shard_block_id,
+                &self.message_id,
                 &transaction_id,
-                &params.abi,
-                address.clone(),
-                (max_block_time / 1000) as u32,
+                &self.abi,
+                self.address.clone(),
+                (self.max_block_time / 1000) as u32,
                 block.gen_utime,
             )
             .await
-            .add_network_url_from_context(&context)
+            .add_network_url_from_context(context)
             .await;
             if result.is_ok() {
-                if let Some(endpoints) = &params.sending_endpoints {
+                if let Some(endpoints) = &self.sending_endpoints {
                     context
                         .get_server_link()?
                         .update_stat(endpoints, EndpointStat::MessageDelivered)
                         .await;
                 }
-                return result;
+                return result.map(Some);
             }
             last_error = Some(result);
         }
         if let Some(result) = last_error {
-            return result;
+            return result.map(Some);
         }
         // If we found a block with expired `gen_utime`,
         // then stop walking and return error.
-        if block.gen_utime as u64 * 1000 > max_block_time {
-            let waiting_expiration_time = (max_block_time / 1000) as u32;
-            let error = if message_expiration_time.is_some() {
+        if block.gen_utime as u64 * 1000 > self.max_block_time {
+            let processing_timeout = context.get_server_link()?.config().message_processing_timeout;
+            let waiting_expiration_time = (self.max_block_time / 1000) as u32;
+            let error = if self.expiration_time.is_some() {
                 Error::message_expired(
-                    &message_id,
-                    &shard_block_id,
+                    &self.message_id,
+                    shard_block_id,
                     waiting_expiration_time,
                     block.gen_utime,
-                    &address,
+                    &self.address,
                 )
             } else {
                 Error::transaction_wait_timeout(
-                    &message_id,
-                    &shard_block_id,
+                    &self.message_id,
+                    shard_block_id,
                     waiting_expiration_time,
                     processing_timeout,
                     block.gen_utime,
-                    &address,
+                    &self.address,
                 )
             };
             let resolved = resolve_error(
                 context.clone(),
-                &address,
-                params.message.clone(),
+                &self.address,
+                self.message.clone(),
                 error,
                 waiting_expiration_time - 1,
             )
             .await
-            .add_network_url_from_context(&context)
+            .add_network_url_from_context(context)
             .await;
-            if let (Some(endpoints), Err(err)) = (&params.sending_endpoints, &resolved) {
+            if let (Some(endpoints), Err(err)) = (&self.sending_endpoints, &resolved) {
                 if err.data["local_error"].is_null() {
                     context
                         .get_server_link()?
@@ -157,9 +217,6 @@ pub async fn wait_for_transaction<F: futures::Future<Output = ()> + Send>(
             }
             resolved?;
         }
-
-        // We have successfully walked through the block.
-        // So store it as the last checked.
-        shard_block_id = block.id.to_string();
+        Ok(None)
     }
 }
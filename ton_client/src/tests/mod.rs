@@ -79,7 +79,7 @@ impl log::Log for SimpleLogger {
 pub const SUBSCRIBE: &str = "Subscription";
 // pub const PIGGY_BANK: &str = "Piggy";
 // pub const WALLET: &str = "LimitWallet";
-// pub const SIMPLE_WALLET: &str = "Wallet";
+pub const SIMPLE_WALLET: &str = "Wallet";
 pub const GIVER_V2: &str = "GiverV2";
 pub const HELLO: &str = "Hello";
 pub const EVENTS: &str = "Events";
@@ -613,6 +613,7 @@ impl TestClient {
                     signer,
                 },
                 send_events: false,
+                send_state_events: None,
             },
             Self::default_callback,
         )
@@ -682,6 +683,7 @@ impl TestClient {
                 ParamsOfProcessMessage {
                     message_encode_params: params,
                     send_events: false,
+                    send_state_events: None,
                 },
                 Self::default_callback,
             )
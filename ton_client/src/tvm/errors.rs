@@ -33,6 +33,7 @@ pub enum ErrorCode {
     InvalidAccountBoc = 412,
     InvalidMessageType = 413,
     ContractExecutionError = 414,
+    MessageChainLimitExceeded = 415,
 }
 pub struct Error;
 
@@ -247,6 +248,16 @@ impl Error {
         )
     }
 
+    pub fn message_chain_limit_exceeded(limit: u32) -> ClientError {
+        error(
+            ErrorCode::MessageChainLimitExceeded,
+            format!(
+                "Message chain produced more than {} transactions. Possibly contracts send messages to each other endlessly",
+                limit
+            ),
+        )
+    }
+
     pub fn internal_error<E: Display>(err: E) -> ClientError {
         error(
             ErrorCode::InternalError,
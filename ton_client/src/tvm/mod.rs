@@ -17,6 +17,7 @@ pub(crate) mod check_transaction;
 mod errors;
 pub(crate) mod run_get;
 pub(crate) mod run_message;
+pub(crate) mod run_message_chain;
 pub(crate) mod types;
 
 mod stack;
@@ -29,5 +30,9 @@ pub use run_message::{
     run_executor, run_tvm, AccountForExecutor, ParamsOfRunExecutor, ParamsOfRunTvm,
     ResultOfRunExecutor, ResultOfRunTvm,
 };
+pub use run_message_chain::{
+    run_message_chain, ChainAccount, ChainAccountState, ChainTransaction,
+    ParamsOfRunMessageChain, ResultOfRunMessageChain,
+};
 pub use ton_sdk::TransactionFees;
 pub use types::ExecutionOptions;
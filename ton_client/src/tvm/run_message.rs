@@ -186,7 +186,7 @@ pub struct ResultOfRunTvm {
     pub account: String,
 }
 
-async fn parse_transaction(
+pub(crate) async fn parse_transaction(
     context: &Arc<ClientContext>,
     transaction: &Transaction,
 ) -> ClientResult<Value> {
@@ -350,7 +350,7 @@ pub async fn run_tvm(
     })
 }
 
-async fn call_executor<F>(
+pub(crate) async fn call_executor<F>(
     mut account: Cell,
     msg: Message,
     options: ResolvedExecutionOptions,
@@ -0,0 +1,286 @@
+/*
+ * Copyright 2018-2020 TON DEV SOLUTIONS LTD.
+ *
+ * Licensed under the SOFTWARE EVALUATION License (the "License"); you may not use
+ * this file except in compliance with the License.
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific TON DEV software governing permissions and
+ * limitations under the License.
+ *
+ */
+
+use super::run_message::{call_executor, parse_transaction};
+use super::types::{ExecutionOptions, ResolvedExecutionOptions};
+use crate::abi::Abi;
+use crate::boc::internal::{
+    deserialize_cell_from_boc, deserialize_object_from_boc, deserialize_object_from_cell,
+    serialize_cell_to_boc, serialize_object_to_base64,
+};
+use crate::boc::BocCacheType;
+use crate::client::ClientContext;
+use crate::error::ClientResult;
+use crate::processing::{parsing::decode_output, DecodedOutput};
+use crate::tvm::{check_transaction::calc_transaction_fees, Error};
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
+use ton_block::{Account, Message, MsgAddressInt, Transaction};
+use ton_sdk::TransactionFees;
+use ton_types::Cell;
+
+const DEFAULT_MAX_TRANSACTIONS: u32 = 100;
+
+#[derive(Serialize, Deserialize, ApiType, Debug, Clone, Default)]
+pub struct ChainAccount {
+    /// Account BOC. Encoded as base64.
+    pub boc: String,
+    /// Contract ABI for decoding output messages of the account transactions
+    pub abi: Option<Abi>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Clone, Default)]
+pub struct ParamsOfRunMessageChain {
+    /// Initial message BOC. Must be encoded as base64.
+    pub message: String,
+    /// Local accounts participating in the message chain
+    pub accounts: Vec<ChainAccount>,
+    /// Execution options.
+    pub execution_options: Option<ExecutionOptions>,
+    /// Maximum number of transactions in the chain. Default is 100.
+    ///
+    /// Protects from endless message exchange between the contracts.
+    pub max_transactions: Option<u32>,
+    /// Skip transaction check flag
+    pub skip_transaction_check: Option<bool>,
+    /// Cache type to put the result. The BOC itself returned if no cache type provided
+    pub boc_cache: Option<BocCacheType>,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, Debug, PartialEq, Clone)]
+pub struct ChainTransaction {
+    /// Parsed transaction.
+    ///
+    /// In addition to the regular transaction fields there is a
+    /// `boc` field encoded with `base64` which contains source
+    /// transaction BOC.
+    pub transaction: Value,
+
+    /// Index of the transaction which produced the inbound message
+    /// of this transaction. Absent for the transaction of the initial message.
+    pub parent: Option<u32>,
+
+    /// List of output messages' BOCs. Encoded as `base64`
+    pub out_messages: Vec<String>,
+
+    /// Optional decoded message bodies according to the account `abi`.
+    pub decoded: Option<DecodedOutput>,
+
+    /// Transaction fees
+    pub fees: TransactionFees,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, Debug, PartialEq, Clone)]
+pub struct ChainAccountState {
+    /// Account address
+    pub address: String,
+    /// Updated account state BOC. Encoded as `base64`
+    pub boc: String,
+}
+
+#[derive(Serialize, Deserialize, ApiType, Default, Debug, PartialEq, Clone)]
+pub struct ResultOfRunMessageChain {
+    /// Executed transactions in the order of execution.
+    ///
+    /// Transactions form a tree: each transaction refers to the
+    /// transaction produced its inbound message with `parent` field.
+    pub transactions: Vec<ChainTransaction>,
+
+    /// Updated states of the local accounts in the order of `accounts` parameter
+    pub accounts: Vec<ChainAccountState>,
+
+    /// Output messages which were not routed: external outbound messages and
+    /// internal messages to the accounts missing in `accounts` parameter.
+    /// Encoded as `base64`
+    pub unrouted_messages: Vec<String>,
+
+    /// Sum of the fees of all the transactions
+    pub total_fees: TransactionFees,
+}
+
+struct LocalAccount {
+    address: MsgAddressInt,
+    state: Cell,
+    abi: Option<Abi>,
+}
+
+fn add_fees(total: &mut TransactionFees, fees: &TransactionFees) {
+    total.in_msg_fwd_fee += fees.in_msg_fwd_fee;
+    total.storage_fee += fees.storage_fee;
+    total.gas_fee += fees.gas_fee;
+    total.out_msgs_fwd_fee += fees.out_msgs_fwd_fee;
+    total.total_account_fees += fees.total_account_fees;
+    total.total_output += fees.total_output;
+}
+
+fn out_messages(transaction: &Transaction) -> ClientResult<Vec<Message>> {
+    let mut messages = vec![];
+    for i in 0..transaction.outmsg_cnt {
+        messages.push(
+            transaction
+                .get_out_msg(i)
+                .map_err(|err| Error::can_not_read_transaction(err))?
+                .ok_or_else(|| Error::can_not_read_transaction("message missing"))?,
+        );
+    }
+    Ok(messages)
+}
+
+/// Emulates execution of the message chain on the local accounts
+///
+/// Executes the initial message on the destination account, then routes
+/// produced internal messages to the destination accounts and executes
+/// them the same way until no more messages for the local accounts left.
+/// Messages are executed in the order they are produced.
+///
+/// All the destination accounts must be provided in `accounts` parameter,
+/// messages to other accounts are returned in `unrouted_messages` field.
+/// Initial message must be addressed to one of the local accounts.
+///
+/// Use it to test interaction of several contracts offline,
+/// e.g. multisig wallet calling token root which deploys a token wallet.
+///
+/// If `skip_transaction_check` is not set, aborted transaction finishes the
+/// chain with an error, the same way as `run_executor` does. Set it to `true`
+/// to get bounced messages routed back to the senders.
+#[api_function]
+pub async fn run_message_chain(
+    context: std::sync::Arc<ClientContext>,
+    params: ParamsOfRunMessageChain,
+) -> ClientResult<ResultOfRunMessageChain> {
+    let mut accounts = Vec::with_capacity(params.accounts.len());
+    let mut indexes = HashMap::new();
+    for account in params.accounts {
+        let (_, state) = deserialize_cell_from_boc(&context, &account.boc, "account").await?;
+        let address = deserialize_object_from_cell::<Account>(state.clone(), "account")?
+            .get_addr()
+            .cloned()
+            .ok_or_else(|| Error::invalid_account_boc("account has no address"))?;
+        if indexes.insert(address.to_string(), accounts.len()).is_some() {
+            return Err(Error::invalid_account_boc(format!(
+                "account {} is specified more than once",
+                address
+            )));
+        }
+        accounts.push(LocalAccount { address, state, abi: account.abi });
+    }
+
+    let message = deserialize_object_from_boc::<Message>(&context, &params.message, "message")
+        .await?
+        .object;
+    let destination = message.dst_ref().ok_or_else(|| Error::invalid_message_type())?;
+    if !indexes.contains_key(&destination.to_string()) {
+        return Err(Error::invalid_account_boc(format!(
+            "initial message destination {} is missing in the local accounts",
+            destination
+        )));
+    }
+
+    let mut options =
+        ResolvedExecutionOptions::from_options(&context, params.execution_options).await?;
+    let max_transactions = params.max_transactions.unwrap_or(DEFAULT_MAX_TRANSACTIONS);
+    let skip_transaction_check = params.skip_transaction_check.unwrap_or_default();
+
+    let mut queue = VecDeque::new();
+    queue.push_back((message, None));
+    let mut transactions = Vec::new();
+    let mut unrouted_messages = Vec::new();
+    let mut total_fees = TransactionFees::default();
+
+    while let Some((message, parent)) = queue.pop_front() {
+        let index = match message.dst_ref().and_then(|dst| indexes.get(&dst.to_string())) {
+            Some(index) => *index,
+            None => {
+                unrouted_messages.push(serialize_object_to_base64(&message, "message")?);
+                continue;
+            }
+        };
+        if transactions.len() as u32 >= max_transactions {
+            return Err(Error::message_chain_limit_exceeded(max_transactions));
+        }
+
+        let account = &mut accounts[index];
+        let state = account.state.clone();
+        let address = account.address.clone();
+        let contract_info = move || async move {
+            let account = deserialize_object_from_cell::<Account>(state, "account")?;
+            let balance = account.balance().map(|balance| balance.grams.0).unwrap_or_default();
+            let balance = u64::try_from(balance).map_err(|_| {
+                Error::invalid_account_boc(format!("balance {} does not fit into 64 bits", balance))
+            })?;
+            Ok((address, balance))
+        };
+
+        let (transaction, modified_account) = call_executor(
+            account.state.clone(),
+            message,
+            options.clone(),
+            contract_info.clone(),
+        )
+        .await?;
+        let fees = calc_transaction_fees(
+            &transaction,
+            false,
+            skip_transaction_check,
+            contract_info,
+        )
+        .await?;
+        add_fees(&mut total_fees, &fees);
+        account.state = modified_account;
+        // next transaction must start after all the messages of this one are created
+        options.transaction_lt = transaction.logical_time() + transaction.outmsg_cnt as u64 + 1;
+
+        let transaction_index = transactions.len() as u32;
+        let mut out_message_bocs = vec![];
+        for message in out_messages(&transaction)? {
+            out_message_bocs.push(serialize_object_to_base64(&message, "message")?);
+            if message.is_internal() {
+                queue.push_back((message, Some(transaction_index)));
+            } else {
+                unrouted_messages.push(out_message_bocs.last().unwrap().clone());
+            }
+        }
+
+        let decoded = if let Some(abi) = account.abi.as_ref() {
+            Some(decode_output(&context, abi, out_message_bocs.clone()).await?)
+        } else {
+            None
+        };
+
+        transactions.push(ChainTransaction {
+            transaction: parse_transaction(&context, &transaction).await?,
+            parent,
+            out_messages: out_message_bocs,
+            decoded,
+            fees,
+        });
+    }
+
+    let mut states = Vec::with_capacity(accounts.len());
+    for account in accounts {
+        states.push(ChainAccountState {
+            address: account.address.to_string(),
+            boc: serialize_cell_to_boc(&context, account.state, "account", params.boc_cache.clone())
+                .await?,
+        });
+    }
+
+    Ok(ResultOfRunMessageChain {
+        transactions,
+        accounts: states,
+        unrouted_messages,
+        total_fees,
+    })
+}
@@ -24,7 +24,7 @@ use crate::boc::{
 };
 use crate::boc::tests::BLOCK_CONFIG;
 use crate::json_interface::modules::{AbiModule, TvmModule};
-use crate::tests::{TestClient, HELLO, SUBSCRIBE};
+use crate::tests::{TestClient, HELLO, SIMPLE_WALLET, SUBSCRIBE};
 use api_info::ApiModule;
 use serde_json::Value;
 use ton_executor::BlockchainConfig;
@@ -708,3 +708,141 @@ async fn test_resolve_blockchain_config() {
     let config = resolve_blockchain_config(&net_context, None).await.unwrap();
     assert_ne!(config.raw_config(), default_config.raw_config());
 }
+
+#[tokio::test(core_threads = 2)]
+async fn test_run_message_chain() {
+    TestClient::init_log();
+    let client = Arc::new(TestClient::new());
+
+    let encode_account = client.wrap_async(
+        crate::abi::encode_account::encode_account,
+        AbiModule::api(),
+        crate::abi::encode_account::encode_account_api(),
+    );
+    let run_message_chain = client.wrap_async(
+        run_message_chain,
+        TvmModule::api(),
+        run_message_chain::run_message_chain_api(),
+    );
+    let execution_options = Some(ExecutionOptions {
+        blockchain_config: Some(BLOCK_CONFIG.to_owned()),
+        ..Default::default()
+    });
+
+    let keys = client.generate_sign_keys();
+    let mut accounts = vec![];
+    for name in &[SIMPLE_WALLET, HELLO] {
+        let (abi, tvc) = TestClient::package(name, Some(2));
+        let account = encode_account
+            .call(ParamsOfEncodeAccount {
+                state_init: StateInitSource::Tvc {
+                    tvc,
+                    public_key: Some(keys.public.clone()),
+                    init_params: None,
+                },
+                balance: Some(10_000_000_000),
+                last_paid: None,
+                last_trans_lt: None,
+                boc_cache: None,
+            })
+            .await
+            .unwrap();
+        let address = format!("0:{}", account.id);
+
+        // run constructor so the contract can be called
+        let message = client
+            .encode_message(ParamsOfEncodeMessage {
+                abi: abi.clone(),
+                address: Some(address.clone()),
+                call_set: CallSet::some_with_function("constructor"),
+                deploy_set: None,
+                processing_try_index: None,
+                signer: Signer::Keys { keys: keys.clone() },
+            })
+            .await
+            .unwrap();
+        let result = run_message_chain
+            .call(ParamsOfRunMessageChain {
+                message: message.message,
+                accounts: vec![ChainAccount { boc: account.account, abi: Some(abi.clone()) }],
+                execution_options: execution_options.clone(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(result.transactions.len(), 1);
+        assert_eq!(result.accounts[0].address, address);
+
+        accounts.push((abi, address, result.accounts[0].boc.clone()));
+    }
+    let (wallet_abi, _, wallet) = accounts[0].clone();
+    let (hello_abi, hello_address, hello) = accounts[1].clone();
+
+    let message = client
+        .encode_message(ParamsOfEncodeMessage {
+            abi: wallet_abi.clone(),
+            address: Some(accounts[0].1.clone()),
+            call_set: CallSet::some_with_function_and_input(
+                "sendTransaction",
+                json!({
+                    "dest": hello_address,
+                    "value": 1_000_000_000u64,
+                    "bounce": false,
+                }),
+            ),
+            deploy_set: None,
+            processing_try_index: None,
+            signer: Signer::Keys { keys: keys.clone() },
+        })
+        .await
+        .unwrap();
+
+    // destination account is not local, so the transfer is not routed
+    let result = run_message_chain
+        .call(ParamsOfRunMessageChain {
+            message: message.message.clone(),
+            accounts: vec![ChainAccount { boc: wallet.clone(), abi: Some(wallet_abi.clone()) }],
+            execution_options: execution_options.clone(),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(result.transactions.len(), 1);
+    assert_eq!(result.unrouted_messages, result.transactions[0].out_messages);
+
+    let result = run_message_chain
+        .call(ParamsOfRunMessageChain {
+            message: message.message,
+            accounts: vec![
+                ChainAccount { boc: wallet, abi: Some(wallet_abi) },
+                ChainAccount { boc: hello.clone(), abi: Some(hello_abi) },
+            ],
+            execution_options,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result.transactions.len(), 2);
+    assert_eq!(result.transactions[0].parent, None);
+    assert_eq!(result.transactions[1].parent, Some(0));
+    assert_eq!(result.transactions[1].transaction["account_addr"], hello_address);
+    assert!(result.unrouted_messages.is_empty());
+    assert_eq!(
+        result.total_fees.gas_fee,
+        result.transactions[0].fees.gas_fee + result.transactions[1].fees.gas_fee
+    );
+
+    let balance = |boc: String| {
+        let client = client.clone();
+        async move {
+            let parsed: crate::boc::ResultOfParse = client
+                .request_async("boc.parse_account", crate::boc::ParamsOfParse { boc })
+                .await
+                .unwrap();
+            u64::from_str_radix(parsed.parsed["balance"].as_str().unwrap().trim_start_matches("0x"), 16)
+                .unwrap()
+        }
+    };
+    assert!(balance(result.accounts[1].boc.clone()).await > balance(hello).await);
+}
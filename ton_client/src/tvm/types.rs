@@ -36,6 +36,7 @@ pub struct ExecutionOptions {
     pub transaction_lt: Option<u64>,
 }
 
+#[derive(Clone)]
 pub(crate) struct ResolvedExecutionOptions {
     pub blockchain_config: Arc<BlockchainConfig>,
     pub block_time: u32,
@@ -18,23 +18,61 @@ use crate::{AbiContract, MessageId};
 use chrono::prelude::Utc;
 use ed25519_dalek::{Keypair, PublicKey};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::convert::Into;
 use std::io::{Cursor, Read, Seek};
 use ton_abi::json_abi::DecodedMessage;
+use ton_abi::token::Tokenizer;
+use ton_abi::{Function, Token, TokenValue};
 use ton_block::{AccountIdPrefixFull, Deserializable, ExternalInboundMessageHeader, GetRepresentationHash,
     Message as TvmMessage, MsgAddressInt, Serializable, ShardIdent, StateInit,
     InternalMessageHeader, CurrencyCollection};
 use ton_types::cells_serialization::deserialize_cells_tree;
-use ton_types::{error, fail, AccountId, Result, SliceData};
+use ton_types::{error, fail, AccountId, BuilderData, Result, SliceData};
 
 pub struct Contract {}
 
 #[derive(Clone, Debug)]
-pub struct FunctionCallSet {
+pub struct FunctionCallSet<'a> {
     pub func: String,
     pub header: Option<String>,
     pub input: String,
-    pub abi: String,
+    pub abi: &'a AbiContract,
+}
+
+impl FunctionCallSet<'_> {
+    fn tokenize(&self, with_header: bool) -> Result<(&Function, HashMap<String, TokenValue>, Vec<Token>)> {
+        let function = self.abi.function(&self.func)?;
+        let header = match &self.header {
+            Some(header) if with_header => {
+                let header: Value = serde_json::from_str(header)?;
+                Tokenizer::tokenize_optional_params(&function.header, &header, &HashMap::new())?
+            }
+            _ => HashMap::new(),
+        };
+        let input: Value = serde_json::from_str(&self.input)?;
+        let input = Tokenizer::tokenize_all_params(&function.inputs, &input)?;
+        Ok((function, header, input))
+    }
+
+    // Encodes function call body with already parsed ABI.
+    // Header is ignored for internal calls.
+    pub fn encode_body(&self, internal: bool, key_pair: Option<&Keypair>) -> Result<BuilderData> {
+        let (function, mut header, input) = self.tokenize(!internal)?;
+        if key_pair.is_some() && !header.contains_key("pubkey") {
+            header.insert(
+                "pubkey".to_owned(),
+                TokenValue::PublicKey(key_pair.map(|pair| pair.public)),
+            );
+        }
+        function.encode_input(&header, &input, internal, key_pair)
+    }
+
+    // Encodes external function call body without signature and returns data to sign.
+    pub fn prepare_body_for_sign(&self) -> Result<(BuilderData, Vec<u8>)> {
+        let (function, header, input) = self.tokenize(true)?;
+        function.create_unsigned_call(&header, &input, false, true)
+    }
 }
 
 pub struct SdkMessage {
@@ -207,9 +245,9 @@ impl ContractImage {
     }
 
     ///Allows to change initial values for public contract variables
-    pub fn update_data(&mut self, data_json: &str, abi_json: &str) -> Result<()> {
-        let new_data = ton_abi::json_abi::update_contract_data(
-            abi_json,
+    pub fn update_data(&mut self, data_json: &str, abi: &AbiContract) -> Result<()> {
+        let new_data = Contract::update_contract_data(
+            abi,
             data_json,
             self.state_init.data.clone().unwrap_or_default().into(),
         )?;
@@ -296,6 +334,22 @@ impl Contract {
         Self::decode_unknown_function_call_json(abi, slice, internal)
     }
 
+    /// Sets values of the contract's static variables listed in `data_json`
+    pub fn update_contract_data(abi: &AbiContract, data_json: &str, data: SliceData) -> Result<SliceData> {
+        let data_json: Value = serde_json::from_str(data_json)?;
+        let params: Vec<_> = abi
+            .data()
+            .values()
+            .map(|item| item.value.clone())
+            .filter(|param| data_json.get(&param.name).is_some())
+            .collect();
+        let mut tokens = HashMap::new();
+        for token in Tokenizer::tokenize_all_params(&params, &data_json)? {
+            tokens.insert(abi.data()[&token.name].key, token.value);
+        }
+        abi.update_data(data, &tokens)
+    }
+
     // ------- Call constructing functions -------
 
     // Packs given inputs by abi into an external inbound Message struct.
@@ -307,14 +361,7 @@ impl Contract {
         key_pair: Option<&Keypair>,
     ) -> Result<SdkMessage> {
         // pack params into bag of cells via ABI
-        let msg_body = ton_abi::encode_function_call(
-            params.abi,
-            params.func,
-            params.header,
-            params.input,
-            false,
-            key_pair,
-        )?;
+        let msg_body = params.encode_body(false, key_pair)?;
 
         let msg = Self::create_ext_in_message(address.clone(), msg_body.into())?;
         let (body, id) = Self::serialize_message(&msg)?;
@@ -338,14 +385,7 @@ impl Contract {
         params: FunctionCallSet,
     ) -> Result<SdkMessage> {
         // pack params into bag of cells via ABI
-        let msg_body = ton_abi::encode_function_call(
-            params.abi,
-            params.func,
-            None,
-            params.input,
-            true,
-            None,
-        )?;
+        let msg_body = params.encode_body(true, None)?;
 
         Self::construct_int_message_with_body(
             address,
@@ -383,12 +423,7 @@ impl Contract {
         params: FunctionCallSet,
     ) -> Result<MessageToSign> {
         // pack params into bag of cells via ABI
-        let (msg_body, data_to_sign) = ton_abi::prepare_function_call_for_sign(
-            params.abi,
-            params.func,
-            params.header,
-            params.input,
-        )?;
+        let (msg_body, data_to_sign) = params.prepare_body_for_sign()?;
 
         let msg = Self::create_ext_in_message(address, msg_body.into())?;
 
@@ -409,14 +444,7 @@ impl Contract {
         key_pair: Option<&Keypair>,
         workchain_id: i32,
     ) -> Result<SdkMessage> {
-        let msg_body = ton_abi::encode_function_call(
-            params.abi,
-            params.func,
-            params.header,
-            params.input,
-            false,
-            key_pair,
-        )?;
+        let msg_body = params.encode_body(false, key_pair)?;
 
         let cell = msg_body.into();
         let msg = Self::create_ext_deploy_message(Some(cell), image, workchain_id)?;
@@ -481,12 +509,7 @@ impl Contract {
         image: ContractImage,
         workchain_id: i32,
     ) -> Result<MessageToSign> {
-        let (msg_body, data_to_sign) = ton_abi::prepare_function_call_for_sign(
-            params.abi,
-            params.func,
-            params.header,
-            params.input,
-        )?;
+        let (msg_body, data_to_sign) = params.prepare_body_for_sign()?;
 
         let cell = msg_body.into();
         let msg = Self::create_ext_deploy_message(Some(cell), image, workchain_id)?;
@@ -507,14 +530,7 @@ impl Contract {
         ihr_disabled: bool,
         bounce: bool,
     ) -> Result<Vec<u8>> {
-        let msg_body = ton_abi::encode_function_call(
-            params.abi,
-            params.func,
-            None,
-            params.input,
-            true,
-            None,
-        )?;
+        let msg_body = params.encode_body(true, None)?;
 
         let cell = msg_body.into();
         let msg = Self::create_int_deploy_message(src, Some(cell), image, workchain_id, ihr_disabled, bounce)?;